@@ -1,16 +1,37 @@
-use crate::config::{Config, WidgetConfig};
+use crate::config::{Config, Position, WidgetConfig};
 use crate::creature::persistence::{default_creature_path, load_or_create_creature, save_creature};
 use crate::creature::Creature;
 use crate::event::{Event, EventHandler};
-use crate::feeds::{FeedData, FeedMessage};
+use crate::feeds::cache::{default_cache_dir, FeedCache};
+use crate::feeds::export;
+use crate::feeds::github::mark_thread_read;
+use crate::feeds::hackernews::{fetch_comments, HnComment};
+use crate::feeds::http::shared_client;
+use crate::feeds::mock::MockFetcher;
+use crate::feeds::pixelart::PixelData;
+use crate::feeds::timeline::TimelineSource;
+use crate::feeds::youtube::fetch_thumbnail;
+use crate::feeds::{
+    fetch_with_timeout, fetch_with_timeout_notify, FeedData, FeedFetcher, FeedMessage,
+    FETCH_RETRIES, FETCH_TIMEOUT,
+};
+use crate::logging;
 use crate::twitter_message::{TwitterData, TwitterMessage};
 use crate::twitter_parser;
 use crate::ui::article_reader::ArticleReader;
+use crate::ui::comments_view::CommentsView;
 use crate::ui::creature_menu::CreatureMenu;
+use crate::ui::spinner::Spinner;
+use crate::ui::text::truncate_with_ellipsis;
+use crate::ui::theme::{style_border, Theme};
+use crate::ui::widgets::youtube::THUMBNAIL_SIZE;
 use crate::ui::widgets::{
-    clock::Clock, creature::CreatureWidget, github::GithubWidget, hackernews::HackernewsWidget,
-    pixelart::PixelArtWidget, rss::RssWidget, sports::SportsWidget, stocks::StocksWidget,
-    twitter::TwitterWidget, youtube::YoutubeWidget, FeedWidget,
+    arxiv, bluesky::BlueskyWidget, calendar::CalendarWidget, clock::Clock, command::CommandWidget,
+    creature::CreatureWidget, crypto, github::GithubWidget, gitlab, hackernews::HackernewsWidget,
+    json_poll, list::NumberJump, mastodon::MastodonWidget, news, pixelart::PixelArtWidget,
+    podcast::PodcastWidget, reddit::RedditWidget, releases::ReleasesWidget, rss::RssWidget,
+    sports::SportsWidget, stocks::StocksWidget, timeline::TimelineWidget, twitter::TwitterWidget,
+    weather::WeatherWidget, youtube::YoutubeWidget, FeedWidget, SelectedItem,
 };
 use anyhow::Result;
 use crossterm::{
@@ -22,15 +43,67 @@ use ratatui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout},
     prelude::Rect,
+    style::{Color, Modifier, Style},
     Frame, Terminal,
 };
+use std::collections::HashMap;
 use std::io::{self, Stdout};
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
+
+/// Smallest grid cell (in columns and rows) a widget can draw into legibly
+const MIN_CELL_WIDTH: u16 = 20;
+const MIN_CELL_HEIGHT: u16 = 5;
+
+/// How long a widget's border flashes after new items arrive, and how fast it blinks
+const FLASH_DURATION: Duration = Duration::from_millis(1500);
+const FLASH_BLINK: Duration = Duration::from_millis(250);
+
+/// Longest response body shown in the error details view, in columns
+const MAX_ERROR_DETAIL: usize = 2000;
+
+/// Outcome of marking a GitHub notification as read in the background
+struct MarkReadResult {
+    widget_id: String,
+    thread_id: String,
+    result: Result<(), String>,
+}
+
+/// A YouTube thumbnail, downloaded in the background
+struct ThumbnailResult {
+    widget_id: String,
+    video_id: String,
+    result: Result<PixelData, String>,
+}
+
+/// A Hacker News story's comment thread, fetched in the background
+struct CommentsResult {
+    story_id: u64,
+    result: Result<Vec<HnComment>, String>,
+}
+
+/// Whether there is a graphical session to open a browser in. macOS and
+/// Windows always have one; elsewhere it takes an X11 or Wayland display.
+fn has_display() -> bool {
+    if cfg!(any(target_os = "macos", windows)) {
+        return true;
+    }
+    ["DISPLAY", "WAYLAND_DISPLAY"]
+        .iter()
+        .any(|var| std::env::var_os(var).is_some_and(|value| !value.is_empty()))
+}
 
 pub struct App {
     config: Config,
+    /// Where `config` was loaded from; rearranged widgets are saved back there
+    config_path: PathBuf,
+    /// Whether widgets were moved since startup, so the layout needs saving
+    layout_changed: bool,
+    /// Id of the widget filling the whole screen, if one is zoomed
+    zoomed: Option<String>,
     widgets: Vec<Box<dyn FeedWidget>>,
     selected_widget: usize,
     should_quit: bool,
@@ -38,18 +111,47 @@ pub struct App {
     feed_tx: mpsc::UnboundedSender<FeedMessage>,
     twitter_rx: mpsc::UnboundedReceiver<TwitterMessage>,
     twitter_tx: mpsc::UnboundedSender<TwitterMessage>,
+    mark_read_rx: mpsc::UnboundedReceiver<MarkReadResult>,
+    mark_read_tx: mpsc::UnboundedSender<MarkReadResult>,
+    comments_rx: mpsc::UnboundedReceiver<CommentsResult>,
+    comments_tx: mpsc::UnboundedSender<CommentsResult>,
+    thumbnail_rx: mpsc::UnboundedReceiver<ThumbnailResult>,
+    thumbnail_tx: mpsc::UnboundedSender<ThumbnailResult>,
     creature_path: PathBuf,
     creature_widget_idx: Option<usize>,
     last_xp_tick: Instant,
     creature_menu: CreatureMenu,
     article_reader: ArticleReader,
+    comments_view: CommentsView,
     status_message: Option<(String, Instant)>,
+    number_jump: NumberJump,
+    theme: Theme,
+    /// Widgets with new items, by index, and when their flash started
+    flashes: HashMap<usize, Instant>,
+    /// Connection pool shared by every fetcher
+    http_client: Arc<reqwest::Client>,
+    /// Last fetched data per widget, or None when caching is turned off
+    cache: Option<Arc<FeedCache>>,
+    /// Id of the widget being refreshed on request, to report when it's done
+    refreshing: Option<String>,
+    /// Whether background fetching is paused; watched by every fetcher task
+    paused: watch::Sender<bool>,
+    /// Background fetch loop of each widget, by index
+    fetch_tasks: Vec<JoinHandle<()>>,
+    /// The last data each widget received, by index, for exporting
+    latest_data: Vec<Option<FeedData>>,
+    /// Opened on first copy and kept alive, since on X11 the clipboard
+    /// contents are served by this handle
+    clipboard: Option<arboard::Clipboard>,
 }
 
 impl App {
-    pub fn new(config: Config) -> Self {
+    pub fn new(config: Config, config_path: PathBuf) -> Self {
         let (feed_tx, feed_rx) = mpsc::unbounded_channel();
         let (twitter_tx, twitter_rx) = mpsc::unbounded_channel();
+        let (mark_read_tx, mark_read_rx) = mpsc::unbounded_channel();
+        let (comments_tx, comments_rx) = mpsc::unbounded_channel();
+        let (thumbnail_tx, thumbnail_rx) = mpsc::unbounded_channel();
 
         // Load or create creature
         let creature_path = default_creature_path();
@@ -58,30 +160,55 @@ impl App {
             Creature::default()
         });
 
-        let mut widgets: Vec<Box<dyn FeedWidget>> = Vec::new();
-        let mut creature_widget_idx = None;
-
-        for widget_config in &config.widgets {
-            let widget: Box<dyn FeedWidget> = match widget_config {
-                WidgetConfig::Hackernews(cfg) => Box::new(HackernewsWidget::new(cfg.clone())),
-                WidgetConfig::Stocks(cfg) => Box::new(StocksWidget::new(cfg.clone())),
-                WidgetConfig::Rss(cfg) => Box::new(RssWidget::new(cfg.clone())),
-                WidgetConfig::Sports(cfg) => Box::new(SportsWidget::new(cfg.clone())),
-                WidgetConfig::Github(cfg) => Box::new(GithubWidget::new(cfg.clone())),
-                WidgetConfig::Youtube(cfg) => Box::new(YoutubeWidget::new(cfg.clone())),
-                WidgetConfig::Twitter(cfg) => Box::new(TwitterWidget::new(cfg.clone())),
-                WidgetConfig::Pixelart(cfg) => Box::new(PixelArtWidget::new(cfg.clone())),
-                WidgetConfig::Clock(cfg) => Box::new(Clock::new(cfg.clone())),
-                WidgetConfig::Creature(cfg) => {
-                    creature_widget_idx = Some(widgets.len());
-                    Box::new(CreatureWidget::new(cfg.clone(), creature.clone()))
+        let mut widgets = Self::build_widgets(&config, &creature);
+        let creature_widget_idx = config
+            .widgets
+            .iter()
+            .rposition(|w| matches!(w, WidgetConfig::Creature(_)));
+
+        let http_client = shared_client();
+        Self::connect_timelines(&mut widgets);
+
+        // Mock data mustn't end up in the cache of real fetches
+        let use_cache = config.general.cache_ttl_minutes > 0 && config.general.mock_data.is_none();
+        let cache = use_cache.then(|| {
+            Arc::new(FeedCache::new(
+                default_cache_dir(),
+                Duration::from_secs(config.general.cache_ttl_minutes * 60),
+            ))
+        });
+        let mut latest_data = vec![None; widgets.len()];
+        if let Some(cache) = &cache {
+            for (widget, latest) in widgets.iter_mut().zip(&mut latest_data) {
+                if let Some(data) = cache.load(&widget.id()) {
+                    widget.update_data(data.clone());
+                    *latest = Some(data);
                 }
-            };
-            widgets.push(widget);
+            }
+            for i in 0..widgets.len() {
+                let Some(data) = latest_data[i].clone() else {
+                    continue;
+                };
+                let id = widgets[i].id();
+                for (timeline_id, merged) in Self::route_to_timelines(&mut widgets, &id, &data) {
+                    if let Some(idx) = widgets.iter().position(|w| w.id() == timeline_id) {
+                        widgets[idx].update_data(merged.clone());
+                        latest_data[idx] = Some(merged);
+                    }
+                }
+            }
+        }
+
+        let theme = Theme::resolve(&config.general);
+        for widget in widgets.iter_mut() {
+            widget.set_palette(theme.palette);
         }
 
         Self {
             config,
+            config_path,
+            layout_changed: false,
+            zoomed: None,
             widgets,
             selected_widget: 0,
             should_quit: false,
@@ -89,13 +216,72 @@ impl App {
             feed_tx,
             twitter_rx,
             twitter_tx,
+            mark_read_rx,
+            mark_read_tx,
+            comments_rx,
+            comments_tx,
+            thumbnail_rx,
+            thumbnail_tx,
             creature_path,
             creature_widget_idx,
             last_xp_tick: Instant::now(),
             creature_menu: CreatureMenu::default(),
             article_reader: ArticleReader::default(),
+            comments_view: CommentsView::default(),
             status_message: None,
+            refreshing: None,
+            paused: watch::Sender::new(false),
+            fetch_tasks: Vec::new(),
+            latest_data,
+            number_jump: NumberJump::default(),
+            theme,
+            flashes: HashMap::new(),
+            clipboard: None,
+            http_client,
+            cache,
+        }
+    }
+
+    /// One widget per entry in `config.widgets`, in the same order
+    pub fn build_widgets(config: &Config, creature: &Creature) -> Vec<Box<dyn FeedWidget>> {
+        let mut widgets: Vec<Box<dyn FeedWidget>> = Vec::new();
+
+        for widget_config in &config.widgets {
+            let widget: Box<dyn FeedWidget> = match widget_config {
+                WidgetConfig::Hackernews(cfg) => Box::new(HackernewsWidget::new(cfg.clone())),
+                WidgetConfig::Stocks(cfg) => Box::new(StocksWidget::new(cfg.clone())),
+                WidgetConfig::Rss(cfg) => Box::new(RssWidget::new(cfg.clone())),
+                WidgetConfig::Sports(cfg) => Box::new(SportsWidget::new(cfg.clone())),
+                WidgetConfig::Github(cfg) => {
+                    Box::new(GithubWidget::new(cfg.clone(), config.general.icons))
+                }
+                WidgetConfig::Gitlab(cfg) => {
+                    Box::new(gitlab::widget(cfg.clone(), config.general.icons))
+                }
+                WidgetConfig::Youtube(cfg) => Box::new(YoutubeWidget::new(cfg.clone())),
+                WidgetConfig::Twitter(cfg) => Box::new(TwitterWidget::new(cfg.clone())),
+                WidgetConfig::Pixelart(cfg) => Box::new(PixelArtWidget::new(cfg.clone())),
+                WidgetConfig::Clock(cfg) => Box::new(Clock::new(cfg.clone())),
+                WidgetConfig::Timeline(cfg) => Box::new(TimelineWidget::new(cfg.clone())),
+                WidgetConfig::Reddit(cfg) => Box::new(RedditWidget::new(cfg.clone())),
+                WidgetConfig::Mastodon(cfg) => Box::new(MastodonWidget::new(cfg.clone())),
+                WidgetConfig::Bluesky(cfg) => Box::new(BlueskyWidget::new(cfg.clone())),
+                WidgetConfig::Weather(cfg) => Box::new(WeatherWidget::new(cfg.clone())),
+                WidgetConfig::Calendar(cfg) => Box::new(CalendarWidget::new(cfg.clone())),
+                WidgetConfig::Releases(cfg) => Box::new(ReleasesWidget::new(cfg.clone())),
+                WidgetConfig::Podcast(cfg) => Box::new(PodcastWidget::new(cfg.clone())),
+                WidgetConfig::Arxiv(cfg) => Box::new(arxiv::widget(cfg.clone())),
+                WidgetConfig::Crypto(cfg) => Box::new(crypto::widget(cfg.clone())),
+                WidgetConfig::Command(cfg) => Box::new(CommandWidget::new(cfg.clone())),
+                WidgetConfig::Jsonpoll(cfg) => Box::new(json_poll::widget(cfg.clone())),
+                WidgetConfig::News(cfg) => Box::new(news::widget(cfg.clone())),
+                WidgetConfig::Creature(cfg) => {
+                    Box::new(CreatureWidget::new(cfg.clone(), creature.clone()))
+                }
+            };
+            widgets.push(widget);
         }
+        widgets
     }
 
     pub async fn run(&mut self) -> Result<()> {
@@ -123,6 +309,7 @@ impl App {
             // Clear expired status messages
             self.clear_expired_status();
             self.tick_twitter_widgets();
+            self.request_thumbnails();
 
             // Draw UI
             terminal.draw(|frame| self.render(frame))?;
@@ -140,6 +327,15 @@ impl App {
                 Some(msg) = self.twitter_rx.recv() => {
                     self.handle_twitter_message(msg);
                 }
+                Some(msg) = self.mark_read_rx.recv() => {
+                    self.handle_mark_read_result(msg);
+                }
+                Some(msg) = self.comments_rx.recv() => {
+                    self.comments_view.set_comments(msg.story_id, msg.result);
+                }
+                Some(msg) = self.thumbnail_rx.recv() => {
+                    self.handle_thumbnail_result(msg);
+                }
             }
         }
 
@@ -147,6 +343,7 @@ impl App {
         self.save_creature_state();
 
         Self::restore_terminal(&mut terminal)?;
+        self.save_layout();
         Ok(())
     }
 
@@ -192,12 +389,52 @@ impl App {
                     return;
                 }
 
+                if self.comments_view.visible {
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Char('q') => self.comments_view.hide(),
+                        KeyCode::Down | KeyCode::Char('j') => self.comments_view.scroll_down(),
+                        KeyCode::Up | KeyCode::Char('k') => self.comments_view.scroll_up(),
+                        KeyCode::PageDown => self.comments_view.page_down(10),
+                        KeyCode::PageUp => self.comments_view.page_up(10),
+                        KeyCode::Char('o') => {
+                            let url = self.comments_view.url();
+                            self.open_url(&url);
+                        }
+                        _ => {}
+                    }
+                    return;
+                }
+
                 // If Twitter modal is open, route events there
                 if self.has_twitter_modal_open() {
                     self.handle_twitter_modal_event(key);
                     return;
                 }
 
+                // If a GitHub filter is being typed, route events there
+                if let Some(github) = self.selected_github_mut().filter(|g| g.is_editing_filter()) {
+                    match key.code {
+                        KeyCode::Esc => github.clear_filter(),
+                        KeyCode::Enter => github.confirm_filter(),
+                        KeyCode::Backspace => github.filter_pop(),
+                        KeyCode::Char(c) => github.filter_push(c),
+                        _ => {}
+                    }
+                    return;
+                }
+
+                // If a stopwatch is being named, route events there
+                if let Some(clock) = self.selected_clock_mut().filter(|c| c.is_naming()) {
+                    match key.code {
+                        KeyCode::Esc => clock.cancel_name(),
+                        KeyCode::Enter => clock.confirm_name(),
+                        KeyCode::Backspace => clock.name_pop(),
+                        KeyCode::Char(c) => clock.name_push(c),
+                        _ => {}
+                    }
+                    return;
+                }
+
                 // If creature menu is visible, route events there
                 if self.creature_menu.visible {
                     match key.code {
@@ -236,7 +473,13 @@ impl App {
                     KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                         self.should_quit = true
                     }
-                    KeyCode::Char('s') => self.handle_stopwatch_toggle(),
+                    KeyCode::Char('s') => {
+                        if let Some(github) = self.selected_github_mut() {
+                            github.cycle_sort();
+                        } else {
+                            self.handle_stopwatch_toggle();
+                        }
+                    }
                     KeyCode::Char('r') => {
                         if self.is_twitter_selected() {
                             self.twitter_open_reply();
@@ -252,72 +495,396 @@ impl App {
                         }
                     }
                     KeyCode::Char('/') if self.is_twitter_selected() => self.twitter_open_search(),
+                    KeyCode::Char('/') => {
+                        if let Some(github) = self.selected_github_mut() {
+                            github.open_filter();
+                        }
+                    }
+                    KeyCode::Esc => {
+                        if let Some(github) = self.selected_github_mut() {
+                            github.clear_filter();
+                        }
+                    }
+                    KeyCode::Char('l') if self.is_twitter_selected() => {
+                        self.twitter_act_on_selected("like", TwitterData::Liked)
+                    }
+                    KeyCode::Char('R') if self.is_twitter_selected() => {
+                        self.twitter_act_on_selected("retweet", TwitterData::Retweeted)
+                    }
+                    KeyCode::Char('R') | KeyCode::F(5) => self.refresh_selected(),
+                    KeyCode::Char('p') => self.toggle_paused(),
+                    KeyCode::Char('z') => self.toggle_zoom(),
+                    KeyCode::Char('e') => self.export_feed_data(),
+                    KeyCode::Char('v') => {
+                        if let Some(youtube) = self.selected_youtube_mut() {
+                            youtube.toggle_thumbnail();
+                        } else if let Some(github) = self.selected_github_mut() {
+                            github.toggle_grouped();
+                        }
+                    }
                     KeyCode::Char('m') if self.is_twitter_selected() => {
                         self.twitter_load_mentions()
                     }
+                    KeyCode::Char('n') => {
+                        if let Some(clock) = self.selected_clock_mut() {
+                            clock.open_name_prompt();
+                        }
+                    }
+                    KeyCode::Char('f') => {
+                        if let Some(hn) = self.selected_hackernews_mut() {
+                            hn.cycle_filter();
+                        } else if let Some(sports) = self.selected_sports_mut() {
+                            sports.cycle_league();
+                        }
+                    }
+                    KeyCode::Char('L') => {
+                        if let Some(sports) = self.selected_sports_mut() {
+                            sports.toggle_live_only();
+                        }
+                    }
                     KeyCode::Char('o') => self.open_selected_in_browser(),
+                    KeyCode::Char('y') => self.copy_selected_url(),
+                    KeyCode::Char('x') => self.github_mark_read(),
+                    KeyCode::Char('u') => {
+                        if let Some(github) = self.selected_github_mut() {
+                            github.toggle_unread_only();
+                        }
+                    }
                     KeyCode::Char('+') | KeyCode::Char('=') => self.handle_pixel_increase(),
                     KeyCode::Char('-') | KeyCode::Char('_') => self.handle_pixel_decrease(),
+                    KeyCode::Char('a') => self.handle_pixel_render_mode(),
+                    KeyCode::Char('w') => self.handle_pixel_auto_fit(),
                     KeyCode::Enter => {
-                        if self.is_twitter_selected() {
+                        if self.open_error_detail() {
+                            // The focused widget is showing an error
+                        } else if self.is_twitter_selected() {
                             self.twitter_read_tweet();
+                        } else if self.selected_hackernews().is_some() {
+                            self.open_hn_comments();
+                        } else if self.selected_podcast().is_some() {
+                            self.open_selected_in_browser();
                         } else {
                             self.open_article_reader();
                         }
                     }
-                    KeyCode::Tab => self.next_widget(),
+                    KeyCode::Char(c) if c.is_ascii_digit() => self.jump_to_number(c),
+                    KeyCode::Tab => {
+                        // A clock with several stopwatches cycles through
+                        // them before moving on
+                        let switched = self
+                            .selected_clock_mut()
+                            .is_some_and(|clock| clock.next_stopwatch());
+                        if !switched {
+                            self.next_widget();
+                        }
+                    }
                     KeyCode::BackTab => self.prev_widget(),
+                    KeyCode::Up if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                        self.move_selected(-1, 0)
+                    }
+                    KeyCode::Down if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                        self.move_selected(1, 0)
+                    }
+                    KeyCode::Left if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                        self.move_selected(0, -1)
+                    }
+                    KeyCode::Right if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                        self.move_selected(0, 1)
+                    }
                     KeyCode::Down | KeyCode::Char('j') => self.scroll_down(),
+                    KeyCode::Home | KeyCode::Char('g') => self.scroll_to_top(),
+                    KeyCode::End | KeyCode::Char('G') => self.scroll_to_bottom(),
+                    KeyCode::PageUp => self.page_up(),
+                    KeyCode::PageDown => self.page_down(),
                     KeyCode::Up | KeyCode::Char('k') => self.scroll_up(),
-                    KeyCode::Left | KeyCode::Char('h') => self.switch_tab_prev(),
-                    KeyCode::Right | KeyCode::Char('l') => self.switch_tab_next(),
+                    KeyCode::Left | KeyCode::Char('h') => self.scroll_left(),
+                    KeyCode::Right => self.scroll_right(),
+                    KeyCode::Char('l') => {
+                        // On a running stopwatch `l` takes a lap instead
+                        let lapped = self.handle_stopwatch_lap();
+                        if !lapped {
+                            self.scroll_right();
+                        }
+                    }
                     _ => {}
                 }
             }
-            Event::Tick => {}
+            Event::Tick => Spinner::advance(),
             Event::Resize(_, _) => {}
             Event::Mouse(_) => {}
         }
     }
 
     fn handle_feed_message(&mut self, msg: FeedMessage) {
-        for widget in &mut self.widgets {
+        let mut alerts = Vec::new();
+        for (idx, widget) in self.widgets.iter_mut().enumerate() {
             if widget.id() == msg.widget_id {
                 widget.update_data(msg.data.clone());
+                if !matches!(msg.data, FeedData::Loading) {
+                    self.latest_data[idx] = Some(msg.data.clone());
+                }
+                if widget.take_new_items() && self.config.general.flash_on_new {
+                    self.flashes.insert(idx, Instant::now());
+                }
+                alerts = widget.take_alerts();
                 break;
             }
         }
+        // A timeline's own fetcher only sends Loading; its refresh is done
+        // when a source's data merges
+        if self.refreshing.as_ref() == Some(&msg.widget_id)
+            && !matches!(msg.data, FeedData::Loading)
+        {
+            self.refreshing = None;
+            match &msg.data {
+                // Errors can carry a response body after the first line
+                FeedData::Error(e) => self.set_status(&format!(
+                    "Refresh failed: {}",
+                    e.lines().next().unwrap_or_default()
+                )),
+                _ => self.set_status("Refreshed"),
+            }
+        }
+        if !alerts.is_empty() {
+            self.set_status(&alerts.join(" | "));
+        }
+
+        // Timelines merge what their sources receive instead of fetching it
+        // again
+        for (widget_id, data) in
+            Self::route_to_timelines(&mut self.widgets, &msg.widget_id, &msg.data)
+        {
+            self.handle_feed_message(FeedMessage { widget_id, data });
+        }
     }
 
-    fn start_feed_fetchers(&self) {
-        for widget in &self.widgets {
-            let tx = self.feed_tx.clone();
-            let widget_id = widget.id();
-            let fetcher = widget.create_fetcher();
-            let refresh_interval = Duration::from_secs(self.config.general.refresh_interval_secs);
+    /// Give each timeline widget the widgets it merges. Runs after every
+    /// widget is built so sources may appear in any order.
+    pub fn connect_timelines(widgets: &mut [Box<dyn FeedWidget>]) {
+        for i in 0..widgets.len() {
+            let Some(source_ids) = widgets[i]
+                .as_any()
+                .and_then(|w| w.downcast_ref::<TimelineWidget>())
+                .map(|w| w.source_ids().to_vec())
+            else {
+                continue;
+            };
+
+            let mut sources = Vec::new();
+            let mut unknown = Vec::new();
+            for id in source_ids {
+                // Timelines can't include each other
+                let source = widgets
+                    .iter()
+                    .find(|w| w.id() == id && !id.starts_with("timeline-"));
+                match source {
+                    Some(w) => sources.push(TimelineSource::new(id, w.title().to_string())),
+                    None => unknown.push(id),
+                }
+            }
+
+            if let Some(timeline) = widgets[i]
+                .as_any_mut()
+                .and_then(|w| w.downcast_mut::<TimelineWidget>())
+            {
+                timeline.set_sources(sources, unknown);
+            }
+        }
+    }
+
+    /// Point timelines at the new ids of source widgets that moved
+    fn rename_timeline_sources(
+        widgets: &mut [Box<dyn FeedWidget>],
+        renamed: &HashMap<String, String>,
+    ) {
+        for widget in widgets {
+            if let Some(timeline) = widget
+                .as_any_mut()
+                .and_then(|w| w.downcast_mut::<TimelineWidget>())
+            {
+                timeline.rename_sources(renamed);
+            }
+        }
+    }
+
+    /// Hand data received by `source_id` to every timeline that merges it.
+    /// Returns the id and merged data of each timeline with something new
+    /// to show.
+    pub fn route_to_timelines(
+        widgets: &mut [Box<dyn FeedWidget>],
+        source_id: &str,
+        data: &FeedData,
+    ) -> Vec<(String, FeedData)> {
+        widgets
+            .iter_mut()
+            .filter_map(|w| {
+                let timeline = w.as_any_mut()?.downcast_mut::<TimelineWidget>()?;
+                let merged = timeline.merge_source(source_id, data)?;
+                Some((timeline.id(), merged))
+            })
+            .collect()
+    }
+
+    /// A widget's fetcher, or with `general.mock_data` set, one serving the
+    /// widget's entry from that file. Local widgets (clock, pixel art, ...)
+    /// keep their own.
+    pub fn create_fetcher(
+        config: &Config,
+        widget: &dyn FeedWidget,
+        client: &Arc<reqwest::Client>,
+    ) -> Box<dyn FeedFetcher> {
+        let fetcher = widget.create_fetcher(client.clone());
+        match &config.general.mock_data {
+            Some(path) if !fetcher.is_local() => {
+                Box::new(MockFetcher::from_file(path.clone(), widget.id()))
+            }
+            _ => fetcher,
+        }
+    }
+
+    fn start_feed_fetchers(&mut self) {
+        self.fetch_tasks = (0..self.widgets.len())
+            .map(|idx| self.spawn_fetcher(idx))
+            .collect();
+    }
 
+    /// Start the fetch loop of the widget at `idx`. Results are sent under
+    /// the widget's id at the time, so moving it means starting a new loop.
+    fn spawn_fetcher(&self, idx: usize) -> JoinHandle<()> {
+        // Widgets were built from `config.widgets` in order, one each
+        let widget = &self.widgets[idx];
+        let tx = self.feed_tx.clone();
+        let widget_id = widget.id();
+        let fetcher = Self::create_fetcher(&self.config, widget.as_ref(), &self.http_client);
+        let refresh_interval = self.config.widgets[idx].refresh_interval(&self.config.general);
+        let cache = self.cache.clone();
+        // Local fetchers (clock, pixel art, ...) ignore pausing
+        let mut paused = (!fetcher.is_local()).then(|| self.paused.subscribe());
+        // Until the widget has something to show, a failed attempt is shown
+        // right away instead of leaving it loading through every retry
+        let mut shown = self.latest_data[idx].is_some();
+
+        tokio::spawn(async move {
+            loop {
+                if let Some(paused) = &mut paused {
+                    let _ = paused.wait_for(|paused| !paused).await;
+                }
+                let data = if shown {
+                    fetch_with_timeout(fetcher.as_ref(), FETCH_RETRIES, FETCH_TIMEOUT).await
+                } else {
+                    let show_error = |data| {
+                        let _ = tx.send(FeedMessage {
+                            widget_id: widget_id.clone(),
+                            data,
+                        });
+                    };
+                    fetch_with_timeout_notify(
+                        fetcher.as_ref(),
+                        FETCH_RETRIES,
+                        FETCH_TIMEOUT,
+                        &show_error,
+                    )
+                    .await
+                };
+                shown = true;
+                if let FeedData::Error(e) = &data {
+                    logging::warn!(
+                        "fetch failed for {} ({}): {}",
+                        fetcher.source_name(),
+                        widget_id,
+                        e
+                    );
+                }
+                if let Some(cache) = &cache {
+                    if FeedCache::is_cacheable(&data) {
+                        let _ = cache.store(&widget_id, &data);
+                    }
+                }
+                let _ = tx.send(FeedMessage {
+                    widget_id: widget_id.clone(),
+                    data,
+                });
+                let wait = fetcher
+                    .min_poll_interval()
+                    .map_or(refresh_interval, |min| min.max(refresh_interval));
+                match &mut paused {
+                    // Pausing or resuming cuts the wait short, so resuming
+                    // fetches straight away
+                    Some(paused) => tokio::select! {
+                        _ = tokio::time::sleep(wait) => {}
+                        Ok(()) = paused.changed() => {}
+                    },
+                    None => tokio::time::sleep(wait).await,
+                }
+            }
+        })
+    }
+
+    /// Fetch the selected widget's data now, outside its schedule. The
+    /// result arrives like any other feed message.
+    fn refresh_selected(&mut self) {
+        let Some(widget) = self.widgets.get(self.selected_widget) else {
+            return;
+        };
+        let widget_id = widget.id();
+        // A timeline has nothing of its own to fetch, so refresh the
+        // widgets it merges; it reports back once their data merges
+        let mut targets: Vec<&dyn FeedWidget> = match widget
+            .as_any()
+            .and_then(|w| w.downcast_ref::<TimelineWidget>())
+        {
+            Some(timeline) => self
+                .widgets
+                .iter()
+                .filter(|w| timeline.merges(&w.id()))
+                .map(|w| w.as_ref())
+                .collect(),
+            None => Vec::new(),
+        };
+        if targets.is_empty() {
+            targets.push(widget.as_ref());
+        }
+        let fetches: Vec<_> = targets
+            .into_iter()
+            .map(|w| {
+                let fetcher = Self::create_fetcher(&self.config, w, &self.http_client);
+                (w.id(), fetcher)
+            })
+            .collect();
+        if *self.paused.borrow() && fetches.iter().any(|(_, f)| !f.is_local()) {
+            self.set_status("Fetching is paused (p to resume)");
+            return;
+        }
+
+        self.set_status(&format!("Refreshing {}...", widget.title()));
+        self.refreshing = Some(widget_id);
+
+        for (widget_id, fetcher) in fetches {
+            let tx = self.feed_tx.clone();
+            let cache = self.cache.clone();
             tokio::spawn(async move {
-                loop {
-                    match fetcher.fetch().await {
-                        Ok(data) => {
-                            let _ = tx.send(FeedMessage {
-                                widget_id: widget_id.clone(),
-                                data,
-                            });
-                        }
-                        Err(e) => {
-                            let _ = tx.send(FeedMessage {
-                                widget_id: widget_id.clone(),
-                                data: FeedData::Error(e.to_string()),
-                            });
-                        }
+                let data = fetch_with_timeout(fetcher.as_ref(), FETCH_RETRIES, FETCH_TIMEOUT).await;
+                if let Some(cache) = &cache {
+                    if FeedCache::is_cacheable(&data) {
+                        let _ = cache.store(&widget_id, &data);
                     }
-                    tokio::time::sleep(refresh_interval).await;
                 }
+                let _ = tx.send(FeedMessage { widget_id, data });
             });
         }
     }
 
+    /// Stop or restart all background fetching. Resuming refreshes every
+    /// widget straight away.
+    fn toggle_paused(&mut self) {
+        self.paused.send_modify(|paused| *paused = !*paused);
+        if *self.paused.borrow() {
+            self.set_status("Fetching paused");
+        } else {
+            self.set_status("Fetching resumed");
+        }
+    }
+
     fn refresh_all(&self) {
         // Fetchers run continuously, so this triggers an immediate refresh
         // by restarting the fetchers (simplified for now)
@@ -342,14 +909,17 @@ impl App {
     }
 
     fn next_widget(&mut self) {
+        self.number_jump.reset();
         if !self.widgets.is_empty() {
             self.widgets[self.selected_widget].set_selected(false);
             self.selected_widget = (self.selected_widget + 1) % self.widgets.len();
             self.widgets[self.selected_widget].set_selected(true);
+            self.follow_zoom();
         }
     }
 
     fn prev_widget(&mut self) {
+        self.number_jump.reset();
         if !self.widgets.is_empty() {
             self.widgets[self.selected_widget].set_selected(false);
             self.selected_widget = if self.selected_widget == 0 {
@@ -358,6 +928,125 @@ impl App {
                 self.selected_widget - 1
             };
             self.widgets[self.selected_widget].set_selected(true);
+            self.follow_zoom();
+        }
+    }
+
+    /// Fill the screen with the selected widget, or go back to the grid
+    fn toggle_zoom(&mut self) {
+        self.zoomed = match self.zoomed {
+            Some(_) => None,
+            None => self.widgets.get(self.selected_widget).map(|w| w.id()),
+        };
+    }
+
+    /// While zoomed, switching widgets zooms the newly selected one
+    fn follow_zoom(&mut self) {
+        if self.zoomed.is_some() {
+            self.zoomed = Some(self.widgets[self.selected_widget].id());
+        }
+    }
+
+    /// Move the selected widget one cell over, swapping places with the
+    /// widget already there. Widgets stay within the current grid.
+    fn move_selected(&mut self, d_row: isize, d_col: isize) {
+        let Some(widget) = self.widgets.get(self.selected_widget) else {
+            return;
+        };
+        let from = widget.position();
+        let (max_row, max_col) = self.calculate_grid_dimensions();
+        let to = match (
+            from.0.checked_add_signed(d_row),
+            from.1.checked_add_signed(d_col),
+        ) {
+            (Some(row), Some(col)) if row <= max_row && col <= max_col => (row, col),
+            _ => return,
+        };
+
+        let mut moves = vec![(self.selected_widget, to)];
+        if let Some(neighbor) = self.widgets.iter().position(|w| w.position() == to) {
+            moves.push((neighbor, from));
+        }
+        self.place_widgets(&moves);
+        self.layout_changed = true;
+    }
+
+    /// Move widgets to new cells, keeping the config in step. Ids follow
+    /// positions, so timeline sources are renamed and each moved widget's
+    /// fetch loop is restarted under its new id.
+    fn place_widgets(&mut self, moves: &[(usize, (usize, usize))]) {
+        let mut renamed = HashMap::new();
+        for &(idx, (row, col)) in moves {
+            let old_id = self.widgets[idx].id();
+            self.widgets[idx].set_position(row, col);
+            *self.config.widgets[idx].position_mut() = Position { row, col };
+            let new_id = self.widgets[idx].id();
+            if new_id != old_id {
+                renamed.insert(old_id, new_id);
+                if let Some(task) = self.fetch_tasks.get(idx) {
+                    task.abort();
+                    self.fetch_tasks[idx] = self.spawn_fetcher(idx);
+                }
+            }
+        }
+
+        // When two clocks swap, the second one moved removes the file the
+        // first just wrote, so write them again once every widget has moved
+        for &(idx, _) in moves {
+            if let Some(clock) = self.widgets[idx]
+                .as_any()
+                .and_then(|w| w.downcast_ref::<Clock>())
+            {
+                clock.save_stopwatch();
+            }
+        }
+
+        if let Some(new_id) = self.zoomed.as_ref().and_then(|id| renamed.get(id)) {
+            self.zoomed = Some(new_id.clone());
+        }
+        Self::rename_timeline_sources(&mut self.widgets, &renamed);
+        for widget_config in &mut self.config.widgets {
+            if let WidgetConfig::Timeline(timeline) = widget_config {
+                for source in &mut timeline.sources {
+                    if let Some(new_id) = renamed.get(source) {
+                        *source = new_id.clone();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Write every widget's latest data to `export.json` next to the config
+    /// file, keyed by widget id
+    fn export_feed_data(&mut self) {
+        let path = self.config_path.with_file_name("export.json");
+        let data = self
+            .widgets
+            .iter()
+            .zip(&self.latest_data)
+            .filter_map(|(widget, data)| Some((widget.id(), data.as_ref()?)));
+        let result = export::to_json(data).and_then(|json| {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            Ok(std::fs::write(&path, json)?)
+        });
+        match result {
+            Ok(()) => self.set_status(&format!("Exported feed data to {}", path.display())),
+            Err(e) => self.set_status(&format!("Export failed: {}", e)),
+        }
+    }
+
+    /// Write a rearranged layout back to the config file
+    fn save_layout(&self) {
+        if !self.layout_changed {
+            return;
+        }
+        if let Err(e) = self.config.save_layout(&self.config_path) {
+            eprintln!(
+                "Warning: Could not save the widget layout to {:?}: {}",
+                self.config_path, e
+            );
         }
     }
 
@@ -373,6 +1062,56 @@ impl App {
         }
     }
 
+    fn scroll_to_top(&mut self) {
+        if let Some(widget) = self.widgets.get_mut(self.selected_widget) {
+            widget.scroll_to_top();
+        }
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        if let Some(widget) = self.widgets.get_mut(self.selected_widget) {
+            widget.scroll_to_bottom();
+        }
+    }
+
+    fn page_up(&mut self) {
+        if let Some(widget) = self.widgets.get_mut(self.selected_widget) {
+            widget.page_up();
+        }
+    }
+
+    fn page_down(&mut self) {
+        if let Some(widget) = self.widgets.get_mut(self.selected_widget) {
+            widget.page_down();
+        }
+    }
+
+    /// Select the item whose displayed number is being typed on the focused widget
+    fn jump_to_number(&mut self, digit: char) {
+        let Some(digit) = digit.to_digit(10) else {
+            return;
+        };
+        if let Some(index) = self.number_jump.push(digit, Instant::now()) {
+            if let Some(widget) = self.widgets.get_mut(self.selected_widget) {
+                widget.select_index(index);
+            }
+        }
+    }
+
+    /// Pixel art pans left; everything else goes to the previous tab
+    fn scroll_left(&mut self) {
+        if !self.handle_pixel_pan(false) {
+            self.switch_tab_prev();
+        }
+    }
+
+    /// Pixel art pans right; everything else goes to the next tab
+    fn scroll_right(&mut self) {
+        if !self.handle_pixel_pan(true) {
+            self.switch_tab_next();
+        }
+    }
+
     fn switch_tab_next(&mut self) {
         if !self.widgets.is_empty() {
             if let Some(widget) = self.widgets.get_mut(self.selected_widget) {
@@ -400,40 +1139,66 @@ impl App {
     }
 
     fn render(&mut self, frame: &mut Frame) {
-        let area = frame.area();
+        let screen = frame.area();
 
-        // Calculate grid dimensions
-        let (max_row, max_col) = self.calculate_grid_dimensions();
+        // The key hints bar takes the bottom row, the grid the rest
+        let (area, hints_area) = if self.config.general.key_hints && screen.height > 1 {
+            let [area, hints_area] =
+                Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(screen);
+            (area, Some(hints_area))
+        } else {
+            (screen, None)
+        };
 
-        // Create row constraints
-        let row_constraints: Vec<Constraint> = (0..=max_row)
-            .map(|_| Constraint::Ratio(1, (max_row + 1) as u32))
-            .collect();
+        // A zoomed widget gets the whole screen to itself
+        let zoomed = self
+            .zoomed
+            .as_ref()
+            .and_then(|id| self.widgets.iter().position(|w| &w.id() == id));
 
-        let rows = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(row_constraints)
-            .split(area);
+        // Calculate grid dimensions
+        let (max_row, max_col) = match zoomed {
+            Some(_) => (0, 0),
+            None => self.calculate_grid_dimensions(),
+        };
+
+        let gap = self.config.layout.gap;
+        let min_width = (MIN_CELL_WIDTH.saturating_add(gap))
+            .saturating_mul(max_col as u16 + 1)
+            .saturating_sub(gap);
+        let min_height = (MIN_CELL_HEIGHT.saturating_add(gap))
+            .saturating_mul(max_row as u16 + 1)
+            .saturating_sub(gap);
+        if area.width < min_width || area.height < min_height {
+            Self::render_too_small(frame, area, min_width, min_height);
+            self.theme.apply(frame.buffer_mut(), None);
+            return;
+        }
 
-        // Create column constraints for each row
-        for row_idx in 0..=max_row {
-            let col_constraints: Vec<Constraint> = (0..=max_col)
-                .map(|_| Constraint::Ratio(1, (max_col + 1) as u32))
-                .collect();
+        let cells = match zoomed {
+            Some(idx) => vec![(idx, area)],
+            None => self.grid_cells(area, max_row, max_col),
+        };
 
-            let cols = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints(col_constraints)
-                .split(rows[row_idx]);
+        let mut focused_cell = None;
+        let mut flashing_cells = Vec::new();
 
-            // Render widgets in their positions
-            for (widget_idx, widget) in self.widgets.iter().enumerate() {
-                let pos = widget.position();
-                if pos.0 == row_idx && pos.1 <= max_col {
-                    let cell = cols[pos.1];
-                    widget.render(frame, cell, widget_idx == self.selected_widget);
-                }
+        for (widget_idx, cell) in cells {
+            let is_selected = widget_idx == self.selected_widget;
+            if is_selected {
+                focused_cell = Some(cell);
             }
+            self.widgets[widget_idx].render(frame, cell, is_selected);
+            if self.is_flash_visible(widget_idx) {
+                flashing_cells.push(cell);
+            }
+        }
+
+        let flash_style = Style::default()
+            .fg(Color::Magenta)
+            .add_modifier(Modifier::REVERSED);
+        for cell in flashing_cells {
+            style_border(frame.buffer_mut(), cell, flash_style);
         }
 
         // Render creature menu overlay if visible
@@ -448,8 +1213,96 @@ impl App {
             self.article_reader.render(frame, area);
         }
 
+        if self.comments_view.visible {
+            self.comments_view.render(frame, area);
+        }
+
+        if *self.paused.borrow() {
+            Self::render_paused_indicator(frame, area);
+        }
+
+        if let Some(hints_area) = hints_area {
+            self.render_key_hints(frame, hints_area);
+        }
+
         // Render status message if present
         self.render_status_message(frame, area);
+
+        // Strip colors last so overlays are covered too
+        self.theme.apply(frame.buffer_mut(), focused_cell);
+    }
+
+    /// The focused widget's keys followed by the ones that work everywhere,
+    /// as `t:Compose  r:Reply  /:Search`
+    fn render_key_hints(&self, frame: &mut Frame, area: Rect) {
+        use ratatui::text::{Line, Span};
+        use ratatui::widgets::Paragraph;
+
+        let zoom = if self.zoomed.is_some() {
+            "Unzoom"
+        } else {
+            "Zoom"
+        };
+        let mut hints = self
+            .widgets
+            .get(self.selected_widget)
+            .map(|w| w.key_hints())
+            .unwrap_or_default();
+        hints.extend([("Tab", "Next widget"), ("z", zoom), ("q", "Quit")]);
+
+        let mut spans = Vec::new();
+        for (key, action) in hints {
+            if !spans.is_empty() {
+                spans.push(Span::raw("  "));
+            }
+            spans.push(Span::styled(
+                key,
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::styled(
+                format!(":{}", action),
+                Style::default().fg(Color::Gray),
+            ));
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    /// Badge over the top-right border while fetching is paused
+    fn render_paused_indicator(frame: &mut Frame, area: Rect) {
+        use ratatui::widgets::Paragraph;
+
+        const LABEL: &str = " PAUSED ";
+        let width = (LABEL.len() as u16).min(area.width);
+        let badge = Rect::new(area.right().saturating_sub(width + 2), area.y, width, 1);
+        let paragraph = Paragraph::new(LABEL).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::REVERSED | Modifier::BOLD),
+        );
+        frame.render_widget(paragraph, badge);
+    }
+
+    /// Shown instead of the grid until the terminal is large enough again
+    fn render_too_small(frame: &mut Frame, area: Rect, min_width: u16, min_height: u16) {
+        use ratatui::layout::Alignment;
+        use ratatui::style::{Color, Style};
+        use ratatui::widgets::{Paragraph, Wrap};
+
+        let message = format!(
+            "Terminal too small (need {}x{}, have {}x{})",
+            min_width, min_height, area.width, area.height
+        );
+        let y = area.y + area.height.saturating_sub(1) / 2;
+        let message_area = Rect::new(area.x, y, area.width, area.height.min(2));
+
+        let paragraph = Paragraph::new(message)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .style(Style::default().fg(Color::Yellow));
+
+        frame.render_widget(paragraph, message_area);
     }
 
     fn render_status_message(&self, frame: &mut Frame, area: Rect) {
@@ -477,6 +1330,48 @@ impl App {
         }
     }
 
+    /// The cell of each widget in the grid, by widget index. Neighbouring
+    /// cells are `layout.gap` apart; there's no gap around the outside.
+    fn grid_cells(&self, area: Rect, max_row: usize, max_col: usize) -> Vec<(usize, Rect)> {
+        let gap = self.config.layout.gap;
+
+        // Create row constraints
+        let row_constraints: Vec<Constraint> = (0..=max_row)
+            .map(|_| Constraint::Ratio(1, (max_row + 1) as u32))
+            .collect();
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(row_constraints)
+            .spacing(gap)
+            .split(area);
+
+        let mut cells = Vec::new();
+
+        // Create column constraints for each row
+        for row_idx in 0..=max_row {
+            let col_constraints: Vec<Constraint> = (0..=max_col)
+                .map(|_| Constraint::Ratio(1, (max_col + 1) as u32))
+                .collect();
+
+            let cols = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(col_constraints)
+                .spacing(gap)
+                .split(rows[row_idx]);
+
+            // Place widgets in their positions
+            for (widget_idx, widget) in self.widgets.iter().enumerate() {
+                let pos = widget.position();
+                if pos.0 == row_idx && pos.1 <= max_col {
+                    cells.push((widget_idx, cols[pos.1]));
+                }
+            }
+        }
+
+        cells
+    }
+
     fn calculate_grid_dimensions(&self) -> (usize, usize) {
         let mut max_row = 0;
         let mut max_col = 0;
@@ -490,7 +1385,8 @@ impl App {
         (max_row, max_col)
     }
 
-    /// Tick the creature widget for animations and XP, and update clock widgets
+    /// Tick the creature widget for animations and XP, and update clock and
+    /// pixel art widgets
     fn tick_creature(&mut self) {
         if let Some(idx) = self.creature_widget_idx {
             // Tick animation
@@ -517,6 +1413,39 @@ impl App {
                 clock.tick_stopwatch();
             }
         }
+
+        // Advance animated pixel art
+        for widget in &mut self.widgets {
+            if let Some(art) = widget
+                .as_any_mut()
+                .and_then(|w| w.downcast_mut::<PixelArtWidget>())
+            {
+                art.tick();
+            }
+        }
+    }
+
+    /// Open the focused widget's error in the reader: the status line as
+    /// the title and the response body, truncated, as the text. Returns
+    /// false if the widget isn't showing an error.
+    fn open_error_detail(&mut self) -> bool {
+        let Some(widget) = self.widgets.get(self.selected_widget) else {
+            return false;
+        };
+        let Some(error) = widget.error_detail() else {
+            return false;
+        };
+
+        let (summary, body) = error.split_once('\n').unwrap_or((error, ""));
+        let item = SelectedItem {
+            title: summary.to_string(),
+            url: None,
+            description: (!body.is_empty()).then(|| truncate_with_ellipsis(body, MAX_ERROR_DETAIL)),
+            source: widget.title().to_string(),
+            metadata: None,
+        };
+        self.article_reader.show(item);
+        true
     }
 
     /// Open the article reader for the currently selected item
@@ -534,22 +1463,36 @@ impl App {
         }
     }
 
-    /// Open the selected item in the default browser
+    /// Open the selected item in the default browser, or its discussion page
+    /// if the item has no link of its own
     fn open_selected_in_browser(&mut self) {
-        if self.widgets.is_empty() {
+        let Some(widget) = self.widgets.get(self.selected_widget) else {
             return;
+        };
+        match widget.open_selected_url() {
+            Some(url) => self.open_url(&url),
+            None => self.set_status("No URL available"),
         }
+    }
 
-        if let Some(widget) = self.widgets.get(self.selected_widget) {
-            if let Some(item) = widget.get_selected_item() {
-                if let Some(url) = item.url {
-                    self.open_url(&url);
-                } else {
-                    self.set_status("No URL available");
-                }
-            } else {
-                self.set_status("No item selected");
-            }
+    /// Copy the selected item's URL to the system clipboard
+    fn copy_selected_url(&mut self) {
+        let Some(widget) = self.widgets.get(self.selected_widget) else {
+            return;
+        };
+        let Some(url) = widget.copy_selected_url() else {
+            self.set_status("No URL available");
+            return;
+        };
+
+        let result = match &mut self.clipboard {
+            Some(clipboard) => clipboard.set_text(url),
+            None => arboard::Clipboard::new()
+                .and_then(|clipboard| self.clipboard.insert(clipboard).set_text(url)),
+        };
+        match result {
+            Ok(()) => self.set_status("Copied URL to clipboard"),
+            Err(e) => self.set_status(&format!("Failed to copy URL: {}", e)),
         }
     }
 
@@ -563,11 +1506,16 @@ impl App {
         }
     }
 
-    /// Open a URL in the default browser
+    /// Open a URL in the default browser. Without one (e.g. over SSH) the
+    /// URL is shown instead so it can be opened by hand.
     fn open_url(&mut self, url: &str) {
+        if !has_display() {
+            self.set_status(&format!("No browser available: {}", url));
+            return;
+        }
         match open::that(url) {
             Ok(_) => self.set_status("Opening in browser..."),
-            Err(e) => self.set_status(&format!("Failed to open browser: {}", e)),
+            Err(_) => self.set_status(&format!("Couldn't open a browser: {}", url)),
         }
     }
 
@@ -583,6 +1531,17 @@ impl App {
                 self.status_message = None;
             }
         }
+        self.flashes
+            .retain(|_, started| started.elapsed() < FLASH_DURATION);
+    }
+
+    /// Whether a widget's border is in the "on" phase of its new-items flash
+    fn is_flash_visible(&self, widget_idx: usize) -> bool {
+        self.flashes.get(&widget_idx).is_some_and(|started| {
+            let elapsed = started.elapsed();
+            elapsed < FLASH_DURATION
+                && (elapsed.as_millis() / FLASH_BLINK.as_millis()).is_multiple_of(2)
+        })
     }
 
     /// Save creature state to disk
@@ -657,13 +1616,25 @@ impl App {
                         }
                         KeyCode::Char(c) => tw.add_char(c),
                         KeyCode::Backspace => tw.delete_char(),
+                        KeyCode::Left => tw.cursor_left(),
+                        KeyCode::Right => tw.cursor_right(),
+                        KeyCode::Home => tw.cursor_home(),
+                        KeyCode::End => tw.cursor_end(),
                         KeyCode::Enter => {
+                            use crate::ui::widgets::twitter::TwitterMode;
+                            if matches!(tw.get_mode(), TwitterMode::Compose | TwitterMode::Reply)
+                                && !tw.check_compose_length()
+                            {
+                                return;
+                            }
+
                             // Extract data needed for spawning command
                             let widget_id = tw.id();
                             let mode = tw.get_mode();
                             let compose_text = tw.get_compose_text().to_string();
                             let search_query = tw.get_search_query().to_string();
                             let tweet_url = tw.get_selected_tweet_url();
+                            let timeout = tw.command_timeout();
 
                             // Spawn the command
                             self.spawn_twitter_command_with_data(
@@ -672,6 +1643,7 @@ impl App {
                                 compose_text,
                                 search_query,
                                 tweet_url,
+                                timeout,
                             );
                         }
                         _ => {}
@@ -714,6 +1686,34 @@ impl App {
         }
     }
 
+    /// Run a bird command such as `like` or `retweet` on the selected tweet
+    fn twitter_act_on_selected(&mut self, command: &'static str, done: fn(String) -> TwitterData) {
+        let Some(tw) = self
+            .widgets
+            .get_mut(self.selected_widget)
+            .and_then(|w| w.as_any_mut())
+            .and_then(|w| w.downcast_mut::<TwitterWidget>())
+        else {
+            return;
+        };
+        let Some(url) = tw.action_target() else {
+            return;
+        };
+        let tx = self.twitter_tx.clone();
+        let widget_id = tw.id();
+        let timeout = tw.command_timeout();
+
+        tokio::spawn(async move {
+            let result =
+                TwitterWidget::execute_bird_command_static(&[command, &url], timeout).await;
+            let data = match result {
+                Ok(output) => done(output),
+                Err(e) => TwitterData::Error(e.to_string()),
+            };
+            let _ = tx.send(TwitterMessage { widget_id, data });
+        });
+    }
+
     fn twitter_open_search(&mut self) {
         if let Some(widget) = self.widgets.get_mut(self.selected_widget) {
             if let Some(tw) = widget
@@ -726,14 +1726,22 @@ impl App {
     }
 
     fn twitter_load_mentions(&mut self) {
-        if let Some(widget) = self.widgets.get(self.selected_widget) {
+        if let Some(tw) = self
+            .widgets
+            .get(self.selected_widget)
+            .and_then(|w| w.as_any())
+            .and_then(|w| w.downcast_ref::<TwitterWidget>())
+        {
             let tx = self.twitter_tx.clone();
-            let widget_id = widget.id();
+            let widget_id = tw.id();
+            let timeout = tw.command_timeout();
 
             tokio::spawn(async move {
-                let result =
-                    TwitterWidget::execute_bird_command_static(&["mentions", "--json", "-n", "5"])
-                        .await;
+                let result = TwitterWidget::execute_bird_command_static(
+                    &["mentions", "--json", "-n", "5"],
+                    timeout,
+                )
+                .await;
                 let data = match result {
                     Ok(output) => TwitterData::Mentions(twitter_parser::parse_json_tweets(&output)),
                     Err(e) => TwitterData::Error(e.to_string()),
@@ -752,11 +1760,14 @@ impl App {
                 if let Some(url) = tw.get_selected_tweet_url() {
                     let tx = self.twitter_tx.clone();
                     let widget_id = widget.id();
+                    let timeout = tw.command_timeout();
 
                     tokio::spawn(async move {
-                        let result =
-                            TwitterWidget::execute_bird_command_static(&["read", "--plain", &url])
-                                .await;
+                        let result = TwitterWidget::execute_bird_command_static(
+                            &["read", "--plain", &url],
+                            timeout,
+                        )
+                        .await;
                         let data = match result {
                             Ok(output) => TwitterData::TweetDetail(output),
                             Err(e) => TwitterData::Error(e.to_string()),
@@ -775,6 +1786,7 @@ impl App {
         compose_text: String,
         search_query: String,
         tweet_url: Option<String>,
+        timeout: Duration,
     ) {
         use crate::ui::widgets::twitter::TwitterMode;
 
@@ -783,11 +1795,10 @@ impl App {
         match mode {
             TwitterMode::Compose => {
                 tokio::spawn(async move {
-                    let result = TwitterWidget::execute_bird_command_static(&[
-                        "tweet",
-                        "--plain",
-                        &compose_text,
-                    ])
+                    let result = TwitterWidget::execute_bird_command_static(
+                        &["tweet", "--plain", &compose_text],
+                        timeout,
+                    )
                     .await;
                     let data = match result {
                         Ok(output) => TwitterData::TweetPosted(output),
@@ -799,12 +1810,10 @@ impl App {
             TwitterMode::Reply => {
                 if let Some(url) = tweet_url {
                     tokio::spawn(async move {
-                        let result = TwitterWidget::execute_bird_command_static(&[
-                            "reply",
-                            "--plain",
-                            &url,
-                            &compose_text,
-                        ])
+                        let result = TwitterWidget::execute_bird_command_static(
+                            &["reply", "--plain", &url, &compose_text],
+                            timeout,
+                        )
                         .await;
                         let data = match result {
                             Ok(output) => TwitterData::ReplyPosted(output),
@@ -816,13 +1825,10 @@ impl App {
             }
             TwitterMode::Search => {
                 tokio::spawn(async move {
-                    let result = TwitterWidget::execute_bird_command_static(&[
-                        "search",
-                        "--json",
-                        "-n",
-                        "5",
-                        &search_query,
-                    ])
+                    let result = TwitterWidget::execute_bird_command_static(
+                        &["search", "--json", "-n", "5", &search_query],
+                        timeout,
+                    )
                     .await;
                     let data = match result {
                         Ok(output) => {
@@ -851,6 +1857,179 @@ impl App {
         }
     }
 
+    /// The selected widget, if it is a sports scoreboard
+    fn selected_sports_mut(&mut self) -> Option<&mut SportsWidget> {
+        self.widgets
+            .get_mut(self.selected_widget)?
+            .as_any_mut()
+            .and_then(|w| w.downcast_mut::<SportsWidget>())
+    }
+
+    /// The selected widget, if it is a GitHub dashboard
+    fn selected_github_mut(&mut self) -> Option<&mut GithubWidget> {
+        self.widgets
+            .get_mut(self.selected_widget)?
+            .as_any_mut()
+            .and_then(|w| w.downcast_mut::<GithubWidget>())
+    }
+
+    /// The selected widget, if it is a clock
+    fn selected_clock_mut(&mut self) -> Option<&mut Clock> {
+        self.widgets
+            .get_mut(self.selected_widget)?
+            .as_any_mut()
+            .and_then(|w| w.downcast_mut::<Clock>())
+    }
+
+    /// The selected widget, if it is a podcast list
+    fn selected_podcast(&self) -> Option<&PodcastWidget> {
+        self.widgets
+            .get(self.selected_widget)?
+            .as_any()
+            .and_then(|w| w.downcast_ref::<PodcastWidget>())
+    }
+
+    /// The selected widget, if it is Hacker News
+    fn selected_hackernews(&self) -> Option<&HackernewsWidget> {
+        self.widgets
+            .get(self.selected_widget)?
+            .as_any()
+            .and_then(|w| w.downcast_ref::<HackernewsWidget>())
+    }
+
+    fn selected_hackernews_mut(&mut self) -> Option<&mut HackernewsWidget> {
+        self.widgets
+            .get_mut(self.selected_widget)?
+            .as_any_mut()
+            .and_then(|w| w.downcast_mut::<HackernewsWidget>())
+    }
+
+    /// Open the comment thread of the selected HN story, fetching it in the
+    /// background
+    fn open_hn_comments(&mut self) {
+        let Some(story) = self
+            .selected_hackernews()
+            .and_then(|hn| hn.selected_story())
+        else {
+            self.set_status("No item selected");
+            return;
+        };
+        let story_id = story.id;
+        self.comments_view.show_loading(
+            story_id,
+            format!("{} ({} comments)", story.title, story.descendants),
+        );
+
+        let tx = self.comments_tx.clone();
+        let client = self.http_client.clone();
+        tokio::spawn(async move {
+            let result = fetch_comments(&client, story_id)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(CommentsResult { story_id, result });
+        });
+    }
+
+    fn selected_youtube_mut(&mut self) -> Option<&mut YoutubeWidget> {
+        self.widgets
+            .get_mut(self.selected_widget)?
+            .as_any_mut()
+            .and_then(|w| w.downcast_mut::<YoutubeWidget>())
+    }
+
+    /// Download thumbnails YouTube widgets are waiting on
+    fn request_thumbnails(&mut self) {
+        for widget in &mut self.widgets {
+            let Some(youtube) = widget
+                .as_any_mut()
+                .and_then(|w| w.downcast_mut::<YoutubeWidget>())
+            else {
+                continue;
+            };
+            let Some((video_id, url)) = youtube.take_thumbnail_request() else {
+                continue;
+            };
+            let widget_id = youtube.id();
+            let tx = self.thumbnail_tx.clone();
+            let client = self.http_client.clone();
+
+            tokio::spawn(async move {
+                let result = fetch_thumbnail(&client, &url, THUMBNAIL_SIZE)
+                    .await
+                    .map_err(|e| e.to_string());
+                let _ = tx.send(ThumbnailResult {
+                    widget_id,
+                    video_id,
+                    result,
+                });
+            });
+        }
+    }
+
+    fn handle_thumbnail_result(&mut self, msg: ThumbnailResult) {
+        for widget in &mut self.widgets {
+            if widget.id() == msg.widget_id {
+                if let Some(youtube) = widget
+                    .as_any_mut()
+                    .and_then(|w| w.downcast_mut::<YoutubeWidget>())
+                {
+                    youtube.set_thumbnail(msg.video_id, msg.result);
+                }
+                break;
+            }
+        }
+    }
+
+    /// Mark the selected GitHub notification as read via the API
+    fn github_mark_read(&mut self) {
+        let Some(github) = self.selected_github_mut() else {
+            return;
+        };
+        if !github.can_mark_read() {
+            self.set_status("Marking as read is only supported for GitHub");
+            return;
+        }
+        let Some(thread_id) = github.selected_unread_notification() else {
+            return;
+        };
+        let widget_id = github.id();
+        let token = github.token().to_string();
+        let tx = self.mark_read_tx.clone();
+        let client = self.http_client.clone();
+
+        tokio::spawn(async move {
+            let result = mark_thread_read(&client, &token, &thread_id)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send(MarkReadResult {
+                widget_id,
+                thread_id,
+                result,
+            });
+        });
+    }
+
+    fn handle_mark_read_result(&mut self, msg: MarkReadResult) {
+        if let Err(e) = msg.result {
+            self.set_status(&format!(
+                "Failed to mark as read: {}",
+                e.lines().next().unwrap_or_default()
+            ));
+            return;
+        }
+        for widget in &mut self.widgets {
+            if widget.id() == msg.widget_id {
+                if let Some(github) = widget
+                    .as_any_mut()
+                    .and_then(|w| w.downcast_mut::<GithubWidget>())
+                {
+                    github.mark_read(&msg.thread_id);
+                }
+                break;
+            }
+        }
+    }
+
     /// Increase pixel size on selected pixel art widget
     fn handle_pixel_increase(&mut self) {
         if !self.widgets.is_empty() {
@@ -865,6 +2044,49 @@ impl App {
         }
     }
 
+    /// Pan the selected pixel art widget left or right
+    /// Returns true if a pixel art widget is selected, false otherwise
+    fn handle_pixel_pan(&mut self, right: bool) -> bool {
+        let Some(pixel_art) = self
+            .widgets
+            .get_mut(self.selected_widget)
+            .and_then(|w| w.as_any_mut())
+            .and_then(|w| w.downcast_mut::<PixelArtWidget>())
+        else {
+            return false;
+        };
+        if right {
+            pixel_art.scroll_right();
+        } else {
+            pixel_art.scroll_left();
+        }
+        true
+    }
+
+    /// Cycle the render mode of the selected pixel art widget
+    fn handle_pixel_render_mode(&mut self) {
+        if let Some(pixel_art) = self
+            .widgets
+            .get_mut(self.selected_widget)
+            .and_then(|w| w.as_any_mut())
+            .and_then(|w| w.downcast_mut::<PixelArtWidget>())
+        {
+            pixel_art.toggle_render_mode();
+        }
+    }
+
+    /// Toggle fitting the selected pixel art widget's image to the widget
+    fn handle_pixel_auto_fit(&mut self) {
+        if let Some(pixel_art) = self
+            .widgets
+            .get_mut(self.selected_widget)
+            .and_then(|w| w.as_any_mut())
+            .and_then(|w| w.downcast_mut::<PixelArtWidget>())
+        {
+            pixel_art.toggle_auto_fit();
+        }
+    }
+
     /// Toggle stopwatch on the selected clock widget
     fn handle_stopwatch_toggle(&mut self) {
         if !self.widgets.is_empty() {
@@ -890,6 +2112,16 @@ impl App {
         }
     }
 
+    /// Record a lap on the selected clock widget's running stopwatch
+    /// Returns true if a lap was recorded, false otherwise
+    fn handle_stopwatch_lap(&mut self) -> bool {
+        self.widgets
+            .get_mut(self.selected_widget)
+            .and_then(|w| w.as_any_mut())
+            .and_then(|w| w.downcast_mut::<Clock>())
+            .is_some_and(|clock| clock.lap_stopwatch())
+    }
+
     /// Reset stopwatch on the selected clock widget
     /// Returns true if a clock widget was reset, false otherwise
     fn handle_stopwatch_reset(&mut self) -> bool {
@@ -904,3 +2136,65 @@ impl App {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feeds::RssItem;
+
+    fn rss(title: &str) -> FeedData {
+        FeedData::Rss(vec![RssItem {
+            title: title.to_string(),
+            link: None,
+            published: None,
+            timestamp: Some(1),
+            source: "feed".to_string(),
+            description: None,
+        }])
+    }
+
+    #[test]
+    fn test_timeline_follows_sources_that_move() {
+        let config: Config = toml::from_str(
+            r#"
+            [[widgets]]
+            type = "rss"
+            title = "Left"
+            position = { row = 0, col = 0 }
+
+            [[widgets]]
+            type = "rss"
+            title = "Right"
+            position = { row = 0, col = 1 }
+
+            [[widgets]]
+            type = "timeline"
+            sources = ["rss-0-0"]
+            position = { row = 1, col = 0 }
+            "#,
+        )
+        .unwrap();
+        let mut widgets = App::build_widgets(&config, &Creature::default());
+        App::connect_timelines(&mut widgets);
+
+        // Swap the two feeds, as Shift+Right on the left one does
+        let mut renamed = HashMap::new();
+        for (idx, col) in [(0, 1), (1, 0)] {
+            let old_id = widgets[idx].id();
+            widgets[idx].set_position(0, col);
+            renamed.insert(old_id, widgets[idx].id());
+        }
+        App::rename_timeline_sources(&mut widgets, &renamed);
+
+        // The feed now in the old source's cell isn't merged
+        assert!(App::route_to_timelines(&mut widgets, "rss-0-0", &rss("right")).is_empty());
+
+        let merged = App::route_to_timelines(&mut widgets, "rss-0-1", &rss("left"));
+        let [(timeline_id, FeedData::Timeline(items))] = merged.as_slice() else {
+            panic!("expected the moved source to be merged");
+        };
+        assert_eq!(timeline_id, "timeline-1-0");
+        assert_eq!(items[0].title, "left");
+        assert_eq!(items[0].source, "Left");
+    }
+}
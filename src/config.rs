@@ -1,27 +1,84 @@
 use anyhow::Result;
+use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     #[serde(default)]
     pub general: GeneralConfig,
     #[serde(default)]
+    pub layout: LayoutConfig,
+    #[serde(default)]
     pub widgets: Vec<WidgetConfig>,
 }
 
+/// How the widget grid is laid out
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LayoutConfig {
+    /// Empty cells between neighbouring widgets, both across and down
+    #[serde(default)]
+    pub gap: u16,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GeneralConfig {
     #[serde(default = "default_refresh_interval")]
     pub refresh_interval_secs: u64,
     #[serde(default = "default_theme")]
     pub theme: String,
+    #[serde(default)]
+    pub icons: IconSet,
+    /// Briefly flash a widget's border when a refresh brings in new items
+    #[serde(default)]
+    pub flash_on_new: bool,
+    /// Show widgets' last fetched data at startup if it is at most this many
+    /// minutes old; 0 turns the cache off
+    #[serde(default = "default_cache_ttl_minutes")]
+    pub cache_ttl_minutes: u64,
+    /// Show the focused widget's keys in a bar along the bottom
+    #[serde(default = "default_key_hints")]
+    pub key_hints: bool,
+    #[serde(default)]
+    pub palette: PaletteConfig,
+    /// JSON file in the export format (keyed by widget id) to show instead
+    /// of fetching, for demos and screenshots
+    #[serde(default)]
+    pub mock_data: Option<PathBuf>,
+}
+
+/// Colors to use instead of the defaults. Each takes a name (`"cyan"`,
+/// `"dark gray"`), a hex code (`"#ffaf00"`) or a 256-color index (`"208"`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaletteConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub border: Option<Color>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub border_selected: Option<Color>,
+    /// Headings, repository names and other highlights
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent: Option<Color>,
+    /// Secondary text such as timestamps and hints
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dim: Option<Color>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Color>,
 }
 
 fn default_refresh_interval() -> u64 {
     60
 }
 
+fn default_cache_ttl_minutes() -> u64 {
+    60
+}
+
+fn default_key_hints() -> bool {
+    true
+}
+
 fn default_theme() -> String {
     "dark".to_string()
 }
@@ -31,6 +88,12 @@ impl Default for GeneralConfig {
         Self {
             refresh_interval_secs: default_refresh_interval(),
             theme: default_theme(),
+            icons: IconSet::default(),
+            flash_on_new: false,
+            cache_ttl_minutes: default_cache_ttl_minutes(),
+            key_hints: default_key_hints(),
+            palette: PaletteConfig::default(),
+            mock_data: None,
         }
     }
 }
@@ -44,10 +107,130 @@ pub enum WidgetConfig {
     Rss(RssConfig),
     Creature(CreatureConfig),
     Github(GithubConfig),
+    Gitlab(GitlabConfig),
     Youtube(YoutubeConfig),
     Twitter(TwitterConfig),
     Pixelart(PixelArtConfig),
     Clock(ClockConfig),
+    Timeline(TimelineConfig),
+    Reddit(RedditConfig),
+    Mastodon(MastodonConfig),
+    Bluesky(BlueskyConfig),
+    Weather(WeatherConfig),
+    Calendar(CalendarConfig),
+    Releases(ReleasesConfig),
+    Podcast(PodcastConfig),
+    Arxiv(ArxivConfig),
+    Crypto(CryptoConfig),
+    Command(CommandConfig),
+    Jsonpoll(JsonPollConfig),
+    News(NewsConfig),
+}
+
+/// Per-widget refresh intervals shorter than this are raised to it, so a
+/// typo can't hammer an API
+pub const MIN_REFRESH_SECS: u64 = 5;
+
+impl WidgetConfig {
+    /// The widget's own `refresh_secs`, if it has one set
+    fn refresh_secs(&self) -> Option<u64> {
+        match self {
+            WidgetConfig::Stocks(cfg) => cfg.refresh_secs,
+            WidgetConfig::Hackernews(cfg) => cfg.refresh_secs,
+            WidgetConfig::Sports(cfg) => cfg.refresh_secs,
+            WidgetConfig::Rss(cfg) => cfg.refresh_secs,
+            WidgetConfig::Github(cfg) => cfg.refresh_secs,
+            WidgetConfig::Gitlab(cfg) => cfg.refresh_secs,
+            WidgetConfig::Youtube(cfg) => cfg.refresh_secs,
+            WidgetConfig::Twitter(cfg) => cfg.refresh_secs,
+            WidgetConfig::Reddit(cfg) => cfg.refresh_secs,
+            WidgetConfig::Mastodon(cfg) => cfg.refresh_secs,
+            WidgetConfig::Bluesky(cfg) => cfg.refresh_secs,
+            WidgetConfig::Weather(cfg) => cfg.refresh_secs,
+            WidgetConfig::Calendar(cfg) => cfg.refresh_secs,
+            WidgetConfig::Releases(cfg) => cfg.refresh_secs,
+            WidgetConfig::Podcast(cfg) => cfg.refresh_secs,
+            WidgetConfig::Arxiv(cfg) => cfg.refresh_secs,
+            WidgetConfig::Crypto(cfg) => cfg.refresh_secs,
+            WidgetConfig::Command(cfg) => cfg.refresh_secs,
+            WidgetConfig::Jsonpoll(cfg) => cfg.refresh_secs,
+            WidgetConfig::News(cfg) => cfg.refresh_secs,
+            WidgetConfig::Creature(_)
+            | WidgetConfig::Pixelart(_)
+            | WidgetConfig::Clock(_)
+            | WidgetConfig::Timeline(_) => None,
+        }
+    }
+
+    /// How long to wait between fetches: the widget's `refresh_secs`
+    /// (at least `MIN_REFRESH_SECS`), else the global interval
+    pub fn refresh_interval(&self, general: &GeneralConfig) -> Duration {
+        let secs = self
+            .refresh_secs()
+            .map_or(general.refresh_interval_secs, |secs| {
+                secs.max(MIN_REFRESH_SECS)
+            });
+        Duration::from_secs(secs)
+    }
+
+    /// The grid cell the widget is drawn in
+    pub fn position(&self) -> &Position {
+        match self {
+            WidgetConfig::Stocks(cfg) => &cfg.position,
+            WidgetConfig::Hackernews(cfg) => &cfg.position,
+            WidgetConfig::Sports(cfg) => &cfg.position,
+            WidgetConfig::Rss(cfg) => &cfg.position,
+            WidgetConfig::Creature(cfg) => &cfg.position,
+            WidgetConfig::Github(cfg) => &cfg.position,
+            WidgetConfig::Gitlab(cfg) => &cfg.position,
+            WidgetConfig::Youtube(cfg) => &cfg.position,
+            WidgetConfig::Twitter(cfg) => &cfg.position,
+            WidgetConfig::Pixelart(cfg) => &cfg.position,
+            WidgetConfig::Clock(cfg) => &cfg.position,
+            WidgetConfig::Timeline(cfg) => &cfg.position,
+            WidgetConfig::Reddit(cfg) => &cfg.position,
+            WidgetConfig::Mastodon(cfg) => &cfg.position,
+            WidgetConfig::Bluesky(cfg) => &cfg.position,
+            WidgetConfig::Weather(cfg) => &cfg.position,
+            WidgetConfig::Calendar(cfg) => &cfg.position,
+            WidgetConfig::Releases(cfg) => &cfg.position,
+            WidgetConfig::Podcast(cfg) => &cfg.position,
+            WidgetConfig::Arxiv(cfg) => &cfg.position,
+            WidgetConfig::Crypto(cfg) => &cfg.position,
+            WidgetConfig::Command(cfg) => &cfg.position,
+            WidgetConfig::Jsonpoll(cfg) => &cfg.position,
+            WidgetConfig::News(cfg) => &cfg.position,
+        }
+    }
+
+    pub fn position_mut(&mut self) -> &mut Position {
+        match self {
+            WidgetConfig::Stocks(cfg) => &mut cfg.position,
+            WidgetConfig::Hackernews(cfg) => &mut cfg.position,
+            WidgetConfig::Sports(cfg) => &mut cfg.position,
+            WidgetConfig::Rss(cfg) => &mut cfg.position,
+            WidgetConfig::Creature(cfg) => &mut cfg.position,
+            WidgetConfig::Github(cfg) => &mut cfg.position,
+            WidgetConfig::Gitlab(cfg) => &mut cfg.position,
+            WidgetConfig::Youtube(cfg) => &mut cfg.position,
+            WidgetConfig::Twitter(cfg) => &mut cfg.position,
+            WidgetConfig::Pixelart(cfg) => &mut cfg.position,
+            WidgetConfig::Clock(cfg) => &mut cfg.position,
+            WidgetConfig::Timeline(cfg) => &mut cfg.position,
+            WidgetConfig::Reddit(cfg) => &mut cfg.position,
+            WidgetConfig::Mastodon(cfg) => &mut cfg.position,
+            WidgetConfig::Bluesky(cfg) => &mut cfg.position,
+            WidgetConfig::Weather(cfg) => &mut cfg.position,
+            WidgetConfig::Calendar(cfg) => &mut cfg.position,
+            WidgetConfig::Releases(cfg) => &mut cfg.position,
+            WidgetConfig::Podcast(cfg) => &mut cfg.position,
+            WidgetConfig::Arxiv(cfg) => &mut cfg.position,
+            WidgetConfig::Crypto(cfg) => &mut cfg.position,
+            WidgetConfig::Command(cfg) => &mut cfg.position,
+            WidgetConfig::Jsonpoll(cfg) => &mut cfg.position,
+            WidgetConfig::News(cfg) => &mut cfg.position,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,14 +252,118 @@ pub struct Position {
     pub col: usize,
 }
 
+/// HTTP client options for widgets that talk to self-hosted services
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HttpConfig {
+    /// Proxy for this widget's requests, e.g. `http://proxy.local:8080`
+    #[serde(default)]
+    pub proxy: Option<String>,
+    /// INSECURE: accept invalid and self-signed TLS certificates. This
+    /// disables certificate checks entirely, so only enable it for hosts you
+    /// control.
+    #[serde(default)]
+    pub danger_accept_invalid_certs: bool,
+}
+
+/// Which built-in glyphs the GitHub and GitLab rows use for their prefix
+/// icons
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IconSet {
+    /// Standard Unicode symbols and emoji
+    #[default]
+    Unicode,
+    /// Nerd Font glyphs, for terminals with a patched font
+    Nerd,
+    /// Plain ASCII for terminals without Unicode symbol support
+    Ascii,
+}
+
+/// How the secondary (meta) line of a list item handles text wider than its cell
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Overflow {
+    /// Cut the line with an ellipsis so every item keeps the same height
+    #[default]
+    Truncate,
+    /// Continue the line on as many rows as it needs
+    Wrap,
+}
+
+/// Order of the GitHub notifications tab
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GithubSort {
+    /// Unread notifications first, each group most recently updated first
+    #[default]
+    Unread,
+    /// Most recently updated first
+    Updated,
+    /// Grouped by repository name, most recently updated first within each
+    Repository,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StocksConfig {
     #[serde(default = "default_stocks_title")]
     pub title: String,
     pub symbols: Vec<String>,
+    /// Price thresholds that highlight a quote and raise a status message
+    #[serde(default)]
+    pub alerts: Vec<StockAlert>,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
     pub position: Position,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CryptoConfig {
+    #[serde(default = "default_crypto_title")]
+    pub title: String,
+    pub coins: Vec<CryptoCoin>,
+    /// Currency prices are quoted in, e.g. `usd` or `eur`
+    #[serde(default = "default_crypto_currency")]
+    pub currency: String,
+    /// Thresholds on the coins' symbols, as for stocks
+    #[serde(default)]
+    pub alerts: Vec<StockAlert>,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
+    pub position: Position,
+}
+
+/// A CoinGecko coin id (`bitcoin`) and the symbol to show for it (`BTC`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CryptoCoin {
+    pub id: String,
+    pub symbol: String,
+}
+
+fn default_crypto_title() -> String {
+    "Crypto".to_string()
+}
+
+fn default_crypto_currency() -> String {
+    "usd".to_string()
+}
+
+/// Alert when a symbol's price crosses `price` in the given direction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockAlert {
+    pub symbol: String,
+    pub direction: AlertDirection,
+    pub price: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertDirection {
+    Above,
+    Below,
+}
+
 fn default_stocks_title() -> String {
     "Stocks".to_string()
 }
@@ -89,6 +376,11 @@ pub struct HackernewsConfig {
     pub story_count: usize,
     #[serde(default = "default_story_type")]
     pub story_type: String,
+    #[serde(default)]
+    pub overflow: Overflow,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
     pub position: Position,
 }
 
@@ -104,14 +396,276 @@ fn default_story_type() -> String {
     "top".to_string()
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedditConfig {
+    #[serde(default = "default_reddit_title")]
+    pub title: String,
+    /// Subreddit name without the `r/` prefix, e.g. `"rust"`
+    pub subreddit: String,
+    #[serde(default = "default_max_items")]
+    pub max_items: usize,
+    #[serde(default)]
+    pub overflow: Overflow,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
+    pub position: Position,
+}
+
+fn default_reddit_title() -> String {
+    "Reddit".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MastodonConfig {
+    #[serde(default = "default_mastodon_title")]
+    pub title: String,
+    /// Base URL of the instance, e.g. `"https://mastodon.social"`
+    pub instance: String,
+    pub access_token: String,
+    #[serde(default = "default_max_items")]
+    pub max_items: usize,
+    #[serde(default)]
+    pub overflow: Overflow,
+    #[serde(default)]
+    pub http: HttpConfig,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
+    pub position: Position,
+}
+
+fn default_mastodon_title() -> String {
+    "Mastodon".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlueskyConfig {
+    #[serde(default = "default_bluesky_title")]
+    pub title: String,
+    /// Base URL of the account's PDS
+    #[serde(default = "default_bluesky_service")]
+    pub service: String,
+    /// Handle or email used to log in
+    pub identifier: String,
+    /// App password from Settings > Privacy and security > App passwords
+    pub app_password: String,
+    #[serde(default = "default_max_items")]
+    pub max_items: usize,
+    #[serde(default)]
+    pub overflow: Overflow,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
+    pub position: Position,
+}
+
+fn default_bluesky_title() -> String {
+    "Bluesky".to_string()
+}
+
+fn default_bluesky_service() -> String {
+    "https://bsky.social".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeatherConfig {
+    #[serde(default = "default_weather_title")]
+    pub title: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    #[serde(default)]
+    pub units: TemperatureUnit,
+    /// How many hours of forecast to show after the current conditions
+    #[serde(default = "default_forecast_hours")]
+    pub hours: usize,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+fn default_weather_title() -> String {
+    "Weather".to_string()
+}
+
+fn default_forecast_hours() -> usize {
+    6
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarConfig {
+    #[serde(default = "default_calendar_title")]
+    pub title: String,
+    /// `.ics` URL (http, https or webcal) or path to a local `.ics` file
+    pub source: String,
+    #[serde(default = "default_max_items")]
+    pub max_items: usize,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
+    pub position: Position,
+}
+
+fn default_calendar_title() -> String {
+    "Calendar".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleasesConfig {
+    #[serde(default = "default_releases_title")]
+    pub title: String,
+    /// Packages to watch, as `crate:<name>` or `npm:<name>`
+    pub packages: Vec<String>,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
+    pub position: Position,
+}
+
+fn default_releases_title() -> String {
+    "Releases".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodcastConfig {
+    #[serde(default = "default_podcast_title")]
+    pub title: String,
+    /// Podcast RSS feed URLs
+    pub feeds: Vec<String>,
+    #[serde(default = "default_max_items")]
+    pub max_items: usize,
+    #[serde(default)]
+    pub overflow: Overflow,
+    #[serde(default)]
+    pub http: HttpConfig,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
+    pub position: Position,
+}
+
+fn default_podcast_title() -> String {
+    "Podcasts".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArxivConfig {
+    #[serde(default = "default_arxiv_title")]
+    pub title: String,
+    /// arXiv category, e.g. `cs.LG` or `math.AG`
+    pub category: String,
+    #[serde(default = "default_max_items")]
+    pub max_items: usize,
+    #[serde(default)]
+    pub overflow: Overflow,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
+    pub position: Position,
+}
+
+fn default_arxiv_title() -> String {
+    "arXiv".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandConfig {
+    #[serde(default = "default_command_title")]
+    pub title: String,
+    /// Shell command run on every refresh, e.g. `"df -h"`
+    pub command: String,
+    /// Seconds to wait for the command before giving up on it
+    #[serde(default = "default_command_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
+    pub position: Position,
+}
+
+fn default_command_title() -> String {
+    "Command".to_string()
+}
+
+fn default_command_timeout_secs() -> u64 {
+    10
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonPollConfig {
+    #[serde(default = "default_json_poll_title")]
+    pub title: String,
+    /// Endpoint returning JSON, polled on every refresh
+    pub url: String,
+    #[serde(flatten)]
+    pub paths: JsonPaths,
+    #[serde(default = "default_max_items")]
+    pub max_items: usize,
+    #[serde(default)]
+    pub overflow: Overflow,
+    #[serde(default)]
+    pub http: HttpConfig,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
+    pub position: Position,
+}
+
+/// Where each item field lives in the response, as dotted paths such as
+/// `"$.data.items"` or `"author.name"`. Array elements are addressed as
+/// `"tags[0]"`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonPaths {
+    /// Path to the array of items; the response root by default
+    #[serde(default)]
+    pub items_path: Option<String>,
+    /// Item title, relative to each item
+    pub title_path: String,
+    /// Item link, relative to each item
+    #[serde(default)]
+    pub url_path: Option<String>,
+    /// Text shown under the title, relative to each item
+    #[serde(default)]
+    pub subtitle_path: Option<String>,
+}
+
+fn default_json_poll_title() -> String {
+    "JSON Feed".to_string()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SportsConfig {
     #[serde(default = "default_sports_title")]
     pub title: String,
+    #[serde(default)]
+    pub provider: SportsProvider,
     pub leagues: Vec<String>,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
     pub position: Position,
 }
 
+/// Where the sports widget gets its scores from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SportsProvider {
+    /// ESPN's public scoreboard API (live and scheduled games)
+    #[default]
+    Espn,
+    /// TheSportsDB free API (recently finished games)
+    Thesportsdb,
+}
+
 fn default_sports_title() -> String {
     "Sports".to_string()
 }
@@ -120,9 +674,19 @@ fn default_sports_title() -> String {
 pub struct RssConfig {
     #[serde(default = "default_rss_title")]
     pub title: String,
+    #[serde(default)]
     pub feeds: Vec<String>,
+    /// OPML file whose subscriptions are fetched alongside `feeds`
+    pub opml_path: Option<PathBuf>,
     #[serde(default = "default_max_items")]
     pub max_items: usize,
+    #[serde(default)]
+    pub overflow: Overflow,
+    #[serde(default)]
+    pub http: HttpConfig,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
     pub position: Position,
 }
 
@@ -146,15 +710,65 @@ pub struct GithubConfig {
     pub show_pull_requests: bool,
     #[serde(default = "default_show_commits")]
     pub show_commits: bool,
+    /// Include notifications already marked read, not just unread ones
+    #[serde(default)]
+    pub show_all: bool,
     #[serde(default = "default_max_notifications")]
     pub max_notifications: usize,
     #[serde(default = "default_max_pull_requests")]
     pub max_pull_requests: usize,
     #[serde(default = "default_max_commits")]
     pub max_commits: usize,
+    /// Initial order of the notifications tab; `s` cycles through the others
+    #[serde(default)]
+    pub sort: GithubSort,
+    /// Start with notifications listed under a header per repository
+    /// (toggled with `v`)
+    #[serde(default)]
+    pub grouped: bool,
+    #[serde(default)]
+    pub overflow: Overflow,
+    /// Per item-type icon overrides, e.g. `{ pull_request = "PR" }`
+    #[serde(default)]
+    pub icons: HashMap<String, String>,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitlabConfig {
+    #[serde(default = "default_gitlab_title")]
+    pub title: String,
+    /// Instance URL; change it for self-hosted GitLab
+    #[serde(default = "default_gitlab_url")]
+    pub base_url: String,
+    /// Personal access token with the `read_api` scope
+    pub token: String,
+    #[serde(default = "default_max_notifications")]
+    pub max_todos: usize,
+    #[serde(default)]
+    pub overflow: Overflow,
+    /// Per item-type icon overrides, e.g. `{ merge_request = "MR" }`
+    #[serde(default)]
+    pub icons: HashMap<String, String>,
+    #[serde(default)]
+    pub http: HttpConfig,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
     pub position: Position,
 }
 
+fn default_gitlab_title() -> String {
+    "GitLab To-Dos".to_string()
+}
+
+fn default_gitlab_url() -> String {
+    "https://gitlab.com".to_string()
+}
+
 fn default_github_title() -> String {
     "GitHub Dashboard".to_string()
 }
@@ -187,6 +801,8 @@ fn default_max_commits() -> usize {
 pub struct YoutubeConfig {
     #[serde(default = "default_youtube_title")]
     pub title: String,
+    /// Only needed for `search_query`; channels are read from their RSS feeds
+    #[serde(default)]
     pub api_key: String,
     #[serde(default)]
     pub channels: Vec<String>,
@@ -194,6 +810,11 @@ pub struct YoutubeConfig {
     pub search_query: Option<String>,
     #[serde(default = "default_max_videos")]
     pub max_videos: usize,
+    #[serde(default)]
+    pub overflow: Overflow,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
     pub position: Position,
 }
 
@@ -209,6 +830,12 @@ fn default_max_videos() -> usize {
 pub struct TwitterConfig {
     #[serde(default = "default_twitter_title")]
     pub title: String,
+    /// Seconds to wait for a `bird` command before giving up on it
+    #[serde(default = "default_bird_timeout_secs")]
+    pub command_timeout_secs: u64,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
     pub position: Position,
 }
 
@@ -216,12 +843,28 @@ fn default_twitter_title() -> String {
     "Twitter/X".to_string()
 }
 
+fn default_bird_timeout_secs() -> u64 {
+    15
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PixelArtConfig {
     #[serde(default = "default_pixelart_title")]
     pub title: String,
     pub image_path: Option<PathBuf>,
     pub pixel_size: Option<u32>,
+    /// Halve the image height so it isn't stretched by terminal cells being
+    /// about twice as tall as they are wide
+    #[serde(default)]
+    pub correct_aspect: bool,
+    /// Pick the largest pixel size that fits the widget without scrolling,
+    /// refitting when the widget is resized
+    #[serde(default)]
+    pub auto_fit: bool,
+    /// Spread each pixel's rounding error over its neighbours in ASCII
+    /// mode, so gradients come out as shading rather than bands
+    #[serde(default)]
+    pub dither: bool,
     pub position: Position,
 }
 
@@ -235,6 +878,16 @@ pub struct ClockConfig {
     pub title: String,
     #[serde(default = "default_timezones")]
     pub timezones: Vec<String>,
+    /// strftime format for each clock's time, e.g. `"%I:%M %p"` for 12-hour
+    #[serde(default = "default_time_format")]
+    pub time_format: String,
+    /// strftime format for each clock's date
+    #[serde(default = "default_date_format")]
+    pub date_format: String,
+    /// Draw the time in large block digits. Applies when a single timezone
+    /// is configured.
+    #[serde(default)]
+    pub big_font: bool,
     pub position: Position,
 }
 
@@ -242,6 +895,14 @@ fn default_clock_title() -> String {
     "World Clock".to_string()
 }
 
+pub fn default_time_format() -> String {
+    "%H:%M:%S".to_string()
+}
+
+pub fn default_date_format() -> String {
+    "%b %d".to_string()
+}
+
 fn default_timezones() -> Vec<String> {
     vec![
         "America/New_York".to_string(),
@@ -250,18 +911,107 @@ fn default_timezones() -> Vec<String> {
     ]
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineConfig {
+    #[serde(default = "default_timeline_title")]
+    pub title: String,
+    /// Ids of the widgets to merge, e.g. `"hackernews-0-0"` (`"{type}-{row}-{col}"`)
+    pub sources: Vec<String>,
+    #[serde(default = "default_timeline_max_items")]
+    pub max_items: usize,
+    #[serde(default)]
+    pub overflow: Overflow,
+    pub position: Position,
+}
+
+fn default_timeline_title() -> String {
+    "Timeline".to_string()
+}
+
+fn default_timeline_max_items() -> usize {
+    30
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewsConfig {
+    #[serde(default = "default_news_title")]
+    pub title: String,
+    /// Hacker News list to include ("top", "new", "best", ...); left out,
+    /// Hacker News isn't fetched
+    #[serde(default)]
+    pub hackernews: Option<String>,
+    #[serde(default = "default_story_count")]
+    pub story_count: usize,
+    /// RSS/Atom feeds to include, e.g. `"https://lobste.rs/rss"`
+    #[serde(default)]
+    pub feeds: Vec<String>,
+    #[serde(default = "default_timeline_max_items")]
+    pub max_items: usize,
+    #[serde(default)]
+    pub overflow: Overflow,
+    #[serde(default)]
+    pub http: HttpConfig,
+    /// Overrides `general.refresh_interval_secs` for this widget
+    #[serde(default)]
+    pub refresh_secs: Option<u64>,
+    pub position: Position,
+}
+
+fn default_news_title() -> String {
+    "News".to_string()
+}
+
 impl Config {
     pub fn load(path: &Path) -> Result<Self> {
         let content = std::fs::read_to_string(path)?;
         let config: Config = toml::from_str(&content)?;
         Ok(config)
     }
+
+    /// Write each widget's position, and the sources of timelines, back to
+    /// the config file at `path`. Everything else in the file, comments
+    /// included, is left as it was.
+    pub fn save_layout(&self, path: &Path) -> Result<()> {
+        let mut doc: toml_edit::DocumentMut = std::fs::read_to_string(path)?.parse()?;
+        let tables = doc
+            .get_mut("widgets")
+            .and_then(|widgets| widgets.as_array_of_tables_mut())
+            .filter(|tables| tables.len() == self.widgets.len())
+            .ok_or_else(|| anyhow::anyhow!("Config file no longer matches the loaded widgets"))?;
+
+        for (table, widget) in tables.iter_mut().zip(&self.widgets) {
+            let position = widget.position();
+            table["position"]["row"] = toml_edit::value(position.row as i64);
+            table["position"]["col"] = toml_edit::value(position.col as i64);
+
+            // Sources are ids built from positions, so they move too
+            if let WidgetConfig::Timeline(timeline) = widget {
+                let unchanged = table
+                    .get("sources")
+                    .and_then(|sources| sources.as_array())
+                    .is_some_and(|written| {
+                        written
+                            .iter()
+                            .map(|s| s.as_str())
+                            .eq(timeline.sources.iter().map(|s| Some(s.as_str())))
+                    });
+                if !unchanged {
+                    table["sources"] =
+                        toml_edit::value(timeline.sources.iter().collect::<toml_edit::Array>());
+                }
+            }
+        }
+
+        std::fs::write(path, doc.to_string())?;
+        Ok(())
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
             general: GeneralConfig::default(),
+            layout: LayoutConfig::default(),
             widgets: vec![
                 WidgetConfig::Creature(CreatureConfig {
                     title: "Tui".to_string(),
@@ -272,6 +1022,8 @@ impl Default for Config {
                     title: "Hacker News".to_string(),
                     story_count: 10,
                     story_type: "top".to_string(),
+                    overflow: Overflow::default(),
+                    refresh_secs: None,
                     position: Position { row: 0, col: 1 },
                 }),
                 WidgetConfig::Stocks(StocksConfig {
@@ -282,6 +1034,8 @@ impl Default for Config {
                         "MSFT".to_string(),
                         "NVDA".to_string(),
                     ],
+                    alerts: Vec::new(),
+                    refresh_secs: None,
                     position: Position { row: 1, col: 0 },
                 }),
                 WidgetConfig::Rss(RssConfig {
@@ -289,15 +1043,109 @@ impl Default for Config {
                     feeds: vec![
                         "https://feeds.arstechnica.com/arstechnica/technology-lab".to_string()
                     ],
+                    opml_path: None,
                     max_items: 10,
+                    overflow: Overflow::default(),
+                    http: HttpConfig::default(),
+                    refresh_secs: None,
                     position: Position { row: 1, col: 1 },
                 }),
                 WidgetConfig::Sports(SportsConfig {
                     title: "Sports".to_string(),
+                    provider: SportsProvider::default(),
                     leagues: vec!["nba".to_string(), "nfl".to_string()],
+                    refresh_secs: None,
                     position: Position { row: 2, col: 0 },
                 }),
             ],
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_widget_refresh_interval() {
+        let config: Config = toml::from_str(
+            r#"
+            [general]
+            refresh_interval_secs = 900
+
+            [[widgets]]
+            type = "stocks"
+            symbols = ["AAPL"]
+            refresh_secs = 30
+            position = { row = 0, col = 0 }
+
+            [[widgets]]
+            type = "crypto"
+            coins = [{ id = "bitcoin", symbol = "BTC" }]
+            refresh_secs = 1
+            position = { row = 0, col = 1 }
+
+            [[widgets]]
+            type = "rss"
+            feeds = []
+            position = { row = 1, col = 0 }
+            "#,
+        )
+        .unwrap();
+
+        let intervals: Vec<u64> = config
+            .widgets
+            .iter()
+            .map(|w| w.refresh_interval(&config.general).as_secs())
+            .collect();
+        assert_eq!(intervals, [30, MIN_REFRESH_SECS, 900]);
+    }
+
+    #[test]
+    fn test_layout_gap() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.layout.gap, 0);
+
+        let config: Config = toml::from_str("[layout]\ngap = 2").unwrap();
+        assert_eq!(config.layout.gap, 2);
+    }
+
+    #[test]
+    fn test_save_layout_keeps_the_rest_of_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            r#"# My dashboard
+[[widgets]]
+type = "hackernews"
+position = { row = 0, col = 0 }  # top left
+
+[[widgets]]
+type = "timeline"
+sources = ["hackernews-0-0"]
+position = { row = 0, col = 1 }
+"#,
+        )
+        .unwrap();
+
+        let mut config = Config::load(&path).unwrap();
+        *config.widgets[0].position_mut() = Position { row: 0, col: 1 };
+        *config.widgets[1].position_mut() = Position { row: 0, col: 0 };
+        if let WidgetConfig::Timeline(timeline) = &mut config.widgets[1] {
+            timeline.sources = vec!["hackernews-0-1".to_string()];
+        }
+        config.save_layout(&path).unwrap();
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        assert!(saved.starts_with("# My dashboard\n"));
+        assert!(saved.contains("# top left"));
+        let reloaded = Config::load(&path).unwrap();
+        assert_eq!(reloaded.widgets[0].position().col, 1);
+        assert_eq!(reloaded.widgets[1].position().col, 0);
+        let WidgetConfig::Timeline(timeline) = &reloaded.widgets[1] else {
+            panic!("expected a timeline");
+        };
+        assert_eq!(timeline.sources, ["hackernews-0-1"]);
+    }
+}
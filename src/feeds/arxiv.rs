@@ -0,0 +1,150 @@
+use super::{http_status_error, FeedData, FeedFetcher, RssItem};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+const API_URL: &str = "https://export.arxiv.org/api/query";
+
+/// Authors listed before the rest are folded into "et al."
+const MAX_AUTHORS: usize = 3;
+
+/// The newest submissions in an arXiv category, as RSS items with the
+/// authors as the source and the abstract as the description
+pub struct ArxivFetcher {
+    /// Category such as `cs.LG` or `math.AG`
+    category: String,
+    max_items: usize,
+    client: Arc<reqwest::Client>,
+}
+
+impl ArxivFetcher {
+    pub fn new(category: String, max_items: usize, client: Arc<reqwest::Client>) -> Self {
+        Self {
+            category,
+            max_items,
+            client,
+        }
+    }
+}
+
+/// arXiv hard-wraps titles and abstracts; join them back into one line
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn format_authors(authors: &[feed_rs::model::Person]) -> String {
+    let names: Vec<&str> = authors
+        .iter()
+        .take(MAX_AUTHORS)
+        .map(|a| a.name.as_str())
+        .collect();
+    if authors.len() > MAX_AUTHORS {
+        format!("{} et al.", names.join(", "))
+    } else {
+        names.join(", ")
+    }
+}
+
+fn map_papers(feed: feed_rs::model::Feed) -> Vec<RssItem> {
+    feed.entries
+        .into_iter()
+        .map(|entry| RssItem {
+            title: entry
+                .title
+                .map(|t| collapse_whitespace(&t.content))
+                .unwrap_or_else(|| "No title".to_string()),
+            // The first link is the abstract page; the PDF is listed after it
+            link: entry.links.first().map(|l| l.href.clone()),
+            published: entry
+                .published
+                .map(|d| d.format("%Y-%m-%d %H:%M").to_string()),
+            timestamp: entry.published.map(|d| d.timestamp()),
+            source: format_authors(&entry.authors),
+            description: entry.summary.map(|s| collapse_whitespace(&s.content)),
+        })
+        .collect()
+}
+
+#[async_trait]
+impl FeedFetcher for ArxivFetcher {
+    fn source_name(&self) -> &'static str {
+        "arxiv"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        let response = self
+            .client
+            .get(API_URL)
+            .query(&[
+                ("search_query", format!("cat:{}", self.category)),
+                ("sortBy", "submittedDate".to_string()),
+                ("sortOrder", "descending".to_string()),
+                ("max_results", self.max_items.to_string()),
+            ])
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(http_status_error(
+                response.status(),
+                format!("arXiv API error: {}", response.status()),
+            ));
+        }
+
+        let body = response.bytes().await?;
+        let feed = feed_rs::parser::parse(&body[..])?;
+
+        Ok(FeedData::Rss(map_papers(feed)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>arXiv Query: search_query=cat:cs.LG</title>
+  <id>http://arxiv.org/api/query</id>
+  <updated>2024-05-02T00:00:00-04:00</updated>
+  <entry>
+    <id>http://arxiv.org/abs/2405.00001v1</id>
+    <updated>2024-05-01T17:59:59Z</updated>
+    <published>2024-05-01T17:59:59Z</published>
+    <title>Sparse Attention
+      at Scale</title>
+    <summary>  We study attention.
+  It is sparse.
+</summary>
+    <author><name>Ada Lovelace</name></author>
+    <author><name>Alan Turing</name></author>
+    <author><name>Grace Hopper</name></author>
+    <author><name>Edsger Dijkstra</name></author>
+    <link href="http://arxiv.org/abs/2405.00001v1" rel="alternate" type="text/html"/>
+    <link title="pdf" href="http://arxiv.org/pdf/2405.00001v1" rel="related" type="application/pdf"/>
+  </entry>
+</feed>"#;
+
+    #[test]
+    fn test_map_papers() {
+        let feed = feed_rs::parser::parse(FEED.as_bytes()).unwrap();
+        let papers = map_papers(feed);
+
+        assert_eq!(papers.len(), 1);
+        let paper = &papers[0];
+        assert_eq!(paper.title, "Sparse Attention at Scale");
+        assert_eq!(
+            paper.source,
+            "Ada Lovelace, Alan Turing, Grace Hopper et al."
+        );
+        assert_eq!(
+            paper.description.as_deref(),
+            Some("We study attention. It is sparse.")
+        );
+        assert_eq!(
+            paper.link.as_deref(),
+            Some("http://arxiv.org/abs/2405.00001v1")
+        );
+        assert_eq!(paper.timestamp, Some(1714586399));
+    }
+}
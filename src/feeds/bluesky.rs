@@ -0,0 +1,269 @@
+use super::{http_status_error, BlueskyPost, FeedData, FeedFetcher};
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::sync::{Arc, Mutex};
+
+pub struct BlueskyFetcher {
+    /// Base URL of the PDS, e.g. `"https://bsky.social"`
+    service: String,
+    identifier: String,
+    app_password: String,
+    max_items: usize,
+    client: Arc<reqwest::Client>,
+    /// Tokens from the last login, kept so polls don't log in again
+    session: Mutex<Option<Session>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Session {
+    access_jwt: String,
+    refresh_jwt: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Timeline {
+    feed: Vec<FeedViewPost>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FeedViewPost {
+    post: PostView,
+    reason: Option<Reason>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PostView {
+    uri: String,
+    author: Author,
+    record: PostRecord,
+    #[serde(default)]
+    like_count: u32,
+    #[serde(default)]
+    repost_count: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct Author {
+    handle: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PostRecord {
+    #[serde(default)]
+    text: String,
+    created_at: Option<String>,
+}
+
+/// Why a post is in the timeline; only reposts carry `by`
+#[derive(Debug, Deserialize)]
+struct Reason {
+    by: Option<Author>,
+}
+
+/// Web URL of a post, from its `at://did/app.bsky.feed.post/rkey` URI
+fn post_url(handle: &str, uri: &str) -> String {
+    let rkey = uri.rsplit('/').next().unwrap_or_default();
+    format!("https://bsky.app/profile/{}/post/{}", handle, rkey)
+}
+
+/// Convert timeline entries into posts, noting who reposted them
+fn map_feed(feed: Vec<FeedViewPost>) -> Vec<BlueskyPost> {
+    feed.into_iter()
+        .map(|entry| {
+            let post = entry.post;
+            BlueskyPost {
+                url: post_url(&post.author.handle, &post.uri),
+                id: post.uri,
+                author: format!("@{}", post.author.handle),
+                text: post.record.text,
+                likes: post.like_count,
+                reposts: post.repost_count,
+                reposted_by: entry
+                    .reason
+                    .and_then(|reason| reason.by)
+                    .map(|by| format!("@{}", by.handle)),
+                timestamp: post
+                    .record
+                    .created_at
+                    .and_then(|t| chrono::DateTime::parse_from_rfc3339(&t).ok())
+                    .map(|t| t.timestamp()),
+            }
+        })
+        .collect()
+}
+
+impl BlueskyFetcher {
+    pub fn new(
+        service: String,
+        identifier: String,
+        app_password: String,
+        max_items: usize,
+        client: Arc<reqwest::Client>,
+    ) -> Self {
+        Self {
+            service: service.trim_end_matches('/').to_string(),
+            identifier,
+            app_password,
+            max_items,
+            client,
+            session: Mutex::new(None),
+        }
+    }
+
+    fn xrpc_url(&self, method: &str) -> String {
+        format!("{}/xrpc/{}", self.service, method)
+    }
+
+    /// Log in with the app password
+    async fn create_session(&self) -> Result<Session> {
+        let response = self
+            .client
+            .post(self.xrpc_url("com.atproto.server.createSession"))
+            .json(&serde_json::json!({
+                "identifier": self.identifier,
+                "password": self.app_password,
+            }))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(http_status_error(
+                response.status(),
+                format!("Bluesky login failed: {}", response.status()),
+            ));
+        }
+        Ok(response.json().await?)
+    }
+
+    /// Swap the refresh token for a new session
+    async fn refresh_session(&self, refresh_jwt: &str) -> Result<Session> {
+        let response = self
+            .client
+            .post(self.xrpc_url("com.atproto.server.refreshSession"))
+            .bearer_auth(refresh_jwt)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("Bluesky session refresh failed: {}", response.status());
+        }
+        Ok(response.json().await?)
+    }
+
+    /// Renew an expired session, logging in again if the refresh token has
+    /// expired too
+    async fn renew_session(&self, expired: &Session) -> Result<Session> {
+        match self.refresh_session(&expired.refresh_jwt).await {
+            Ok(session) => Ok(session),
+            Err(_) => self.create_session().await,
+        }
+    }
+
+    async fn get_timeline(&self, session: &Session) -> Result<reqwest::Response> {
+        Ok(self
+            .client
+            .get(self.xrpc_url("app.bsky.feed.getTimeline"))
+            .query(&[("limit", self.max_items.to_string())])
+            .bearer_auth(&session.access_jwt)
+            .send()
+            .await?)
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for BlueskyFetcher {
+    fn source_name(&self) -> &'static str {
+        "bluesky"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        let stored = self.session.lock().unwrap().clone();
+        let mut session = match stored {
+            Some(session) => session,
+            None => self.create_session().await?,
+        };
+
+        let mut response = self.get_timeline(&session).await?;
+        // An expired access token comes back as 400 ExpiredToken or 401
+        if matches!(
+            response.status(),
+            StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED
+        ) {
+            session = self.renew_session(&session).await?;
+            response = self.get_timeline(&session).await?;
+        }
+        *self.session.lock().unwrap() = Some(session);
+
+        if !response.status().is_success() {
+            return Err(http_status_error(
+                response.status(),
+                format!("Bluesky API error: {}", response.status()),
+            ));
+        }
+
+        let timeline: Timeline = response.json().await?;
+        Ok(FeedData::Bluesky(map_feed(timeline.feed)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMELINE_FIXTURE: &str = r#"{
+        "feed": [
+            {
+                "post": {
+                    "uri": "at://did:plc:abc/app.bsky.feed.post/3kxyz",
+                    "cid": "bafy",
+                    "author": {"did": "did:plc:abc", "handle": "alice.bsky.social"},
+                    "record": {
+                        "$type": "app.bsky.feed.post",
+                        "text": "Hello from the atmosphere",
+                        "createdAt": "2024-05-01T11:00:00.000Z"
+                    },
+                    "likeCount": 12,
+                    "repostCount": 3
+                },
+                "reason": {
+                    "$type": "app.bsky.feed.defs#reasonRepost",
+                    "by": {"did": "did:plc:def", "handle": "bob.bsky.social"}
+                }
+            },
+            {
+                "post": {
+                    "uri": "at://did:plc:def/app.bsky.feed.post/3kabc",
+                    "author": {"handle": "bob.bsky.social"},
+                    "record": {"text": "No counts yet"}
+                }
+            }
+        ],
+        "cursor": "next"
+    }"#;
+
+    #[test]
+    fn test_map_feed() {
+        let timeline: Timeline = serde_json::from_str(TIMELINE_FIXTURE).unwrap();
+        let posts = map_feed(timeline.feed);
+
+        assert_eq!(posts.len(), 2);
+        let post = &posts[0];
+        assert_eq!(post.author, "@alice.bsky.social");
+        assert_eq!(post.reposted_by.as_deref(), Some("@bob.bsky.social"));
+        assert_eq!(post.text, "Hello from the atmosphere");
+        assert_eq!((post.likes, post.reposts), (12, 3));
+        assert_eq!(
+            post.url,
+            "https://bsky.app/profile/alice.bsky.social/post/3kxyz"
+        );
+        assert_eq!(post.timestamp, Some(1714561200));
+
+        assert_eq!(posts[1].reposted_by, None);
+        assert_eq!((posts[1].likes, posts[1].timestamp), (0, None));
+    }
+}
@@ -0,0 +1,132 @@
+use super::FeedData;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Get the default directory for cached feed data
+pub fn default_cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("feedtui")
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    /// Unix time the data was fetched
+    saved_at: i64,
+    data: FeedData,
+}
+
+/// The last successful fetch of each widget, kept on disk so widgets have
+/// something to show while the first fetch after startup is in flight
+pub struct FeedCache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+impl FeedCache {
+    pub fn new(dir: PathBuf, ttl: Duration) -> Self {
+        Self { dir, ttl }
+    }
+
+    fn path(&self, widget_id: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", widget_id))
+    }
+
+    /// Whether `data` is a successful result worth keeping
+    pub fn is_cacheable(data: &FeedData) -> bool {
+        !matches!(
+            data,
+            FeedData::Loading | FeedData::Error(_) | FeedData::PixelArt(_)
+        )
+    }
+
+    /// Write a widget's data, replacing any earlier entry
+    pub fn store(&self, widget_id: &str, data: &FeedData) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+
+        let entry = CacheEntry {
+            saved_at: chrono::Utc::now().timestamp(),
+            data: data.clone(),
+        };
+        // Write then rename so a crash never leaves a half-written entry
+        let path = self.path(widget_id);
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_vec(&entry)?)?;
+        std::fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+
+    /// A widget's cached data, unless missing, unreadable or older than the TTL
+    pub fn load(&self, widget_id: &str) -> Option<FeedData> {
+        self.load_at(&self.path(widget_id), chrono::Utc::now().timestamp())
+    }
+
+    fn load_at(&self, path: &Path, now: i64) -> Option<FeedData> {
+        let content = std::fs::read(path).ok()?;
+        let entry: CacheEntry = serde_json::from_slice(&content).ok()?;
+
+        let age = Duration::from_secs(now.saturating_sub(entry.saved_at).max(0) as u64);
+        (age <= self.ttl).then_some(entry.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feeds::HnStory;
+    use tempfile::tempdir;
+
+    fn stories() -> FeedData {
+        FeedData::HackerNews(vec![HnStory {
+            id: 1,
+            title: "Show HN: feedtui".to_string(),
+            url: None,
+            score: 42,
+            by: "someone".to_string(),
+            descendants: 7,
+            time: Some(1_700_000_000),
+            kind: "story".to_string(),
+        }])
+    }
+
+    #[test]
+    fn test_store_and_load() {
+        let dir = tempdir().unwrap();
+        let cache = FeedCache::new(dir.path().join("cache"), Duration::from_secs(600));
+
+        assert!(cache.load("hackernews-0-0").is_none());
+        cache.store("hackernews-0-0", &stories()).unwrap();
+
+        let Some(FeedData::HackerNews(loaded)) = cache.load("hackernews-0-0") else {
+            panic!("expected cached stories");
+        };
+        assert_eq!(loaded[0].title, "Show HN: feedtui");
+        assert_eq!(loaded[0].time, Some(1_700_000_000));
+    }
+
+    #[test]
+    fn test_expired_entries_are_ignored() {
+        let dir = tempdir().unwrap();
+        let cache = FeedCache::new(dir.path().to_path_buf(), Duration::from_secs(600));
+        let path = cache.path("hackernews-0-0");
+        let entry = CacheEntry {
+            saved_at: 1_000,
+            data: stories(),
+        };
+        std::fs::write(&path, serde_json::to_vec(&entry).unwrap()).unwrap();
+
+        assert!(cache.load_at(&path, 1_600).is_some());
+        assert!(cache.load_at(&path, 1_601).is_none());
+    }
+
+    #[test]
+    fn test_only_successful_data_is_cacheable() {
+        assert!(FeedCache::is_cacheable(&stories()));
+        assert!(!FeedCache::is_cacheable(&FeedData::Loading));
+        assert!(!FeedCache::is_cacheable(&FeedData::Error(
+            "boom".to_string()
+        )));
+    }
+}
@@ -0,0 +1,537 @@
+use super::{http_status_error, CalendarEvent, FeedData, FeedFetcher};
+use crate::logging;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use jiff::civil::{Date, DateTime, Weekday};
+use jiff::tz::TimeZone;
+use jiff::{Span, Zoned};
+use std::sync::Arc;
+
+/// Most occurrences generated for one recurring event, so an old rule with
+/// no end can't spin forever
+const MAX_OCCURRENCES: usize = 10_000;
+
+pub struct CalendarFetcher {
+    /// `.ics` URL (http, https or webcal) or local file path
+    source: String,
+    max_items: usize,
+    client: Arc<reqwest::Client>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Frequency {
+    Daily,
+    Weekly,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Rrule {
+    frequency: Frequency,
+    interval: i64,
+    count: Option<usize>,
+    until: Option<i64>,
+    /// BYDAY: the days of each week a WEEKLY rule repeats on, or the only
+    /// days a DAILY rule keeps. Empty means the start's weekday / every day.
+    by_day: Vec<Weekday>,
+}
+
+/// A VEVENT as written in the file, before recurrences are expanded
+#[derive(Debug, Clone)]
+struct VEvent {
+    summary: String,
+    location: Option<String>,
+    start: Zoned,
+    end: Option<Zoned>,
+    all_day: bool,
+    /// The RRULE, or why it can't be expanded
+    rrule: Option<Result<Rrule, String>>,
+    /// EXDATE: start times of occurrences removed from the rule
+    exdates: Vec<i64>,
+}
+
+/// Join folded lines: a line starting with a space or tab continues the
+/// previous one
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in ics.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// Split `NAME;PARAM=x;TZID=y:value` into its name, TZID parameter and value
+fn split_property(line: &str) -> Option<(&str, Option<&str>, &str)> {
+    let (head, value) = line.split_once(':')?;
+    let mut parts = head.split(';');
+    let name = parts.next()?;
+    let tzid = parts.find_map(|p| p.strip_prefix("TZID="));
+    Some((name, tzid, value))
+}
+
+fn unescape_text(value: &str) -> String {
+    value
+        .replace("\\n", " ")
+        .replace("\\N", " ")
+        .replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\\\", "\\")
+}
+
+/// Parse a DATE or DATE-TIME value. UTC times end in `Z`, others are in
+/// their TZID or, without one, in `local`. Returns the time and whether it
+/// is a whole day.
+fn parse_date_time(value: &str, tzid: Option<&str>, local: &TimeZone) -> Option<(Zoned, bool)> {
+    let tz = match tzid {
+        _ if value.ends_with('Z') => TimeZone::UTC,
+        Some(name) => TimeZone::get(name.trim_matches('"')).unwrap_or_else(|_| local.clone()),
+        None => local.clone(),
+    };
+    let value = value.trim_end_matches('Z');
+
+    if value.len() == 8 {
+        let date = Date::strptime("%Y%m%d", value).ok()?;
+        return Some((date.to_zoned(tz).ok()?, true));
+    }
+    let time = DateTime::strptime("%Y%m%dT%H%M%S", value).ok()?;
+    Some((time.to_zoned(tz).ok()?, false))
+}
+
+/// A BYDAY entry such as `MO`. The ordinal some rules put in front (`1MO`,
+/// `-1FR`) only means something for monthly and yearly rules, so it is
+/// ignored.
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    let day = value.trim_start_matches(|c: char| c == '+' || c == '-' || c.is_ascii_digit());
+    Some(match day {
+        "MO" => Weekday::Monday,
+        "TU" => Weekday::Tuesday,
+        "WE" => Weekday::Wednesday,
+        "TH" => Weekday::Thursday,
+        "FR" => Weekday::Friday,
+        "SA" => Weekday::Saturday,
+        "SU" => Weekday::Sunday,
+        _ => return None,
+    })
+}
+
+/// Parse a DAILY or WEEKLY rule, optionally limited by BYDAY. Any other
+/// frequency or BY part gives the reason it can't be expanded, so the event
+/// isn't quietly shown as happening once.
+fn parse_rrule(value: &str, local: &TimeZone) -> Result<Rrule, String> {
+    let mut rule = Rrule {
+        frequency: Frequency::Daily,
+        interval: 1,
+        count: None,
+        until: None,
+        by_day: Vec::new(),
+    };
+    let mut frequency = None;
+    for part in value.split(';') {
+        let (key, val) = part
+            .split_once('=')
+            .ok_or_else(|| format!("invalid RRULE part {}", part))?;
+        match key {
+            "FREQ" => {
+                frequency = Some(match val {
+                    "DAILY" => Frequency::Daily,
+                    "WEEKLY" => Frequency::Weekly,
+                    _ => return Err(format!("unsupported FREQ={}", val)),
+                })
+            }
+            "INTERVAL" => {
+                rule.interval = val
+                    .parse()
+                    .ok()
+                    .filter(|&i: &i64| i > 0)
+                    .ok_or_else(|| format!("invalid INTERVAL={}", val))?
+            }
+            "COUNT" => rule.count = val.parse().ok(),
+            "UNTIL" => {
+                rule.until =
+                    parse_date_time(val, None, local).map(|(t, _)| t.timestamp().as_second())
+            }
+            "BYDAY" => {
+                rule.by_day = val
+                    .split(',')
+                    .map(|day| parse_weekday(day).ok_or_else(|| format!("invalid BYDAY={}", val)))
+                    .collect::<Result<_, _>>()?
+            }
+            // Only changes which week a day belongs to; weeks start on Monday
+            "WKST" => {}
+            _ if key.starts_with("BY") => return Err(format!("unsupported {}", part)),
+            _ => {}
+        }
+    }
+    rule.frequency = frequency.ok_or("RRULE without FREQ")?;
+    Ok(rule)
+}
+
+/// Read every VEVENT in a calendar. Properties of nested components such
+/// as VALARM are skipped, as are events without a parsable start.
+fn parse_ics(ics: &str, local: &TimeZone) -> Vec<VEvent> {
+    let mut events = Vec::new();
+    // Components opened inside the current VEVENT
+    let mut nested = 0;
+    let mut current: Option<Vec<String>> = None;
+
+    for line in unfold(ics) {
+        match (line.as_str(), current.as_mut()) {
+            ("BEGIN:VEVENT", None) => current = Some(Vec::new()),
+            ("END:VEVENT", Some(_)) if nested == 0 => {
+                let lines = current.take().unwrap_or_default();
+                events.extend(build_event(&lines, local));
+            }
+            (l, Some(_)) if l.starts_with("BEGIN:") => nested += 1,
+            (l, Some(_)) if l.starts_with("END:") => nested -= 1,
+            (_, Some(lines)) if nested == 0 => lines.push(line),
+            _ => {}
+        }
+    }
+    events
+}
+
+fn build_event(lines: &[String], local: &TimeZone) -> Option<VEvent> {
+    let mut summary = String::new();
+    let mut location = None;
+    let mut start = None;
+    let mut end = None;
+    let mut rrule = None;
+    let mut exdates = Vec::new();
+
+    for line in lines {
+        let Some((name, tzid, value)) = split_property(line) else {
+            continue;
+        };
+        match name {
+            "SUMMARY" => summary = unescape_text(value),
+            "LOCATION" if !value.is_empty() => location = Some(unescape_text(value)),
+            "DTSTART" => start = parse_date_time(value, tzid, local),
+            "DTEND" => end = parse_date_time(value, tzid, local).map(|(t, _)| t),
+            "RRULE" => rrule = Some(parse_rrule(value, local)),
+            "EXDATE" => exdates.extend(
+                value
+                    .split(',')
+                    .filter_map(|v| parse_date_time(v, tzid, local))
+                    .map(|(t, _)| t.timestamp().as_second()),
+            ),
+            _ => {}
+        }
+    }
+
+    let (start, all_day) = start?;
+    Some(VEvent {
+        summary,
+        location,
+        start,
+        end,
+        all_day,
+        rrule,
+        exdates,
+    })
+}
+
+/// Start times of the occurrences a rule generates in its `n`th period: the
+/// day or week `n` intervals after the event's start. None once the dates
+/// run out of range.
+fn period_starts(start: &Zoned, rule: &Rrule, n: i64) -> Option<Vec<Zoned>> {
+    let offset = n.checked_mul(rule.interval)?;
+    match rule.frequency {
+        Frequency::Daily => {
+            let at = start.checked_add(Span::new().try_days(offset).ok()?).ok()?;
+            let kept = rule.by_day.is_empty() || rule.by_day.contains(&at.weekday());
+            Some(if kept { vec![at] } else { Vec::new() })
+        }
+        Frequency::Weekly if rule.by_day.is_empty() => Some(vec![start
+            .checked_add(Span::new().try_weeks(offset).ok()?)
+            .ok()?]),
+        Frequency::Weekly => {
+            let monday = start
+                .checked_sub(Span::new().days(start.weekday().to_monday_zero_offset()))
+                .ok()?
+                .checked_add(Span::new().try_weeks(offset).ok()?)
+                .ok()?;
+            let mut days: Vec<i8> = rule
+                .by_day
+                .iter()
+                .map(|day| day.to_monday_zero_offset())
+                .collect();
+            days.sort_unstable();
+            days.dedup();
+            days.into_iter()
+                .map(|day| monday.checked_add(Span::new().days(day)).ok())
+                // Days of the first week before the start aren't occurrences
+                .filter(|at| at.as_ref().is_none_or(|at| at >= start))
+                .collect()
+        }
+    }
+}
+
+/// Every occurrence of `event` that hasn't ended by `now`, up to `limit`.
+/// Occurrences step in the event's own time zone, so a weekly meeting keeps
+/// its wall-clock time across daylight saving changes.
+fn upcoming_occurrences(event: &VEvent, now: i64, limit: usize) -> Vec<CalendarEvent> {
+    let start = event.start.timestamp().as_second();
+    // A missing DTEND means one day for all-day events and an instant otherwise
+    let length = match &event.end {
+        Some(end) => end.timestamp().as_second() - start,
+        None if event.all_day => 24 * 3600,
+        None => 0,
+    };
+    let occurrence = |at: &Zoned| {
+        let start = at.timestamp().as_second();
+        CalendarEvent {
+            summary: event.summary.clone(),
+            location: event.location.clone(),
+            start,
+            end: start + length,
+            all_day: event.all_day,
+        }
+    };
+
+    let rule = match &event.rrule {
+        Some(Ok(rule)) => rule,
+        rrule => {
+            let mut single = occurrence(&event.start);
+            if let Some(Err(reason)) = rrule {
+                single.summary = format!("{} (repeats, not shown: {})", single.summary, reason);
+            }
+            return (start + length > now)
+                .then_some(single)
+                .into_iter()
+                .collect();
+        }
+    };
+
+    let max_generated = rule.count.unwrap_or(MAX_OCCURRENCES).min(MAX_OCCURRENCES);
+    let mut generated = 0;
+    let mut occurrences = Vec::new();
+    // A DAILY rule whose BYDAY never matches has empty periods, so the
+    // periods are capped as well
+    'periods: for n in 0..MAX_OCCURRENCES as i64 {
+        let Some(starts) = period_starts(&event.start, rule, n) else {
+            break;
+        };
+        for at in starts {
+            let at_second = at.timestamp().as_second();
+            if generated >= max_generated
+                || rule.until.is_some_and(|until| at_second > until)
+                || occurrences.len() >= limit
+            {
+                break 'periods;
+            }
+            // Excluded dates still count towards COUNT
+            generated += 1;
+            if !event.exdates.contains(&at_second) && at_second + length > now {
+                occurrences.push(occurrence(&at));
+            }
+        }
+    }
+    occurrences
+}
+
+/// The next `max_items` events that haven't ended by `now`, soonest first
+fn upcoming(events: &[VEvent], now: i64, max_items: usize) -> Vec<CalendarEvent> {
+    let mut upcoming: Vec<CalendarEvent> = events
+        .iter()
+        .flat_map(|event| upcoming_occurrences(event, now, max_items))
+        .collect();
+    upcoming.sort_by_key(|event| event.start);
+    upcoming.truncate(max_items);
+    upcoming
+}
+
+impl CalendarFetcher {
+    pub fn new(source: String, max_items: usize, client: Arc<reqwest::Client>) -> Self {
+        Self {
+            source,
+            max_items,
+            client,
+        }
+    }
+
+    async fn load(&self) -> Result<String> {
+        let url = match self.source.strip_prefix("webcal://") {
+            Some(rest) => format!("https://{}", rest),
+            None => self.source.clone(),
+        };
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return std::fs::read_to_string(&url)
+                .with_context(|| format!("Failed to read calendar {}", url));
+        }
+
+        let response = self.client.get(&url).send().await?;
+        if !response.status().is_success() {
+            return Err(http_status_error(
+                response.status(),
+                format!("Calendar fetch error: {}", response.status()),
+            ));
+        }
+        Ok(response.text().await?)
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for CalendarFetcher {
+    fn source_name(&self) -> &'static str {
+        "calendar"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        let ics = self.load().await?;
+        let events = parse_ics(&ics, &TimeZone::system());
+        for event in &events {
+            if let Some(Err(reason)) = &event.rrule {
+                logging::warn!(
+                    "calendar event {:?} repeats by a rule that isn't expanded: {}",
+                    event.summary,
+                    reason
+                );
+            }
+        }
+        let now = jiff::Timestamp::now().as_second();
+        Ok(FeedData::Calendar(upcoming(&events, now, self.max_items)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+BEGIN:VEVENT\r
+SUMMARY:Planning\\, Q3\r
+LOCATION:Room 4\r
+DTSTART:20240501T140000Z\r
+DTEND:20240501T150000Z\r
+BEGIN:VALARM\r
+SUMMARY:Reminder\r
+END:VALARM\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:Company\r
+  holiday\r
+DTSTART;VALUE=DATE:20240503\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:Standup\r
+DTSTART;TZID=Europe/Berlin:20240429T093000\r
+DTEND;TZID=Europe/Berlin:20240429T094500\r
+RRULE:FREQ=DAILY;COUNT=5\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+SUMMARY:1:1\r
+DTSTART:20240424T100000Z\r
+RRULE:FREQ=WEEKLY;INTERVAL=2;UNTIL=20240531T000000Z\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    fn at(s: &str) -> i64 {
+        s.parse::<jiff::Timestamp>().unwrap().as_second()
+    }
+
+    #[test]
+    fn test_parse_ics() {
+        let events = parse_ics(CALENDAR, &TimeZone::UTC);
+        assert_eq!(events.len(), 4);
+
+        assert_eq!(events[0].summary, "Planning, Q3");
+        assert_eq!(events[0].location.as_deref(), Some("Room 4"));
+        assert_eq!(
+            events[0].start.timestamp().as_second(),
+            at("2024-05-01T14:00:00Z")
+        );
+
+        assert_eq!(events[1].summary, "Company holiday");
+        assert!(events[1].all_day);
+
+        // 09:30 in Berlin is 07:30 UTC in summer
+        assert_eq!(
+            events[2].start.timestamp().as_second(),
+            at("2024-04-29T07:30:00Z")
+        );
+        assert_eq!(
+            events[2].rrule,
+            Some(Ok(Rrule {
+                frequency: Frequency::Daily,
+                interval: 1,
+                count: Some(5),
+                until: None,
+                by_day: Vec::new(),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_upcoming_expands_recurring_events() {
+        let events = parse_ics(CALENDAR, &TimeZone::UTC);
+        let now = at("2024-05-01T12:00:00Z");
+        let upcoming = upcoming(&events, now, 10);
+
+        let summary: Vec<(&str, i64)> = upcoming
+            .iter()
+            .map(|e| (e.summary.as_str(), e.start))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Planning, Q3", at("2024-05-01T14:00:00Z")),
+                ("Standup", at("2024-05-02T07:30:00Z")),
+                ("Company holiday", at("2024-05-03T00:00:00Z")),
+                ("Standup", at("2024-05-03T07:30:00Z")),
+                ("1:1", at("2024-05-08T10:00:00Z")),
+                ("1:1", at("2024-05-22T10:00:00Z")),
+            ]
+        );
+        // Ongoing events are kept; the all-day event defaults to one day
+        assert_eq!(upcoming[2].end - upcoming[2].start, 24 * 3600);
+    }
+
+    #[test]
+    fn test_weekly_by_day_skips_excluded_dates() {
+        // Starts on a Wednesday; the Monday in between is cancelled
+        let ics = "BEGIN:VEVENT\r
+SUMMARY:Gym\r
+DTSTART:20240501T180000Z\r
+RRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR;COUNT=4\r
+EXDATE:20240506T180000Z\r
+END:VEVENT\r
+";
+        let events = parse_ics(ics, &TimeZone::UTC);
+        let starts: Vec<i64> = upcoming(&events, at("2024-04-01T00:00:00Z"), 10)
+            .iter()
+            .map(|e| e.start)
+            .collect();
+        assert_eq!(
+            starts,
+            vec![
+                at("2024-05-01T18:00:00Z"),
+                at("2024-05-03T18:00:00Z"),
+                at("2024-05-08T18:00:00Z"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unsupported_rule_is_reported() {
+        let ics = "BEGIN:VEVENT\r
+SUMMARY:Rent\r
+DTSTART;VALUE=DATE:20240601\r
+RRULE:FREQ=MONTHLY;BYMONTHDAY=1\r
+END:VEVENT\r
+";
+        let events = parse_ics(ics, &TimeZone::UTC);
+        assert_eq!(
+            events[0].rrule,
+            Some(Err("unsupported FREQ=MONTHLY".to_string()))
+        );
+        let upcoming = upcoming(&events, at("2024-05-01T00:00:00Z"), 10);
+        assert_eq!(
+            upcoming[0].summary,
+            "Rent (repeats, not shown: unsupported FREQ=MONTHLY)"
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use super::{FeedData, FeedFetcher, NonRetryable};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::process::Command;
+
+/// Runs a shell command on every refresh and shows its output line by line
+pub struct CommandFetcher {
+    /// Passed to `sh -c` (`cmd /C` on Windows), so pipes and quoting work
+    command: String,
+    timeout: Duration,
+}
+
+impl CommandFetcher {
+    pub fn new(command: String, timeout: Duration) -> Self {
+        Self { command, timeout }
+    }
+
+    fn shell(&self) -> Command {
+        let mut shell = if cfg!(windows) {
+            let mut shell = Command::new("cmd");
+            shell.arg("/C");
+            shell
+        } else {
+            let mut shell = Command::new("sh");
+            shell.arg("-c");
+            shell
+        };
+        shell.arg(&self.command);
+        shell
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for CommandFetcher {
+    fn source_name(&self) -> &'static str {
+        "command"
+    }
+
+    /// Failures are marked `NonRetryable`: running the command again straight
+    /// away would only repeat its side effects and its error
+    async fn fetch(&self) -> Result<FeedData> {
+        let output = self
+            .shell()
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .output();
+        let output = tokio::time::timeout(self.timeout, output)
+            .await
+            .map_err(|_| {
+                NonRetryable(format!(
+                    "Command timed out after {}s",
+                    self.timeout.as_secs()
+                ))
+            })?
+            .map_err(|e| NonRetryable(format!("Failed to run command: {}", e)))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let code = output
+                .status
+                .code()
+                .map_or_else(|| "a signal".to_string(), |c| format!("status {}", c));
+            return Err(
+                NonRetryable(format!("Command exited with {}: {}", code, stderr.trim())).into(),
+            );
+        }
+
+        let lines = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::to_string)
+            .collect();
+        Ok(FeedData::Command(lines))
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn fetcher(command: &str, timeout: Duration) -> CommandFetcher {
+        CommandFetcher::new(command.to_string(), timeout)
+    }
+
+    #[tokio::test]
+    async fn test_command_output_becomes_lines() {
+        let data = fetcher("printf 'one\\ntwo\\n'", Duration::from_secs(5))
+            .fetch()
+            .await
+            .unwrap();
+        assert!(matches!(data, FeedData::Command(lines) if lines == ["one", "two"]));
+    }
+
+    #[tokio::test]
+    async fn test_failures_are_errors() {
+        let err = fetcher("echo oops >&2; exit 3", Duration::from_secs(5))
+            .fetch()
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Command exited with status 3: oops");
+
+        let err = fetcher("sleep 5", Duration::from_millis(50))
+            .fetch()
+            .await
+            .unwrap_err();
+        assert!(err.is::<NonRetryable>());
+    }
+}
@@ -0,0 +1,208 @@
+use super::{FeedData, FeedFetcher, RssItem};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::DateTime;
+
+/// Runs several fetchers at once and merges what they return into a single
+/// newest-first list of RSS items, each tagged with the source it came from
+pub struct CompositeFetcher {
+    fetchers: Vec<Box<dyn FeedFetcher>>,
+    max_items: usize,
+}
+
+impl CompositeFetcher {
+    pub fn new(fetchers: Vec<Box<dyn FeedFetcher>>, max_items: usize) -> Self {
+        Self {
+            fetchers,
+            max_items,
+        }
+    }
+}
+
+fn format_timestamp(timestamp: Option<i64>) -> Option<String> {
+    timestamp
+        .and_then(|ts| DateTime::from_timestamp(ts, 0))
+        .map(|d| d.format("%Y-%m-%d %H:%M").to_string())
+}
+
+/// Convert one source's feed data into RSS items. RSS items keep their feed's
+/// name as the source; other feeds are tagged with where they came from.
+/// Feeds without individual items (stocks, sports, ...) contribute nothing.
+fn rss_items(data: FeedData) -> Vec<RssItem> {
+    match data {
+        FeedData::Rss(items) => items,
+        FeedData::HackerNews(stories) => stories
+            .into_iter()
+            .map(|story| RssItem {
+                link: Some(story.url.unwrap_or_else(|| {
+                    format!("https://news.ycombinator.com/item?id={}", story.id)
+                })),
+                title: story.title,
+                published: format_timestamp(story.time),
+                timestamp: story.time,
+                source: "Hacker News".to_string(),
+                description: None,
+            })
+            .collect(),
+        FeedData::Reddit(posts) => posts
+            .into_iter()
+            .map(|post| RssItem {
+                title: post.title,
+                link: Some(post.permalink),
+                published: format_timestamp(post.timestamp),
+                timestamp: post.timestamp,
+                source: format!("r/{}", post.subreddit),
+                description: None,
+            })
+            .collect(),
+        FeedData::Youtube(videos) => videos
+            .into_iter()
+            .map(|video| RssItem {
+                link: Some(format!("https://www.youtube.com/watch?v={}", video.id)),
+                title: video.title,
+                published: format_timestamp(video.timestamp),
+                timestamp: video.timestamp,
+                source: video.channel,
+                description: Some(video.description),
+            })
+            .collect(),
+        FeedData::Timeline(items) => items
+            .into_iter()
+            .map(|item| RssItem {
+                title: item.title,
+                link: item.url,
+                published: format_timestamp(item.timestamp),
+                timestamp: item.timestamp,
+                source: item.source,
+                description: item.description,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for CompositeFetcher {
+    fn source_name(&self) -> &'static str {
+        "composite"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        if self.fetchers.is_empty() {
+            anyhow::bail!("No sources configured");
+        }
+
+        let results = futures::future::join_all(self.fetchers.iter().map(|f| f.fetch())).await;
+
+        // One source being down shouldn't blank the others; only report an
+        // error when nothing could be fetched
+        let mut items = Vec::new();
+        let mut last_error = None;
+        let mut fetched_any = false;
+        for result in results {
+            match result {
+                Ok(data) => {
+                    fetched_any = true;
+                    items.extend(rss_items(data));
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+        if let (false, Some(e)) = (fetched_any, last_error) {
+            return Err(e);
+        }
+
+        // Newest first, keeping items without a timestamp at the end
+        items.sort_by_key(|item| std::cmp::Reverse(item.timestamp));
+        items.truncate(self.max_items);
+        Ok(FeedData::Rss(items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feeds::HnStory;
+
+    struct StaticFetcher(FeedData);
+
+    #[async_trait]
+    impl FeedFetcher for StaticFetcher {
+        fn source_name(&self) -> &'static str {
+            "static"
+        }
+
+        async fn fetch(&self) -> Result<FeedData> {
+            Ok(self.0.clone())
+        }
+    }
+
+    struct FailingFetcher;
+
+    #[async_trait]
+    impl FeedFetcher for FailingFetcher {
+        fn source_name(&self) -> &'static str {
+            "failing"
+        }
+
+        async fn fetch(&self) -> Result<FeedData> {
+            anyhow::bail!("unreachable")
+        }
+    }
+
+    fn story(title: &str, time: i64) -> HnStory {
+        HnStory {
+            id: 1,
+            title: title.to_string(),
+            url: None,
+            score: 1,
+            by: "pg".to_string(),
+            descendants: 0,
+            time: Some(time),
+            kind: "story".to_string(),
+        }
+    }
+
+    fn rss_item(title: &str, timestamp: Option<i64>) -> RssItem {
+        RssItem {
+            title: title.to_string(),
+            link: None,
+            published: None,
+            timestamp,
+            source: "Lobsters".to_string(),
+            description: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_merges_sources_newest_first() {
+        let fetcher = CompositeFetcher::new(
+            vec![
+                Box::new(StaticFetcher(FeedData::HackerNews(vec![story("hn", 200)]))),
+                Box::new(StaticFetcher(FeedData::Rss(vec![
+                    rss_item("undated", None),
+                    rss_item("lobsters", Some(300)),
+                ]))),
+                Box::new(FailingFetcher),
+            ],
+            10,
+        );
+
+        let FeedData::Rss(items) = fetcher.fetch().await.unwrap() else {
+            panic!("expected rss data");
+        };
+        let titles: Vec<&str> = items.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(titles, vec!["lobsters", "hn", "undated"]);
+        assert_eq!(items[1].source, "Hacker News");
+        assert_eq!(
+            items[1].link.as_deref(),
+            Some("https://news.ycombinator.com/item?id=1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fails_only_when_every_source_fails() {
+        let fetcher = CompositeFetcher::new(vec![Box::new(FailingFetcher)], 10);
+        assert!(fetcher.fetch().await.is_err());
+    }
+}
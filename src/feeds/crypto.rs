@@ -0,0 +1,142 @@
+use super::{http_status_error, FeedData, FeedFetcher, StockQuote};
+use crate::config::CryptoCoin;
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const API_URL: &str = "https://api.coingecko.com/api/v3/simple/price";
+
+/// Coin prices from CoinGecko, as stock quotes so the stocks widget can
+/// draw them
+pub struct CryptoFetcher {
+    coins: Vec<CryptoCoin>,
+    /// Quote currency, e.g. `usd` or `eur`
+    currency: String,
+    client: Arc<reqwest::Client>,
+}
+
+/// Prices for one coin, keyed by currency (`usd`) and change
+/// (`usd_24h_change`)
+type CoinPrices = HashMap<String, Option<f64>>;
+
+impl CryptoFetcher {
+    pub fn new(coins: Vec<CryptoCoin>, currency: String, client: Arc<reqwest::Client>) -> Self {
+        Self {
+            coins,
+            currency: currency.to_lowercase(),
+            client,
+        }
+    }
+}
+
+/// Build quotes in config order. Coins CoinGecko doesn't know are left out.
+fn map_prices(
+    coins: &[CryptoCoin],
+    currency: &str,
+    prices: &HashMap<String, CoinPrices>,
+) -> Vec<StockQuote> {
+    let change_key = format!("{}_24h_change", currency);
+
+    coins
+        .iter()
+        .filter_map(|coin| {
+            let coin_prices = prices.get(&coin.id)?;
+            let price = (*coin_prices.get(currency)?)?;
+            let change_percent = coin_prices
+                .get(&change_key)
+                .copied()
+                .flatten()
+                .unwrap_or(0.0);
+            // The price a day ago, from which the percentage was taken
+            let previous = price / (1.0 + change_percent / 100.0);
+
+            Some(StockQuote {
+                symbol: coin.symbol.clone(),
+                price,
+                change: price - previous,
+                change_percent,
+                name: coin.id.clone(),
+                history: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiResponse(HashMap<String, CoinPrices>);
+
+#[async_trait]
+impl FeedFetcher for CryptoFetcher {
+    fn source_name(&self) -> &'static str {
+        "crypto"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        let ids: Vec<&str> = self.coins.iter().map(|c| c.id.as_str()).collect();
+        let response = self
+            .client
+            .get(API_URL)
+            .query(&[
+                ("ids", ids.join(",")),
+                ("vs_currencies", self.currency.clone()),
+                ("include_24hr_change", "true".to_string()),
+            ])
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(http_status_error(
+                response.status(),
+                format!("CoinGecko API error: {}", response.status()),
+            ));
+        }
+
+        let ApiResponse(prices) = response.json().await?;
+        Ok(FeedData::Stocks(map_prices(
+            &self.coins,
+            &self.currency,
+            &prices,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_prices() {
+        let coins = vec![
+            CryptoCoin {
+                id: "ethereum".to_string(),
+                symbol: "ETH".to_string(),
+            },
+            CryptoCoin {
+                id: "bitcoin".to_string(),
+                symbol: "BTC".to_string(),
+            },
+            CryptoCoin {
+                id: "not-a-coin".to_string(),
+                symbol: "NOPE".to_string(),
+            },
+        ];
+        let ApiResponse(prices) = serde_json::from_str(
+            r#"{"bitcoin": {"usd": 110.0, "usd_24h_change": 10.0},
+                "ethereum": {"usd": 3000.0, "usd_24h_change": null}}"#,
+        )
+        .unwrap();
+
+        let quotes = map_prices(&coins, "usd", &prices);
+        assert_eq!(quotes.len(), 2);
+        assert_eq!(quotes[0].symbol, "ETH");
+        assert_eq!(quotes[0].change, 0.0);
+
+        let btc = &quotes[1];
+        assert_eq!(btc.symbol, "BTC");
+        assert_eq!(btc.price, 110.0);
+        assert_eq!(btc.change_percent, 10.0);
+        assert!((btc.change - 10.0).abs() < 1e-9);
+    }
+}
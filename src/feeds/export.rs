@@ -0,0 +1,45 @@
+use super::FeedData;
+use anyhow::Result;
+use std::collections::BTreeMap;
+
+/// Render widgets' data as a JSON object keyed by widget id, for scripts
+/// and debugging. Pixel art is decoded from a local file, so it is left out.
+pub fn to_json<'a>(data: impl IntoIterator<Item = (String, &'a FeedData)>) -> Result<String> {
+    let data: BTreeMap<String, &FeedData> = data
+        .into_iter()
+        .filter(|(_, data)| !matches!(data, FeedData::PixelArt(_) | FeedData::Loading))
+        .collect();
+    Ok(serde_json::to_string_pretty(&data)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feeds::pixelart::PixelImage;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_export_is_keyed_by_widget_id() {
+        let command = FeedData::Command(vec!["up 3 days".to_string()]);
+        let error = FeedData::Error("timed out".to_string());
+        let image = FeedData::PixelArt(PixelImage {
+            frames: Vec::new(),
+            source: Arc::new(Vec::new()),
+        });
+        let json = to_json([
+            ("command-0-1".to_string(), &command),
+            ("pixelart-0-0".to_string(), &image),
+            ("rss-1-0".to_string(), &error),
+        ])
+        .unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "command-0-1": { "Command": ["up 3 days"] },
+                "rss-1-0": { "Error": "timed out" },
+            })
+        );
+    }
+}
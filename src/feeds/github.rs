@@ -1,22 +1,116 @@
 use super::{
-    FeedData, FeedFetcher, GithubCommit, GithubDashboard, GithubNotification, GithubPullRequest,
+    response_error, FeedData, FeedFetcher, GithubCommit, GithubDashboard, GithubNotification,
+    GithubPullRequest, NonRetryable,
 };
+use crate::logging;
 use anyhow::Result;
 use async_trait::async_trait;
+use reqwest::header::{HeaderMap, ETAG, IF_NONE_MATCH, LINK};
+use reqwest::StatusCode;
 use serde::Deserialize;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
 
 const GITHUB_API_BASE: &str = "https://api.github.com";
 
+const GITHUB_WEB_BASE: &str = "https://github.com";
+
+/// Largest page the notifications endpoint returns
+const NOTIFICATIONS_PER_PAGE: usize = 50;
+
+/// Most notification pages requested in one fetch, however high
+/// `max_notifications` is set
+const MAX_NOTIFICATION_PAGES: usize = 10;
+
 pub struct GithubFetcher {
     token: String,
     username: String,
     show_notifications: bool,
     show_pull_requests: bool,
     show_commits: bool,
+    /// Ask for read notifications as well (`all=true`)
+    show_all: bool,
     max_notifications: usize,
     max_pull_requests: usize,
     max_commits: usize,
-    client: reqwest::Client,
+    client: Arc<reqwest::Client>,
+    /// `GITHUB_API_BASE`, or a local server in tests
+    api_base: String,
+    /// Last notifications response, replayed when GitHub answers 304
+    notifications_cache: Mutex<Option<CachedNotifications>>,
+    /// Polling limits GitHub asked for in its last response
+    limits: Mutex<PollLimits>,
+}
+
+#[derive(Debug, Default)]
+struct PollLimits {
+    /// `X-Poll-Interval` from the notifications endpoint
+    poll_interval: Option<Duration>,
+    /// Unix time the rate limit resets, set while no requests are left
+    exhausted_until: Option<i64>,
+}
+
+impl PollLimits {
+    /// Update from the `X-Poll-Interval` and `X-RateLimit-*` headers of a
+    /// response
+    fn record(&mut self, headers: &HeaderMap) {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<i64>().ok())
+        };
+
+        if let Some(secs) = header("x-poll-interval") {
+            self.poll_interval = Some(Duration::from_secs(secs.max(0) as u64));
+        }
+        if let Some(remaining) = header("x-ratelimit-remaining") {
+            self.exhausted_until = if remaining == 0 {
+                header("x-ratelimit-reset")
+            } else {
+                None
+            };
+        }
+    }
+
+    /// Error naming the reset time while the rate limit is used up
+    fn check(&self, now: i64) -> Result<()> {
+        match self.exhausted_until {
+            Some(reset) if reset > now => {
+                let reset_at = chrono::DateTime::from_timestamp(reset, 0)
+                    .map(|t| {
+                        t.with_timezone(&chrono::Local)
+                            .format("%H:%M:%S")
+                            .to_string()
+                    })
+                    .unwrap_or_else(|| reset.to_string());
+                Err(NonRetryable(format!(
+                    "GitHub rate limit exceeded, resets at {}",
+                    reset_at
+                ))
+                .into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Shortest wait before the next poll is worthwhile
+    fn min_interval(&self, now: i64) -> Option<Duration> {
+        let until_reset = self
+            .exhausted_until
+            .filter(|&reset| reset > now)
+            .map(|reset| Duration::from_secs((reset - now) as u64));
+        match (self.poll_interval, until_reset) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+struct CachedNotifications {
+    etag: String,
+    notifications: Vec<GithubNotification>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -119,6 +213,72 @@ struct EventCommitAuthor {
     name: String,
 }
 
+/// URL of the next page from a `Link` header, e.g.
+/// `<https://api.github.com/notifications?page=2>; rel="next", <...>; rel="last"`
+fn next_page_url(headers: &HeaderMap) -> Option<String> {
+    let link = headers.get(LINK)?.to_str().ok()?;
+    link.split(',').find_map(|part| {
+        let (url, params) = part.split_once(';')?;
+        params
+            .split(';')
+            .any(|param| param.trim() == r#"rel="next""#)
+            .then(|| {
+                url.trim()
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string()
+            })
+    })
+}
+
+/// Convert API notifications into display notifications, keeping at most `max`
+/// Subjects without a web page of their own (check suites, releases the
+/// API only knows by id, ...) link to their repository instead
+fn map_notifications(api: Vec<GithubApiNotification>, max: usize) -> Vec<GithubNotification> {
+    api.into_iter()
+        .take(max)
+        .map(|n| GithubNotification {
+            url: n
+                .subject
+                .url
+                .as_deref()
+                .and_then(api_url_to_html_url)
+                .unwrap_or_else(|| format!("{}/{}", GITHUB_WEB_BASE, n.repository.full_name)),
+            id: n.id,
+            title: n.subject.title,
+            notification_type: n.subject.notification_type,
+            repository: n.repository.full_name,
+            unread: n.unread,
+            updated_at: n.updated_at,
+            reason: n.reason,
+        })
+        .collect()
+}
+
+/// Turn a notification subject's API URL into the page a browser should open,
+/// e.g. `api.github.com/repos/o/r/pulls/1` into `github.com/o/r/pull/1`.
+/// Releases are addressed by id in the API but by tag on the web, so they
+/// open the repository's releases page.
+pub fn api_url_to_html_url(api_url: &str) -> Option<String> {
+    let path = api_url
+        .strip_prefix(GITHUB_API_BASE)?
+        .strip_prefix("/repos/")?;
+    let mut parts = path.split('/');
+    let owner = parts.next().filter(|s| !s.is_empty())?;
+    let repo = parts.next().filter(|s| !s.is_empty())?;
+    let kind = parts.next()?;
+    let id = parts.next().filter(|s| !s.is_empty());
+    let page = match (kind, id) {
+        ("pulls", Some(number)) => format!("pull/{}", number),
+        ("issues", Some(number)) => format!("issues/{}", number),
+        ("discussions", Some(number)) => format!("discussions/{}", number),
+        ("commits", Some(sha)) => format!("commit/{}", sha),
+        ("releases", _) => "releases".to_string(),
+        _ => return None,
+    };
+    Some(format!("{}/{}/{}/{}", GITHUB_WEB_BASE, owner, repo, page))
+}
+
 impl GithubFetcher {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -127,9 +287,11 @@ impl GithubFetcher {
         show_notifications: bool,
         show_pull_requests: bool,
         show_commits: bool,
+        show_all: bool,
         max_notifications: usize,
         max_pull_requests: usize,
         max_commits: usize,
+        client: Arc<reqwest::Client>,
     ) -> Self {
         Self {
             token,
@@ -137,48 +299,106 @@ impl GithubFetcher {
             show_notifications,
             show_pull_requests,
             show_commits,
+            show_all,
             max_notifications,
             max_pull_requests,
             max_commits,
-            client: reqwest::Client::new(),
+            client,
+            api_base: GITHUB_API_BASE.to_string(),
+            notifications_cache: Mutex::new(None),
+            limits: Mutex::new(PollLimits::default()),
         }
     }
 
+    /// Send requests to `api_base` instead of api.github.com
+    #[cfg(test)]
+    pub(crate) fn with_api_base(mut self, api_base: &str) -> Self {
+        self.api_base = api_base.to_string();
+        self
+    }
+
+    fn check_rate_limit(&self) -> Result<()> {
+        self.limits
+            .lock()
+            .unwrap()
+            .check(chrono::Utc::now().timestamp())
+    }
+
     async fn fetch_notifications(&self) -> Result<Vec<GithubNotification>> {
-        let url = format!("{}/notifications", GITHUB_API_BASE);
+        let mut url = format!(
+            "{}/notifications?per_page={}",
+            self.api_base, NOTIFICATIONS_PER_PAGE
+        );
+        // Without `all`, GitHub only returns unread notifications
+        if self.show_all {
+            url.push_str("&all=true");
+        }
 
-        let response = self
+        let mut request = self
             .client
             .get(&url)
             .header("Authorization", format!("token {}", self.token))
-            .header("User-Agent", "feedtui")
-            .header("Accept", "application/vnd.github.v3+json")
-            .send()
-            .await?;
+            .header("Accept", "application/vnd.github.v3+json");
+
+        // A conditional request answered with 304 doesn't count against the
+        // rate limit
+        if let Some(cached) = self.notifications_cache.lock().unwrap().as_ref() {
+            request = request.header(IF_NONE_MATCH, &cached.etag);
+        }
+
+        let response = request.send().await?;
+        self.limits.lock().unwrap().record(response.headers());
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some(cached) = self.notifications_cache.lock().unwrap().as_ref() {
+                return Ok(cached.notifications.clone());
+            }
+        }
 
         if !response.status().is_success() {
-            return Err(anyhow::anyhow!(
-                "GitHub API error (notifications): {}",
-                response.status()
-            ));
+            return Err(response_error(response, "GitHub API error (notifications)").await);
         }
 
-        let api_notifications: Vec<GithubApiNotification> = response.json().await?;
-
-        let notifications: Vec<GithubNotification> = api_notifications
-            .into_iter()
-            .take(self.max_notifications)
-            .map(|n| GithubNotification {
-                id: n.id,
-                title: n.subject.title,
-                notification_type: n.subject.notification_type,
-                repository: n.repository.full_name,
-                url: n.subject.url.unwrap_or_else(|| "N/A".to_string()),
-                unread: n.unread,
-                updated_at: n.updated_at,
-                reason: n.reason,
-            })
-            .collect();
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+        let mut next_page = next_page_url(response.headers());
+        let mut api_notifications: Vec<GithubApiNotification> = response.json().await?;
+
+        // Follow `rel="next"` links until there are enough notifications
+        let mut pages = 1;
+        while let Some(url) = next_page.take() {
+            if api_notifications.len() >= self.max_notifications || pages >= MAX_NOTIFICATION_PAGES
+            {
+                break;
+            }
+
+            let response = self
+                .client
+                .get(&url)
+                .header("Authorization", format!("token {}", self.token))
+                .header("Accept", "application/vnd.github.v3+json")
+                .send()
+                .await?;
+            self.limits.lock().unwrap().record(response.headers());
+            if !response.status().is_success() {
+                return Err(response_error(response, "GitHub API error (notifications)").await);
+            }
+
+            next_page = next_page_url(response.headers());
+            let page: Vec<GithubApiNotification> = response.json().await?;
+            api_notifications.extend(page);
+            pages += 1;
+        }
+
+        let notifications = map_notifications(api_notifications, self.max_notifications);
+
+        *self.notifications_cache.lock().unwrap() = etag.map(|etag| CachedNotifications {
+            etag,
+            notifications: notifications.clone(),
+        });
 
         Ok(notifications)
     }
@@ -186,23 +406,20 @@ impl GithubFetcher {
     async fn fetch_pull_requests(&self) -> Result<Vec<GithubPullRequest>> {
         let url = format!(
             "{}/search/issues?q=involves:{}+type:pr+state:open&sort=updated&per_page={}",
-            GITHUB_API_BASE, self.username, self.max_pull_requests
+            self.api_base, self.username, self.max_pull_requests
         );
 
         let response = self
             .client
             .get(&url)
             .header("Authorization", format!("token {}", self.token))
-            .header("User-Agent", "feedtui")
             .header("Accept", "application/vnd.github.v3+json")
             .send()
             .await?;
+        self.limits.lock().unwrap().record(response.headers());
 
         if !response.status().is_success() {
-            return Err(anyhow::anyhow!(
-                "GitHub API error (pull requests): {}",
-                response.status()
-            ));
+            return Err(response_error(response, "GitHub API error (pull requests)").await);
         }
 
         #[derive(Debug, Deserialize)]
@@ -264,22 +481,19 @@ impl GithubFetcher {
     }
 
     async fn fetch_commits(&self) -> Result<Vec<GithubCommit>> {
-        let url = format!("{}/users/{}/events", GITHUB_API_BASE, self.username);
+        let url = format!("{}/users/{}/events", self.api_base, self.username);
 
         let response = self
             .client
             .get(&url)
             .header("Authorization", format!("token {}", self.token))
-            .header("User-Agent", "feedtui")
             .header("Accept", "application/vnd.github.v3+json")
             .send()
             .await?;
+        self.limits.lock().unwrap().record(response.headers());
 
         if !response.status().is_success() {
-            return Err(anyhow::anyhow!(
-                "GitHub API error (commits): {}",
-                response.status()
-            ));
+            return Err(response_error(response, "GitHub API error (commits)").await);
         }
 
         let events: Vec<GithubApiEvent> = response.json().await?;
@@ -290,13 +504,8 @@ impl GithubFetcher {
                 if let Some(event_commits) = event.payload.commits {
                     for commit in event_commits {
                         commits.push(GithubCommit {
-                            sha: commit.sha[..7].to_string(),
-                            message: commit
-                                .message
-                                .lines()
-                                .next()
-                                .unwrap_or(&commit.message)
-                                .to_string(),
+                            sha: commit.sha.clone(),
+                            message: commit.message,
                             author: commit.author.name,
                             repository: event.repo.full_name.clone(),
                             branch: "main".to_string(), // GitHub events don't always include branch
@@ -323,33 +532,230 @@ impl GithubFetcher {
 
 #[async_trait]
 impl FeedFetcher for GithubFetcher {
+    fn source_name(&self) -> &'static str {
+        "github"
+    }
+
     async fn fetch(&self) -> Result<FeedData> {
+        self.check_rate_limit()?;
         let mut dashboard = GithubDashboard::default();
 
-        // Fetch notifications if enabled
+        // The first failed section is reported once every section has been
+        // tried; otherwise a refused request would look like an empty section
+        let mut first_error = None;
+
         if self.show_notifications {
-            dashboard.notifications = self.fetch_notifications().await.unwrap_or_else(|e| {
-                eprintln!("Failed to fetch notifications: {}", e);
-                Vec::new()
-            });
+            match self.fetch_notifications().await {
+                Ok(notifications) => dashboard.notifications = notifications,
+                Err(e) => {
+                    logging::warn!("Failed to fetch GitHub notifications: {}", e);
+                    first_error.get_or_insert(e);
+                }
+            }
         }
 
-        // Fetch pull requests if enabled
         if self.show_pull_requests {
-            dashboard.pull_requests = self.fetch_pull_requests().await.unwrap_or_else(|e| {
-                eprintln!("Failed to fetch pull requests: {}", e);
-                Vec::new()
-            });
+            match self.fetch_pull_requests().await {
+                Ok(pull_requests) => dashboard.pull_requests = pull_requests,
+                Err(e) => {
+                    logging::warn!("Failed to fetch GitHub pull requests: {}", e);
+                    first_error.get_or_insert(e);
+                }
+            }
         }
 
-        // Fetch commits if enabled
         if self.show_commits {
-            dashboard.commits = self.fetch_commits().await.unwrap_or_else(|e| {
-                eprintln!("Failed to fetch commits: {}", e);
-                Vec::new()
-            });
+            match self.fetch_commits().await {
+                Ok(commits) => dashboard.commits = commits,
+                Err(e) => {
+                    logging::warn!("Failed to fetch GitHub commits: {}", e);
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        // Sections that failed because the limit ran out mid-fetch were
+        // left empty; report the limit rather than a partial dashboard
+        self.check_rate_limit()?;
+
+        if let Some(e) = first_error {
+            return Err(e);
         }
 
         Ok(FeedData::Github(dashboard))
     }
+
+    fn min_poll_interval(&self) -> Option<Duration> {
+        self.limits
+            .lock()
+            .unwrap()
+            .min_interval(chrono::Utc::now().timestamp())
+    }
+}
+
+/// Mark a notification thread as read
+pub async fn mark_thread_read(
+    client: &reqwest::Client,
+    token: &str,
+    thread_id: &str,
+) -> Result<()> {
+    let url = format!("{}/notifications/threads/{}", GITHUB_API_BASE, thread_id);
+
+    let response = client
+        .patch(&url)
+        .header("Authorization", format!("token {}", token))
+        .header("Accept", "application/vnd.github.v3+json")
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(response_error(response, "GitHub API error (mark as read)").await);
+    }
+
+    Ok(())
+}
+
+/// Abbreviated commit sha for display, like `git log --oneline`
+pub fn short_sha(sha: &str) -> &str {
+    sha.get(..7).unwrap_or(sha)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_page_url_from_link_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(next_page_url(&headers), None);
+
+        headers.insert(
+            LINK,
+            r#"<https://api.github.com/notifications?page=2>; rel="next", <https://api.github.com/notifications?page=5>; rel="last""#
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(
+            next_page_url(&headers).as_deref(),
+            Some("https://api.github.com/notifications?page=2")
+        );
+
+        headers.insert(
+            LINK,
+            r#"<https://api.github.com/notifications?page=1>; rel="prev""#
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(next_page_url(&headers), None);
+    }
+
+    #[test]
+    fn test_short_sha() {
+        assert_eq!(short_sha("0123456789abcdef"), "0123456");
+        assert_eq!(short_sha("abc"), "abc");
+    }
+
+    const NOTIFICATIONS_FIXTURE: &str = r#"[
+        {
+            "id": "1",
+            "subject": {"title": "Fix crash", "type": "PullRequest", "url": "https://api.github.com/repos/a/b/pulls/1"},
+            "repository": {"full_name": "a/b"},
+            "unread": true,
+            "updated_at": "2024-01-01T00:00:00Z",
+            "reason": "review_requested"
+        },
+        {
+            "id": "2",
+            "subject": {"title": "v1.0", "type": "Release", "url": null},
+            "repository": {"full_name": "a/c"},
+            "unread": false,
+            "updated_at": "2024-01-02T00:00:00Z",
+            "reason": "subscribed"
+        }
+    ]"#;
+
+    fn fixture() -> Vec<GithubApiNotification> {
+        serde_json::from_str(NOTIFICATIONS_FIXTURE).unwrap()
+    }
+
+    #[test]
+    fn test_map_notifications() {
+        let notifications = map_notifications(fixture(), 10);
+        assert_eq!(notifications.len(), 2);
+        assert_eq!(notifications[0].title, "Fix crash");
+        assert_eq!(notifications[0].notification_type, "PullRequest");
+        assert_eq!(notifications[0].repository, "a/b");
+        assert!(notifications[0].unread);
+        assert_eq!(notifications[0].url, "https://github.com/a/b/pull/1");
+        assert_eq!(notifications[1].url, "https://github.com/a/c");
+    }
+
+    #[test]
+    fn test_api_url_to_html_url() {
+        assert_eq!(
+            api_url_to_html_url("https://api.github.com/repos/rust-lang/rust/issues/42").as_deref(),
+            Some("https://github.com/rust-lang/rust/issues/42")
+        );
+        assert_eq!(
+            api_url_to_html_url("https://api.github.com/repos/rust-lang/rust/pulls/7").as_deref(),
+            Some("https://github.com/rust-lang/rust/pull/7")
+        );
+        assert_eq!(
+            api_url_to_html_url("https://api.github.com/repos/tokio-rs/tokio/releases/1234")
+                .as_deref(),
+            Some("https://github.com/tokio-rs/tokio/releases")
+        );
+        assert_eq!(
+            api_url_to_html_url("https://api.github.com/repos/a/b/commits/abc123").as_deref(),
+            Some("https://github.com/a/b/commit/abc123")
+        );
+        assert_eq!(
+            api_url_to_html_url("https://api.github.com/repos/a/b"),
+            None
+        );
+        assert_eq!(
+            api_url_to_html_url("https://example.com/repos/a/b/issues/1"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_map_notifications_respects_max() {
+        let notifications = map_notifications(fixture(), 1);
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].id, "1");
+    }
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_poll_limits_follow_headers() {
+        let mut limits = PollLimits::default();
+        limits.record(&headers(&[
+            ("x-poll-interval", "60"),
+            ("x-ratelimit-remaining", "4999"),
+            ("x-ratelimit-reset", "2000"),
+        ]));
+        assert!(limits.check(1000).is_ok());
+        assert_eq!(limits.min_interval(1000), Some(Duration::from_secs(60)));
+
+        limits.record(&headers(&[
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", "2000"),
+        ]));
+        let err = limits.check(1000).unwrap_err();
+        assert!(err.to_string().contains("rate limit exceeded, resets at"));
+        assert!(err.is::<NonRetryable>());
+        assert_eq!(limits.min_interval(1000), Some(Duration::from_secs(1000)));
+
+        // Past the reset time polling resumes at the requested interval
+        assert!(limits.check(2000).is_ok());
+        assert_eq!(limits.min_interval(2000), Some(Duration::from_secs(60)));
+    }
 }
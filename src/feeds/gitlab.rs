@@ -0,0 +1,167 @@
+use super::{http_status_error, FeedData, FeedFetcher, GithubDashboard, GithubNotification};
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Pending GitLab to-do items, shown as GitHub-style notifications so the
+/// GitHub dashboard can draw them
+pub struct GitlabFetcher {
+    /// Instance URL, e.g. `"https://gitlab.com"`
+    base_url: String,
+    token: String,
+    max_todos: usize,
+    client: Arc<reqwest::Client>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiTodo {
+    id: u64,
+    project: Option<ApiProject>,
+    /// Why the to-do was created, e.g. `assigned` or `review_requested`
+    action_name: String,
+    /// `Issue`, `MergeRequest`, `Commit`, ...
+    target_type: String,
+    target: Option<ApiTarget>,
+    target_url: Option<String>,
+    body: Option<String>,
+    state: String,
+    updated_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiProject {
+    path_with_namespace: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiTarget {
+    title: Option<String>,
+}
+
+/// Convert to-dos into notifications. Pending to-dos count as unread.
+fn map_todos(todos: Vec<ApiTodo>) -> Vec<GithubNotification> {
+    todos
+        .into_iter()
+        .map(|todo| GithubNotification {
+            id: todo.id.to_string(),
+            title: todo
+                .target
+                .and_then(|t| t.title)
+                .or(todo.body)
+                .unwrap_or_default(),
+            notification_type: todo.target_type,
+            repository: todo
+                .project
+                .map(|p| p.path_with_namespace)
+                .unwrap_or_default(),
+            url: todo.target_url.unwrap_or_else(|| "N/A".to_string()),
+            unread: todo.state == "pending",
+            updated_at: todo.updated_at,
+            reason: todo.action_name,
+        })
+        .collect()
+}
+
+impl GitlabFetcher {
+    pub fn new(
+        base_url: String,
+        token: String,
+        max_todos: usize,
+        client: Arc<reqwest::Client>,
+    ) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token,
+            max_todos,
+            client,
+        }
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for GitlabFetcher {
+    fn source_name(&self) -> &'static str {
+        "gitlab"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        let url = format!("{}/api/v4/todos", self.base_url);
+        let response = self
+            .client
+            .get(&url)
+            .query(&[
+                ("state", "pending".to_string()),
+                ("per_page", self.max_todos.to_string()),
+            ])
+            .header("PRIVATE-TOKEN", &self.token)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(http_status_error(
+                response.status(),
+                format!("GitLab API error: {}", response.status()),
+            ));
+        }
+
+        let todos: Vec<ApiTodo> = response.json().await?;
+        Ok(FeedData::Github(GithubDashboard {
+            notifications: map_todos(todos),
+            pull_requests: Vec::new(),
+            commits: Vec::new(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_todos() {
+        let todos: Vec<ApiTodo> = serde_json::from_str(
+            r#"[
+                {
+                    "id": 102,
+                    "project": {"id": 2, "path_with_namespace": "group/app"},
+                    "author": {"username": "alice"},
+                    "action_name": "review_requested",
+                    "target_type": "MergeRequest",
+                    "target": {"id": 34, "iid": 7, "title": "Speed up the build"},
+                    "target_url": "https://gitlab.example.com/group/app/-/merge_requests/7",
+                    "body": "Speed up the build",
+                    "state": "pending",
+                    "created_at": "2024-05-01T10:00:00.000Z",
+                    "updated_at": "2024-05-01T11:00:00.000Z"
+                },
+                {
+                    "id": 103,
+                    "project": null,
+                    "action_name": "mentioned",
+                    "target_type": "Issue",
+                    "target": null,
+                    "target_url": null,
+                    "body": "@bob can you look?",
+                    "state": "done",
+                    "updated_at": "2024-05-01T12:00:00.000Z"
+                }
+            ]"#,
+        )
+        .unwrap();
+
+        let notifications = map_todos(todos);
+        let first = &notifications[0];
+        assert_eq!(first.id, "102");
+        assert_eq!(first.title, "Speed up the build");
+        assert_eq!(first.notification_type, "MergeRequest");
+        assert_eq!(first.repository, "group/app");
+        assert_eq!(first.reason, "review_requested");
+        assert!(first.unread);
+
+        let second = &notifications[1];
+        assert_eq!(second.title, "@bob can you look?");
+        assert_eq!(second.url, "N/A");
+        assert!(!second.unread);
+    }
+}
@@ -1,14 +1,34 @@
+use super::html::html_to_text;
 use super::{FeedData, FeedFetcher, HnStory};
 use anyhow::Result;
 use async_trait::async_trait;
+use futures::future::join_all;
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
 
 const HN_API_BASE: &str = "https://hacker-news.firebaseio.com/v0";
 
+/// Replies deeper than this are not fetched (top-level comments are depth 0)
+const MAX_COMMENT_DEPTH: usize = 2;
+/// Upper bound on comment requests for one story, so huge threads stay cheap
+const MAX_COMMENTS: usize = 60;
+
+/// A comment in a story's thread
+#[derive(Debug, Clone)]
+pub struct HnComment {
+    pub by: String,
+    /// Comment body as plain text
+    pub text: String,
+    pub time: Option<i64>,
+    /// Nesting level, 0 for replies to the story itself
+    pub depth: usize,
+}
+
 pub struct HnFetcher {
     story_type: String,
     story_count: usize,
-    client: reqwest::Client,
+    client: Arc<reqwest::Client>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -19,14 +39,80 @@ struct HnItem {
     score: Option<u32>,
     by: Option<String>,
     descendants: Option<u32>,
+    time: Option<i64>,
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    #[serde(default)]
+    kids: Vec<u64>,
+    text: Option<String>,
+    #[serde(default)]
+    deleted: bool,
+    #[serde(default)]
+    dead: bool,
+}
+
+async fn fetch_item(client: &reqwest::Client, id: u64) -> Result<HnItem> {
+    let url = format!("{}/item/{}.json", HN_API_BASE, id);
+    Ok(client.get(&url).send().await?.json().await?)
+}
+
+/// Fetch a story's comment thread in display order. Each level is fetched
+/// concurrently, top-level comments first, until `MAX_COMMENT_DEPTH` or
+/// `MAX_COMMENTS` is reached; comments that fail to load are left out.
+pub async fn fetch_comments(client: &reqwest::Client, story_id: u64) -> Result<Vec<HnComment>> {
+    let story = fetch_item(client, story_id).await?;
+
+    let mut items = HashMap::new();
+    let mut level = story.kids.clone();
+    for _ in 0..=MAX_COMMENT_DEPTH {
+        level.truncate(MAX_COMMENTS - items.len());
+        if level.is_empty() {
+            break;
+        }
+
+        let fetched = join_all(level.iter().map(|&id| fetch_item(client, id))).await;
+        level = Vec::new();
+        for item in fetched.into_iter().flatten() {
+            level.extend(&item.kids);
+            items.insert(item.id, item);
+        }
+    }
+
+    Ok(thread_order(&story.kids, &items))
+}
+
+/// Flatten fetched comments into a depth-first list, so each reply follows
+/// its parent. Deleted and dead comments are dropped along with their replies.
+fn thread_order(roots: &[u64], items: &HashMap<u64, HnItem>) -> Vec<HnComment> {
+    let mut comments = Vec::new();
+    let mut stack: Vec<(u64, usize)> = roots.iter().rev().map(|&id| (id, 0)).collect();
+
+    while let Some((id, depth)) = stack.pop() {
+        let Some(item) = items.get(&id) else {
+            continue;
+        };
+        if item.deleted || item.dead {
+            continue;
+        }
+
+        comments.push(HnComment {
+            by: item.by.clone().unwrap_or_else(|| "unknown".to_string()),
+            text: html_to_text(item.text.as_deref().unwrap_or_default()),
+            time: item.time,
+            depth,
+        });
+        stack.extend(item.kids.iter().rev().map(|&kid| (kid, depth + 1)));
+    }
+
+    comments
 }
 
 impl HnFetcher {
-    pub fn new(story_type: String, story_count: usize) -> Self {
+    pub fn new(story_type: String, story_count: usize, client: Arc<reqwest::Client>) -> Self {
         Self {
             story_type,
             story_count,
-            client: reqwest::Client::new(),
+            client,
         }
     }
 
@@ -37,8 +123,7 @@ impl HnFetcher {
     }
 
     async fn fetch_story(&self, id: u64) -> Result<HnStory> {
-        let url = format!("{}/item/{}.json", HN_API_BASE, id);
-        let item: HnItem = self.client.get(&url).send().await?.json().await?;
+        let item = fetch_item(&self.client, id).await?;
 
         Ok(HnStory {
             id: item.id,
@@ -47,12 +132,18 @@ impl HnFetcher {
             score: item.score.unwrap_or(0),
             by: item.by.unwrap_or_else(|| "unknown".to_string()),
             descendants: item.descendants.unwrap_or(0),
+            time: item.time,
+            kind: item.kind.unwrap_or_else(|| "story".to_string()),
         })
     }
 }
 
 #[async_trait]
 impl FeedFetcher for HnFetcher {
+    fn source_name(&self) -> &'static str {
+        "hackernews"
+    }
+
     async fn fetch(&self) -> Result<FeedData> {
         let ids = self.fetch_story_ids().await?;
 
@@ -67,3 +158,37 @@ impl FeedFetcher for HnFetcher {
         Ok(FeedData::HackerNews(stories))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(json: &str) -> HashMap<u64, HnItem> {
+        let items: Vec<HnItem> = serde_json::from_str(json).unwrap();
+        items.into_iter().map(|item| (item.id, item)).collect()
+    }
+
+    #[test]
+    fn test_thread_order_nests_replies() {
+        let items = items(
+            r#"[
+                {"id": 1, "by": "alice", "text": "Top <i>one</i>", "kids": [3, 4]},
+                {"id": 2, "by": "bob", "text": "Top two"},
+                {"id": 3, "by": "carol", "text": "Reply", "kids": [5]},
+                {"id": 4, "deleted": true, "kids": [6]},
+                {"id": 5, "by": "dave", "text": "Nested"},
+                {"id": 6, "by": "erin", "text": "Orphaned by a deletion"}
+            ]"#,
+        );
+
+        // 7 was never fetched (over the cap), so it is skipped
+        let comments = thread_order(&[1, 7, 2], &items);
+        let outline: Vec<(&str, usize)> =
+            comments.iter().map(|c| (c.by.as_str(), c.depth)).collect();
+        assert_eq!(
+            outline,
+            vec![("alice", 0), ("carol", 1), ("dave", 2), ("bob", 0)]
+        );
+        assert_eq!(comments[0].text, "Top one");
+    }
+}
@@ -0,0 +1,155 @@
+//! Plain text from the HTML bodies some feeds deliver
+
+/// Simple HTML tag stripping
+pub fn strip_html_tags(html: &str) -> String {
+    let mut result = String::new();
+    let mut in_tag = false;
+    let mut in_entity = false;
+    let mut entity = String::new();
+
+    for ch in html.chars() {
+        if ch == '<' {
+            in_tag = true;
+        } else if ch == '>' {
+            in_tag = false;
+        } else if ch == '&' && !in_tag {
+            in_entity = true;
+            entity.clear();
+        } else if ch == ';' && in_entity {
+            in_entity = false;
+            // Convert common HTML entities
+            match entity.as_str() {
+                "amp" => result.push('&'),
+                "lt" => result.push('<'),
+                "gt" => result.push('>'),
+                "quot" => result.push('"'),
+                "apos" => result.push('\''),
+                "nbsp" => result.push(' '),
+                "#39" => result.push('\''),
+                _ => {
+                    // Try numeric entities
+                    if let Some(stripped) = entity.strip_prefix('#') {
+                        if let Ok(code) = stripped.parse::<u32>() {
+                            if let Some(c) = char::from_u32(code) {
+                                result.push(c);
+                            }
+                        }
+                    }
+                }
+            }
+            entity.clear();
+        } else if in_entity {
+            entity.push(ch);
+        } else if !in_tag {
+            result.push(ch);
+        }
+    }
+
+    // Clean up multiple whitespace
+    let mut clean = String::new();
+    let mut last_was_space = false;
+    for ch in result.chars() {
+        if ch.is_whitespace() {
+            if !last_was_space {
+                clean.push(if ch == '\n' { '\n' } else { ' ' });
+                last_was_space = true;
+            }
+        } else {
+            clean.push(ch);
+            last_was_space = false;
+        }
+    }
+
+    clean.trim().to_string()
+}
+
+/// Tags that end a paragraph of text
+const BLOCK_TAGS: &[&str] = &[
+    "p",
+    "div",
+    "blockquote",
+    "pre",
+    "ul",
+    "ol",
+    "li",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "table",
+    "tr",
+    "hr",
+];
+
+/// Convert an HTML article body into readable plain text: paragraphs are
+/// separated by a blank line, `<br>` starts a new line and list items are
+/// bulleted. Whitespace in the source markup is collapsed as a browser would.
+pub fn html_to_text(html: &str) -> String {
+    let mut marked = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        marked.push_str(&rest[..start].replace(['\n', '\r', '\t'], " "));
+        let Some(end) = rest[start..].find('>') else {
+            // Unclosed tag: keep the text and let strip_html_tags drop it
+            marked.push_str(&rest[start..]);
+            rest = "";
+            break;
+        };
+
+        let tag = &rest[start + 1..start + end];
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        if name == "br" {
+            marked.push('\n');
+        } else if name == "li" && !closing {
+            marked.push_str("\n\n• ");
+        } else if BLOCK_TAGS.contains(&name.as_str()) {
+            marked.push_str("\n\n");
+        }
+        rest = &rest[start + end + 1..];
+    }
+    marked.push_str(&rest.replace(['\n', '\r', '\t'], " "));
+
+    marked
+        .split("\n\n")
+        .map(|paragraph| {
+            paragraph
+                .split('\n')
+                .map(strip_html_tags)
+                .filter(|line| !line.is_empty() && line != "•")
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_text_keeps_paragraphs() {
+        let html = "<p>First   paragraph\nspans lines.</p>\n<p>Second &amp; last<br/>line two</p>\
+                    <ul><li>one</li><li>two</li></ul>";
+        assert_eq!(
+            html_to_text(html),
+            "First paragraph spans lines.\n\nSecond & last\nline two\n\n• one\n\n• two"
+        );
+    }
+
+    #[test]
+    fn test_html_to_text_plain_text_is_untouched() {
+        assert_eq!(html_to_text("Just a summary."), "Just a summary.");
+    }
+}
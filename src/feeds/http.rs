@@ -0,0 +1,169 @@
+use super::{FeedData, FeedFetcher, NonRetryable};
+use crate::config::HttpConfig;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+
+const USER_AGENT: &str = concat!("feedtui/", env!("CARGO_PKG_VERSION"));
+
+/// Upper bound for a single request. Kept below `FETCH_TIMEOUT`, which
+/// bounds a whole attempt that may make several requests.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn client_builder() -> reqwest::ClientBuilder {
+    reqwest::Client::builder()
+        .user_agent(USER_AGENT)
+        .timeout(REQUEST_TIMEOUT)
+        .connect_timeout(CONNECT_TIMEOUT)
+}
+
+/// The client all fetchers share, so every request reuses one connection
+/// pool. Panics if the TLS backend can't be initialized, like
+/// `reqwest::Client::new`.
+pub fn shared_client() -> Arc<reqwest::Client> {
+    Arc::new(
+        client_builder()
+            .build()
+            .expect("Failed to build HTTP client"),
+    )
+}
+
+/// The shared client, or a dedicated one when a widget sets its own proxy or
+/// TLS options
+pub fn client_for(
+    shared: &Arc<reqwest::Client>,
+    options: &HttpConfig,
+) -> Result<Arc<reqwest::Client>> {
+    if options.proxy.is_none() && !options.danger_accept_invalid_certs {
+        return Ok(shared.clone());
+    }
+    build_client(options).map(Arc::new)
+}
+
+/// Build a fetcher on the client for a widget's HTTP `options` (see
+/// `client_for`). Invalid options can't be fixed by fetching again, so the
+/// widget gets a fetcher that reports them as a `NonRetryable` error.
+pub fn with_client(
+    shared: &Arc<reqwest::Client>,
+    options: &HttpConfig,
+    build: impl FnOnce(Arc<reqwest::Client>) -> Box<dyn FeedFetcher>,
+) -> Box<dyn FeedFetcher> {
+    match client_for(shared, options) {
+        Ok(client) => build(client),
+        Err(e) => Box::new(InvalidOptions(format!("{:#}", e))),
+    }
+}
+
+/// Stands in for the fetcher of a widget whose HTTP options are invalid
+struct InvalidOptions(String);
+
+#[async_trait]
+impl FeedFetcher for InvalidOptions {
+    fn source_name(&self) -> &'static str {
+        "http"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        Err(NonRetryable(self.0.clone()).into())
+    }
+}
+
+/// Build an HTTP client honoring a widget's proxy and TLS options
+pub fn build_client(options: &HttpConfig) -> Result<reqwest::Client> {
+    let mut builder = client_builder();
+
+    if let Some(ref proxy) = options.proxy {
+        let proxy =
+            reqwest::Proxy::all(proxy).with_context(|| format!("Invalid proxy URL: {}", proxy))?;
+        builder = builder.proxy(proxy);
+    }
+
+    if options.danger_accept_invalid_certs {
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+
+    builder.build().context("Failed to build HTTP client")
+}
+
+/// Serve `routes` (path, status line, body) on a local port for tests,
+/// answering any other path with 404. Returns the server's base URL.
+#[cfg(test)]
+pub(crate) async fn serve(routes: &'static [(&'static str, &'static str, &'static str)]) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut request = [0u8; 4096];
+            let read = socket.read(&mut request).await.unwrap_or(0);
+            // "GET /path?query HTTP/1.1"
+            let request = String::from_utf8_lossy(&request[..read]);
+            let path = request
+                .split_whitespace()
+                .nth(1)
+                .and_then(|target| target.split('?').next())
+                .unwrap_or_default();
+            let (status, body) = routes.iter().find(|(route, _, _)| *route == path).map_or(
+                ("404 Not Found", "<html>Not Found</html>"),
+                |(_, status, body)| (status, body),
+            );
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+        }
+    });
+    base
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_options_build() {
+        assert!(build_client(&HttpConfig::default()).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_proxy_is_an_error() {
+        let options = HttpConfig {
+            proxy: Some("not a url".to_string()),
+            ..HttpConfig::default()
+        };
+        let err = build_client(&options).unwrap_err().to_string();
+        assert!(err.contains("not a url"));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_options_are_reported_without_retrying() {
+        let options = HttpConfig {
+            proxy: Some("not a url".to_string()),
+            ..HttpConfig::default()
+        };
+        let fetcher = with_client(&shared_client(), &options, |_| unreachable!());
+        let err = fetcher.fetch().await.unwrap_err();
+        assert!(err.is::<NonRetryable>());
+        assert!(err.to_string().contains("not a url"));
+    }
+
+    #[test]
+    fn test_default_options_reuse_shared_client() {
+        let shared = shared_client();
+        let client = client_for(&shared, &HttpConfig::default()).unwrap();
+        assert!(Arc::ptr_eq(&shared, &client));
+
+        let options = HttpConfig {
+            danger_accept_invalid_certs: true,
+            ..HttpConfig::default()
+        };
+        let client = client_for(&shared, &options).unwrap();
+        assert!(!Arc::ptr_eq(&shared, &client));
+    }
+}
@@ -0,0 +1,208 @@
+use super::{http_status_error, FeedData, FeedFetcher, NonRetryable, RssItem};
+use crate::config::JsonPaths;
+use anyhow::Result;
+use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Polls an arbitrary JSON endpoint and maps its items onto `RssItem`s
+pub struct JsonPollFetcher {
+    url: String,
+    source: String,
+    paths: JsonPaths,
+    max_items: usize,
+    client: Arc<reqwest::Client>,
+}
+
+impl JsonPollFetcher {
+    pub fn new(
+        url: String,
+        source: String,
+        paths: JsonPaths,
+        max_items: usize,
+        client: Arc<reqwest::Client>,
+    ) -> Self {
+        Self {
+            url,
+            source,
+            paths,
+            max_items,
+            client,
+        }
+    }
+}
+
+/// Follow a dotted path such as `$.data.items[0].title`. A leading `$` and
+/// an empty path both refer to `value` itself.
+fn resolve<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    let path = path.strip_prefix('$').unwrap_or(path);
+    let mut current = value;
+
+    for segment in path.split('.').filter(|s| !s.is_empty()) {
+        let (key, indices) = match segment.find('[') {
+            Some(i) => segment.split_at(i),
+            None => (segment, ""),
+        };
+        if !key.is_empty() {
+            current = match current {
+                Value::Object(map) => map.get(key)?,
+                // Allow `items.0` as well as `items[0]`
+                Value::Array(items) => items.get(key.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+        for index in indices.split('[').skip(1) {
+            let index: usize = index.strip_suffix(']')?.parse().ok()?;
+            current = current.as_array()?.get(index)?;
+        }
+    }
+
+    Some(current)
+}
+
+/// Text of a resolved field. Strings are used as-is; numbers and booleans are
+/// printed.
+fn field_text(item: &Value, path: &str) -> Option<String> {
+    match resolve(item, path)? {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Map a response onto items. Fails naming the path when a configured path
+/// doesn't resolve, since that means the config doesn't match the endpoint.
+fn map_items(
+    body: &Value,
+    paths: &JsonPaths,
+    source: &str,
+    max_items: usize,
+) -> Result<Vec<RssItem>> {
+    let items_path = paths.items_path.as_deref().unwrap_or("");
+    let items = resolve(body, items_path)
+        .and_then(Value::as_array)
+        .ok_or_else(|| {
+            NonRetryable(format!(
+                "items_path '{}' does not resolve to an array",
+                items_path
+            ))
+        })?;
+
+    let missing = |field: &str, path: &str, i: usize| {
+        NonRetryable(format!("{} '{}' not found in item {}", field, path, i + 1))
+    };
+
+    items
+        .iter()
+        .take(max_items)
+        .enumerate()
+        .map(|(i, item)| {
+            let title = field_text(item, &paths.title_path)
+                .ok_or_else(|| missing("title_path", &paths.title_path, i))?;
+            let link = match &paths.url_path {
+                Some(path) => {
+                    Some(field_text(item, path).ok_or_else(|| missing("url_path", path, i))?)
+                }
+                None => None,
+            };
+            let subtitle = match &paths.subtitle_path {
+                Some(path) => {
+                    Some(field_text(item, path).ok_or_else(|| missing("subtitle_path", path, i))?)
+                }
+                None => None,
+            };
+
+            Ok(RssItem {
+                title,
+                link,
+                published: None,
+                timestamp: None,
+                source: subtitle.unwrap_or_else(|| source.to_string()),
+                description: None,
+            })
+        })
+        .collect()
+}
+
+#[async_trait]
+impl FeedFetcher for JsonPollFetcher {
+    fn source_name(&self) -> &'static str {
+        "json_poll"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        let response = self
+            .client
+            .get(&self.url)
+            .header("Accept", "application/json")
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(http_status_error(
+                response.status(),
+                format!("JSON endpoint error: {}", response.status()),
+            ));
+        }
+
+        let body: Value = response.json().await?;
+        let items = map_items(&body, &self.paths, &self.source, self.max_items)?;
+
+        Ok(FeedData::Rss(items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn paths(items: Option<&str>, title: &str, url: Option<&str>) -> JsonPaths {
+        JsonPaths {
+            items_path: items.map(String::from),
+            title_path: title.to_string(),
+            url_path: url.map(String::from),
+            subtitle_path: None,
+        }
+    }
+
+    #[test]
+    fn test_resolve_paths() {
+        let value = json!({"data": {"items": [{"tags": ["a", "b"]}]}});
+        assert_eq!(
+            resolve(&value, "$.data.items[0].tags[1]"),
+            Some(&json!("b"))
+        );
+        assert_eq!(resolve(&value, "data.items.0.tags.0"), Some(&json!("a")));
+        assert_eq!(resolve(&value, "$"), Some(&value));
+        assert_eq!(resolve(&value, "data.missing"), None);
+    }
+
+    #[test]
+    fn test_map_items() {
+        let body = json!({"results": [
+            {"name": "Deploy #42", "links": {"html": "https://ci/42"}, "status": "passed"},
+            {"name": "Deploy #41", "links": {"html": "https://ci/41"}, "status": "failed"}
+        ]});
+        let mut paths = paths(Some("$.results"), "name", Some("links.html"));
+        paths.subtitle_path = Some("status".to_string());
+
+        let items = map_items(&body, &paths, "CI", 10).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].title, "Deploy #42");
+        assert_eq!(items[0].link.as_deref(), Some("https://ci/42"));
+        assert_eq!(items[1].source, "failed");
+    }
+
+    #[test]
+    fn test_unresolved_path_is_an_error() {
+        let body = json!([{"title": "ok"}, {"name": "no title"}]);
+
+        let err = map_items(&body, &paths(None, "title", None), "feed", 10).unwrap_err();
+        assert_eq!(err.to_string(), "title_path 'title' not found in item 2");
+
+        let err = map_items(&body, &paths(Some("data"), "title", None), "feed", 10).unwrap_err();
+        assert!(err.to_string().contains("items_path 'data'"));
+    }
+}
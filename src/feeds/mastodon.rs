@@ -0,0 +1,160 @@
+use super::html::strip_html_tags;
+use super::{http_status_error, FeedData, FeedFetcher, Toot};
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::sync::Arc;
+
+pub struct MastodonFetcher {
+    instance: String,
+    access_token: String,
+    max_items: usize,
+    client: Arc<reqwest::Client>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiStatus {
+    id: String,
+    created_at: String,
+    content: String,
+    url: Option<String>,
+    uri: String,
+    favourites_count: u32,
+    reblogs_count: u32,
+    account: Account,
+    reblog: Option<Box<ApiStatus>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Account {
+    acct: String,
+}
+
+/// Plain text of a status body. Paragraphs and line breaks become spaces so
+/// the text reads as one line.
+fn status_text(html: &str) -> String {
+    let html = html
+        .replace("</p><p>", " ")
+        .replace("<br>", " ")
+        .replace("<br/>", " ")
+        .replace("<br />", " ");
+    strip_html_tags(&html)
+}
+
+/// Convert API statuses into toots. Boosts show the boosted status, noting
+/// who boosted it.
+fn map_statuses(statuses: Vec<ApiStatus>) -> Vec<Toot> {
+    statuses
+        .into_iter()
+        .map(|status| {
+            let (status, boosted_by) = match status.reblog {
+                Some(original) => (*original, Some(format!("@{}", status.account.acct))),
+                None => (status, None),
+            };
+            Toot {
+                id: status.id,
+                author: format!("@{}", status.account.acct),
+                content: status_text(&status.content),
+                favourites: status.favourites_count,
+                reblogs: status.reblogs_count,
+                url: status.url.unwrap_or(status.uri),
+                boosted_by,
+                timestamp: chrono::DateTime::parse_from_rfc3339(&status.created_at)
+                    .ok()
+                    .map(|t| t.timestamp()),
+            }
+        })
+        .collect()
+}
+
+impl MastodonFetcher {
+    pub fn new(
+        instance: String,
+        access_token: String,
+        max_items: usize,
+        client: Arc<reqwest::Client>,
+    ) -> Self {
+        Self {
+            instance,
+            access_token,
+            max_items,
+            client,
+        }
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for MastodonFetcher {
+    fn source_name(&self) -> &'static str {
+        "mastodon"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        let url = format!(
+            "{}/api/v1/timelines/home?limit={}",
+            self.instance.trim_end_matches('/'),
+            self.max_items
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .bearer_auth(&self.access_token)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(http_status_error(
+                response.status(),
+                format!("Mastodon API error: {}", response.status()),
+            ));
+        }
+
+        let statuses: Vec<ApiStatus> = response.json().await?;
+        Ok(FeedData::Mastodon(map_statuses(statuses)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMELINE_FIXTURE: &str = r#"[
+        {
+            "id": "2",
+            "created_at": "2024-05-01T12:00:00.000Z",
+            "content": "",
+            "url": null,
+            "uri": "https://example.social/users/bob/statuses/2/activity",
+            "favourites_count": 0,
+            "reblogs_count": 0,
+            "account": {"acct": "bob"},
+            "reblog": {
+                "id": "1",
+                "created_at": "2024-05-01T11:00:00.000Z",
+                "content": "<p>Hello &amp; welcome</p><p>to <a href=\"https://x\">the fediverse</a></p>",
+                "url": "https://other.social/@alice/1",
+                "uri": "https://other.social/users/alice/statuses/1",
+                "favourites_count": 12,
+                "reblogs_count": 3,
+                "account": {"acct": "alice@other.social"},
+                "reblog": null
+            }
+        }
+    ]"#;
+
+    #[test]
+    fn test_map_statuses_unwraps_boosts() {
+        let statuses: Vec<ApiStatus> = serde_json::from_str(TIMELINE_FIXTURE).unwrap();
+        let toots = map_statuses(statuses);
+
+        assert_eq!(toots.len(), 1);
+        let toot = &toots[0];
+        assert_eq!(toot.author, "@alice@other.social");
+        assert_eq!(toot.boosted_by.as_deref(), Some("@bob"));
+        assert_eq!(toot.content, "Hello & welcome to the fediverse");
+        assert_eq!((toot.favourites, toot.reblogs), (12, 3));
+        assert_eq!(toot.url, "https://other.social/@alice/1");
+        assert_eq!(toot.timestamp, Some(1714561200));
+    }
+}
@@ -0,0 +1,85 @@
+use super::{FeedData, FeedFetcher};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+enum MockSource {
+    /// Data given directly, for tests
+    #[cfg(test)]
+    Data(FeedData),
+    /// A widget's entry in a JSON file keyed by widget id
+    File { path: PathBuf, widget_id: String },
+}
+
+/// Returns canned data instead of fetching, for demos, screenshots and
+/// tests that shouldn't touch the network
+pub struct MockFetcher {
+    source: MockSource,
+}
+
+impl MockFetcher {
+    #[cfg(test)]
+    pub fn new(data: FeedData) -> Self {
+        Self {
+            source: MockSource::Data(data),
+        }
+    }
+
+    /// Serve `widget_id`'s entry from a fixture in the format written by the
+    /// export key. The file is read on every fetch, so edits to it show up
+    /// on the next refresh.
+    pub fn from_file(path: PathBuf, widget_id: String) -> Self {
+        Self {
+            source: MockSource::File { path, widget_id },
+        }
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for MockFetcher {
+    fn source_name(&self) -> &'static str {
+        "mock"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        match &self.source {
+            #[cfg(test)]
+            MockSource::Data(data) => Ok(data.clone()),
+            MockSource::File { path, widget_id } => {
+                let content = std::fs::read_to_string(path)
+                    .with_context(|| format!("Reading mock data from {}", path.display()))?;
+                let mut fixtures: HashMap<String, FeedData> = serde_json::from_str(&content)
+                    .with_context(|| format!("Parsing mock data in {}", path.display()))?;
+                fixtures
+                    .remove(widget_id)
+                    .with_context(|| format!("No mock data for {}", widget_id))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feeds::export;
+
+    #[tokio::test]
+    async fn test_serves_exported_data_by_widget_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("export.json");
+        let command = FeedData::Command(vec!["up 3 days".to_string()]);
+        let json = export::to_json([("command-0-0".to_string(), &command)]).unwrap();
+        std::fs::write(&path, json).unwrap();
+
+        let fetcher = MockFetcher::from_file(path.clone(), "command-0-0".to_string());
+        let FeedData::Command(lines) = fetcher.fetch().await.unwrap() else {
+            panic!("expected command output");
+        };
+        assert_eq!(lines, ["up 3 days"]);
+
+        let missing = MockFetcher::from_file(path, "rss-0-1".to_string());
+        let err = missing.fetch().await.unwrap_err();
+        assert_eq!(err.to_string(), "No mock data for rss-0-1");
+    }
+}
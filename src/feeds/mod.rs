@@ -1,12 +1,104 @@
+pub mod arxiv;
+pub mod bluesky;
+pub mod cache;
+pub mod calendar;
+pub mod command;
+pub mod composite;
+pub mod crypto;
+pub mod export;
 pub mod github;
+pub mod gitlab;
 pub mod hackernews;
+pub mod html;
+pub mod http;
+pub mod json_poll;
+pub mod mastodon;
+pub mod mock;
+pub mod opml;
+pub mod pixelart;
+pub mod podcast;
+pub mod reddit;
+pub mod releases;
 pub mod rss;
 pub mod sports;
 pub mod stocks;
+pub mod timeline;
+pub mod weather;
 pub mod youtube;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+/// Upper bound for each fetch attempt, so a dead API can't leave a widget
+/// loading forever. Every retry gets the full time again.
+pub const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How many times the scheduler retries a failed fetch before giving up.
+/// A widget still loading is shown the first failure while it retries.
+pub const FETCH_RETRIES: usize = 3;
+
+/// Delay before the first retry; doubled for each retry after that
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Marks a fetch failure that retrying can't fix, such as bad credentials
+/// or any other 4xx response
+#[derive(Debug)]
+pub struct NonRetryable(pub String);
+
+impl fmt::Display for NonRetryable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for NonRetryable {}
+
+/// Error for an unsuccessful HTTP response. Client errors (4xx) are marked
+/// `NonRetryable` since sending the same request again won't help.
+pub fn http_status_error(status: reqwest::StatusCode, message: String) -> anyhow::Error {
+    if status.is_client_error() {
+        NonRetryable(message).into()
+    } else {
+        anyhow::anyhow!(message)
+    }
+}
+
+/// Error for an unsuccessful HTTP response that carries the response body,
+/// which usually says why the request was refused (missing scope, rate
+/// limit, ...). Classified like `http_status_error`.
+pub async fn response_error(response: reqwest::Response, context: &str) -> anyhow::Error {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    http_status_error(status, describe_response(context, status, &body))
+}
+
+fn describe_response(context: &str, status: reqwest::StatusCode, body: &str) -> String {
+    let body = body.trim();
+    if body.is_empty() {
+        format!("{}: {}", context, status)
+    } else {
+        format!("{}: {}\n{}", context, status, body)
+    }
+}
+
+/// Whether a failed fetch is worth repeating. Everything is retried except
+/// errors marked `NonRetryable`, 4xx responses and undecodable bodies.
+fn is_retryable(err: &anyhow::Error) -> bool {
+    for cause in err.chain() {
+        if cause.is::<NonRetryable>() {
+            return false;
+        }
+        if let Some(e) = cause.downcast_ref::<reqwest::Error>() {
+            if e.is_decode() || e.status().is_some_and(|s| s.is_client_error()) {
+                return false;
+            }
+        }
+    }
+    true
+}
 
 #[derive(Debug, Clone)]
 pub struct FeedMessage {
@@ -14,7 +106,7 @@ pub struct FeedMessage {
     pub data: FeedData,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum FeedData {
     HackerNews(Vec<HnStory>),
     Stocks(Vec<StockQuote>),
@@ -22,11 +114,24 @@ pub enum FeedData {
     Sports(Vec<SportsEvent>),
     Github(GithubDashboard),
     Youtube(Vec<YoutubeVideo>),
+    Timeline(Vec<TimelineItem>),
+    Reddit(Vec<RedditPost>),
+    Mastodon(Vec<Toot>),
+    Bluesky(Vec<BlueskyPost>),
+    Weather(WeatherReport),
+    Calendar(Vec<CalendarEvent>),
+    Releases(Vec<ReleaseInfo>),
+    Podcast(Vec<PodcastEpisode>),
+    /// Output of a shell command, one entry per line
+    Command(Vec<String>),
+    /// Decoded from a local file, so never cached
+    #[serde(skip)]
+    PixelArt(pixelart::PixelImage),
     Loading,
     Error(String),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HnStory {
     pub id: u64,
     pub title: String,
@@ -34,9 +139,119 @@ pub struct HnStory {
     pub score: u32,
     pub by: String,
     pub descendants: u32,
+    /// Unix timestamp of submission
+    pub time: Option<i64>,
+    /// HN item type: "story", "job" or "poll"
+    #[serde(default)]
+    pub kind: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedditPost {
+    pub title: String,
+    /// Full URL of the comments page
+    pub permalink: String,
+    pub score: i64,
+    pub author: String,
+    pub num_comments: u32,
+    pub subreddit: String,
+    /// Unix timestamp of submission
+    pub timestamp: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Toot {
+    pub id: String,
+    /// Account handle, e.g. `@alice@example.social`
+    pub author: String,
+    /// Status body with HTML stripped
+    pub content: String,
+    pub favourites: u32,
+    pub reblogs: u32,
+    pub url: String,
+    /// Handle of the account that boosted this toot into the timeline
+    pub boosted_by: Option<String>,
+    /// Unix timestamp of posting
+    pub timestamp: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlueskyPost {
+    /// AT URI of the post
+    pub id: String,
+    /// Account handle, e.g. `@alice.bsky.social`
+    pub author: String,
+    pub text: String,
+    pub likes: u32,
+    pub reposts: u32,
+    /// Post page on bsky.app
+    pub url: String,
+    /// Handle of the account that reposted this post into the timeline
+    pub reposted_by: Option<String>,
+    /// Unix timestamp of posting
+    pub timestamp: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeatherReport {
+    pub temperature: f64,
+    /// WMO weather interpretation code
+    pub weather_code: u8,
+    pub wind_speed: f64,
+    /// e.g. `°C`
+    pub temperature_unit: String,
+    /// e.g. `km/h`
+    pub wind_speed_unit: String,
+    /// The coming hours, soonest first
+    pub hourly: Vec<HourlyForecast>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HourlyForecast {
+    /// Local time of day, e.g. `15:00`
+    pub time: String,
+    pub temperature: f64,
+    pub weather_code: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarEvent {
+    pub summary: String,
+    pub location: Option<String>,
+    /// Unix timestamp of the start; midnight local time for all-day events
+    pub start: i64,
+    /// Unix timestamp of the end
+    pub end: i64,
+    pub all_day: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseInfo {
+    /// Identifier from the config, e.g. `crate:serde`
+    pub package: String,
+    pub version: String,
+    /// Unix timestamp the version was published
+    pub published: Option<i64>,
+    /// Package page on the registry's website
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodcastEpisode {
+    pub title: String,
+    /// Title of the feed the episode came from
+    pub show: String,
+    /// The `<enclosure>` URL, if the item has one
+    pub audio_url: Option<String>,
+    /// Episode page, for items without audio
+    pub link: Option<String>,
+    /// Unix timestamp the episode was published
+    pub published: Option<i64>,
+    /// Length from `<itunes:duration>`
+    pub duration_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StockQuote {
     pub symbol: String,
     pub price: f64,
@@ -44,18 +259,23 @@ pub struct StockQuote {
     pub change_percent: f64,
     #[allow(dead_code)]
     pub name: String,
+    /// Intraday prices, oldest first
+    #[serde(default)]
+    pub history: Vec<f64>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RssItem {
     pub title: String,
     pub link: Option<String>,
     pub published: Option<String>,
+    /// Unix timestamp of `published`, used for ordering
+    pub timestamp: Option<i64>,
     pub source: String,
     pub description: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SportsEvent {
     pub league: String,
     pub home_team: String,
@@ -63,26 +283,23 @@ pub struct SportsEvent {
     pub home_score: Option<u32>,
     pub away_score: Option<u32>,
     pub status: String,
-    #[allow(dead_code)]
     pub start_time: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GithubNotification {
-    #[allow(dead_code)]
     pub id: String,
     pub title: String,
     pub notification_type: String,
     pub repository: String,
-    #[allow(dead_code)]
+    /// Web page for the subject, not its API URL
     pub url: String,
     pub unread: bool,
-    #[allow(dead_code)]
     pub updated_at: String,
     pub reason: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GithubPullRequest {
     #[allow(dead_code)]
     pub id: u64,
@@ -107,7 +324,7 @@ pub struct GithubPullRequest {
     pub deletions: u32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GithubCommit {
     pub sha: String,
     pub message: String,
@@ -124,27 +341,273 @@ pub struct GithubCommit {
     pub url: String,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GithubDashboard {
     pub notifications: Vec<GithubNotification>,
     pub pull_requests: Vec<GithubPullRequest>,
     pub commits: Vec<GithubCommit>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct YoutubeVideo {
     pub id: String,
     pub title: String,
     pub channel: String,
     pub published: String,
     pub description: String,
-    #[allow(dead_code)]
     pub thumbnail_url: Option<String>,
     pub view_count: Option<String>,
     pub duration: Option<String>,
+    /// Unix timestamp of `published`, used for ordering
+    pub timestamp: Option<i64>,
+}
+
+/// An item from any list feed, normalized for the merged timeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineItem {
+    pub title: String,
+    pub url: Option<String>,
+    pub description: Option<String>,
+    /// Title of the widget the item came from
+    pub source: String,
+    /// Unix timestamp; items without one sort last
+    pub timestamp: Option<i64>,
 }
 
 #[async_trait]
 pub trait FeedFetcher: Send + Sync {
+    /// Stable identifier of the kind of source, e.g. `"github"`, for log
+    /// messages about failed fetches
+    fn source_name(&self) -> &'static str;
+
     async fn fetch(&self) -> Result<FeedData>;
+
+    /// Shortest wait the source allows before the next fetch, e.g. from
+    /// polling or rate-limit hints in its last response
+    fn min_poll_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Whether fetching stays on this machine, so it keeps running while
+    /// background fetching is paused
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    /// Call `fetch`, retrying transient failures up to `attempts` more times
+    /// with exponential backoff (500ms, 1s, 2s, ...). Each attempt is given
+    /// up after `timeout`, which counts as a transient failure. Errors that
+    /// can't be fixed by retrying are returned immediately.
+    async fn fetch_with_retry(&self, attempts: usize, timeout: Duration) -> Result<FeedData> {
+        self.fetch_with_retry_notify(attempts, timeout, &|_| {})
+            .await
+    }
+
+    /// `fetch_with_retry`, handing each failure that is about to be retried
+    /// to `on_retry`
+    async fn fetch_with_retry_notify(
+        &self,
+        attempts: usize,
+        timeout: Duration,
+        on_retry: &(dyn for<'e> Fn(&'e anyhow::Error) + Send + Sync),
+    ) -> Result<FeedData> {
+        let mut delay = RETRY_BASE_DELAY;
+        let mut retries = 0;
+        loop {
+            let result = match tokio::time::timeout(timeout, self.fetch()).await {
+                Ok(result) => result,
+                Err(_) => Err(anyhow::anyhow!(
+                    "Request timed out after {}s",
+                    timeout.as_secs()
+                )),
+            };
+            match result {
+                Ok(data) => return Ok(data),
+                Err(e) if retries < attempts && is_retryable(&e) => {
+                    on_retry(&e);
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                    retries += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// Run a fetch with retries, each attempt bounded by `timeout`. Failures
+/// and timeouts are turned into `FeedData::Error` so the receiving widget
+/// always leaves its loading state.
+pub async fn fetch_with_timeout(
+    fetcher: &dyn FeedFetcher,
+    retries: usize,
+    timeout: Duration,
+) -> FeedData {
+    match fetcher.fetch_with_retry(retries, timeout).await {
+        Ok(data) => data,
+        Err(e) => FeedData::Error(e.to_string()),
+    }
+}
+
+/// `fetch_with_timeout`, also handing each failure that is about to be
+/// retried to `on_retry` as `FeedData::Error`. A widget with nothing to show
+/// yet can then leave its loading state after the first failed attempt
+/// while the retries carry on behind it.
+pub async fn fetch_with_timeout_notify(
+    fetcher: &dyn FeedFetcher,
+    retries: usize,
+    timeout: Duration,
+    on_retry: &(dyn Fn(FeedData) + Send + Sync),
+) -> FeedData {
+    let on_retry = |e: &anyhow::Error| on_retry(FeedData::Error(e.to_string()));
+    match fetcher
+        .fetch_with_retry_notify(retries, timeout, &on_retry)
+        .await
+    {
+        Ok(data) => data,
+        Err(e) => FeedData::Error(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_response_description_includes_body() {
+        let status = reqwest::StatusCode::FORBIDDEN;
+        assert_eq!(
+            describe_response(
+                "GitHub API error",
+                status,
+                " {\"message\":\"Bad credentials\"}\n"
+            ),
+            "GitHub API error: 403 Forbidden\n{\"message\":\"Bad credentials\"}"
+        );
+        assert_eq!(
+            describe_response("GitHub API error", status, ""),
+            "GitHub API error: 403 Forbidden"
+        );
+    }
+
+    /// Fails `failures` times, then succeeds
+    struct FlakyFetcher {
+        failures: usize,
+        calls: AtomicUsize,
+        permanent: bool,
+        /// Fail by never answering instead of with an error
+        hang: bool,
+    }
+
+    impl FlakyFetcher {
+        fn new(failures: usize) -> Self {
+            Self {
+                failures,
+                calls: AtomicUsize::new(0),
+                permanent: false,
+                hang: false,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl FeedFetcher for FlakyFetcher {
+        fn source_name(&self) -> &'static str {
+            "flaky"
+        }
+
+        async fn fetch(&self) -> Result<FeedData> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.permanent {
+                return Err(NonRetryable("401 Unauthorized".to_string()).into());
+            }
+            if call < self.failures {
+                if self.hang {
+                    std::future::pending::<()>().await;
+                }
+                anyhow::bail!("connection reset");
+            }
+            Ok(FeedData::Rss(Vec::new()))
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_succeeds_after_transient_failures() {
+        let fetcher = FlakyFetcher::new(2);
+        let start = tokio::time::Instant::now();
+
+        let result = fetcher.fetch_with_retry(3, FETCH_TIMEOUT).await;
+
+        assert!(matches!(result, Ok(FeedData::Rss(_))));
+        assert_eq!(fetcher.calls.load(Ordering::SeqCst), 3);
+        // 500ms + 1s of backoff
+        assert_eq!(start.elapsed(), Duration::from_millis(1500));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_each_attempt_gets_its_own_timeout() {
+        let fetcher = FlakyFetcher {
+            hang: true,
+            ..FlakyFetcher::new(1)
+        };
+        let start = tokio::time::Instant::now();
+
+        let result = fetcher.fetch_with_retry(3, FETCH_TIMEOUT).await;
+
+        assert!(matches!(result, Ok(FeedData::Rss(_))));
+        assert_eq!(fetcher.calls.load(Ordering::SeqCst), 2);
+        // One timed-out attempt and 500ms of backoff
+        assert_eq!(start.elapsed(), FETCH_TIMEOUT + Duration::from_millis(500));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_first_failure_is_reported_before_retrying() {
+        let fetcher = FlakyFetcher::new(1);
+        let reported = std::sync::Mutex::new(Vec::new());
+
+        let data = fetch_with_timeout_notify(&fetcher, 3, FETCH_TIMEOUT, &|data| {
+            reported.lock().unwrap().push(data)
+        })
+        .await;
+
+        assert!(matches!(data, FeedData::Rss(_)));
+        let reported = reported.into_inner().unwrap();
+        assert!(matches!(
+            reported.as_slice(),
+            [FeedData::Error(e)] if e == "connection reset"
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_gives_up_after_attempts() {
+        let fetcher = FlakyFetcher::new(10);
+
+        let result = fetcher.fetch_with_retry(2, FETCH_TIMEOUT).await;
+
+        assert!(result.is_err());
+        assert_eq!(fetcher.calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_non_retryable_error_is_not_retried() {
+        let fetcher = FlakyFetcher {
+            permanent: true,
+            ..FlakyFetcher::new(0)
+        };
+
+        let result = fetcher.fetch_with_retry(3, FETCH_TIMEOUT).await;
+
+        assert_eq!(result.unwrap_err().to_string(), "401 Unauthorized");
+        assert_eq!(fetcher.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_client_errors_are_not_retryable() {
+        let not_found = http_status_error(reqwest::StatusCode::NOT_FOUND, "404".to_string());
+        let unavailable =
+            http_status_error(reqwest::StatusCode::SERVICE_UNAVAILABLE, "503".to_string());
+        assert!(!is_retryable(&not_found));
+        assert!(is_retryable(&unavailable));
+    }
 }
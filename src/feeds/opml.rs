@@ -0,0 +1,115 @@
+use anyhow::{Context, Result};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::path::Path;
+
+/// A feed subscription listed in an OPML file
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpmlFeed {
+    pub url: String,
+    /// The outline's title (or text), used as the items' source name
+    pub title: Option<String>,
+}
+
+/// Read the feed subscriptions from an OPML file
+pub fn load_opml(path: &Path) -> Result<Vec<OpmlFeed>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read OPML file {}", path.display()))?;
+    parse_opml(&content).with_context(|| format!("Failed to parse OPML file {}", path.display()))
+}
+
+/// Collect every `<outline>` with an `xmlUrl`, at any nesting depth. Folder
+/// outlines without one are skipped.
+pub fn parse_opml(xml: &str) -> Result<Vec<OpmlFeed>> {
+    let mut reader = Reader::from_str(xml);
+    let mut feeds = Vec::new();
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) | Event::Empty(e) if e.name().as_ref() == b"outline" => {
+                let mut url = None;
+                let mut title = None;
+                let mut text = None;
+
+                for attr in e.attributes() {
+                    let attr = attr?;
+                    let value = attr
+                        .decode_and_unescape_value(reader.decoder())?
+                        .trim()
+                        .to_string();
+                    if value.is_empty() {
+                        continue;
+                    }
+                    match attr.key.as_ref() {
+                        key if key.eq_ignore_ascii_case(b"xmlUrl") => url = Some(value),
+                        b"title" => title = Some(value),
+                        b"text" => text = Some(value),
+                        _ => {}
+                    }
+                }
+
+                if let Some(url) = url {
+                    feeds.push(OpmlFeed {
+                        url,
+                        title: title.or(text),
+                    });
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(feeds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<opml version="2.0">
+  <head><title>Subscriptions</title></head>
+  <body>
+    <outline text="Tech" title="Tech">
+      <outline type="rss" text="Ars Technica" title="Ars Technica"
+               xmlUrl="https://feeds.arstechnica.com/arstechnica/index" htmlUrl="https://arstechnica.com"/>
+      <outline type="rss" text="Lobsters &amp; friends" xmlUrl="https://lobste.rs/rss"/>
+    </outline>
+    <outline type="rss" xmlurl="https://example.com/feed.xml"/>
+    <outline text="Empty folder"></outline>
+  </body>
+</opml>"#;
+
+    #[test]
+    fn test_parse_opml() {
+        let feeds = parse_opml(SAMPLE).unwrap();
+        assert_eq!(
+            feeds,
+            vec![
+                OpmlFeed {
+                    url: "https://feeds.arstechnica.com/arstechnica/index".to_string(),
+                    title: Some("Ars Technica".to_string()),
+                },
+                OpmlFeed {
+                    url: "https://lobste.rs/rss".to_string(),
+                    title: Some("Lobsters & friends".to_string()),
+                },
+                OpmlFeed {
+                    url: "https://example.com/feed.xml".to_string(),
+                    title: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_opml_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("feeds.opml");
+        std::fs::write(&path, SAMPLE).unwrap();
+
+        assert_eq!(load_opml(&path).unwrap().len(), 3);
+        assert!(load_opml(&dir.path().join("missing.opml")).is_err());
+    }
+}
@@ -0,0 +1,281 @@
+use super::{FeedData, FeedFetcher};
+use anyhow::Result;
+use async_trait::async_trait;
+use image::codecs::gif::GifDecoder;
+use image::{AnimationDecoder, DynamicImage};
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Delay used for GIF frames that don't specify one, matching browsers
+const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone)]
+pub struct PixelData {
+    pub pixels: Vec<Vec<PixelColor>>,
+    pub width: u32,
+    pub height: u32,
+    pub original_width: u32,
+    pub original_height: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PixelColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl PixelColor {
+    pub fn grayscale(&self) -> u8 {
+        // Standard luminance calculation
+        ((0.299 * self.r as f64) + (0.587 * self.g as f64) + (0.114 * self.b as f64)) as u8
+    }
+}
+
+/// Decoded frames with how long each is shown. A static image is a single
+/// frame.
+pub type PixelFrames = Vec<(PixelData, Duration)>;
+
+/// An image decoded by `PixelArtFetcher`: its frames at the requested size,
+/// and the full-resolution source so it can be resized again without
+/// decoding it again
+#[derive(Debug, Clone)]
+pub struct PixelImage {
+    pub frames: PixelFrames,
+    pub source: Arc<SourceFrames>,
+}
+
+/// Decodes the configured image off the UI thread. The file doesn't change
+/// between refreshes, so it is only decoded until the first success.
+pub struct PixelArtFetcher {
+    path: Option<PathBuf>,
+    pixel_size: u32,
+    correct_aspect: bool,
+    loaded: AtomicBool,
+}
+
+impl PixelArtFetcher {
+    pub fn new(path: Option<PathBuf>, pixel_size: u32, correct_aspect: bool) -> Self {
+        Self {
+            path,
+            pixel_size,
+            correct_aspect,
+            loaded: AtomicBool::new(false),
+        }
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for PixelArtFetcher {
+    fn source_name(&self) -> &'static str {
+        "pixelart"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        let Some(path) = self.path.clone() else {
+            return Ok(FeedData::Loading);
+        };
+        if self.loaded.load(Ordering::Relaxed) {
+            return Ok(FeedData::Loading);
+        }
+
+        let (pixel_size, correct_aspect) = (self.pixel_size, self.correct_aspect);
+        let image = tokio::task::spawn_blocking(move || -> Result<PixelImage> {
+            let source = load_source(&path)?;
+            Ok(PixelImage {
+                frames: resize_frames(&source, pixel_size, correct_aspect),
+                source: Arc::new(source),
+            })
+        })
+        .await??;
+        self.loaded.store(true, Ordering::Relaxed);
+
+        Ok(FeedData::PixelArt(image))
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+}
+
+/// Size to resize an image to so its longer side is `target_size`
+/// pixels. With `correct_aspect` the height is halved, since each pixel
+/// is drawn into a terminal cell roughly twice as tall as it is wide.
+pub fn target_dimensions(
+    original_width: u32,
+    original_height: u32,
+    target_size: u32,
+    correct_aspect: bool,
+) -> (u32, u32) {
+    let (width, height) = if original_width > original_height {
+        let ratio = original_height as f64 / original_width as f64;
+        (target_size, (target_size as f64 * ratio) as u32)
+    } else {
+        let ratio = original_width as f64 / original_height as f64;
+        ((target_size as f64 * ratio) as u32, target_size)
+    };
+
+    let height = if correct_aspect { height / 2 } else { height };
+    (width.max(1), height.max(1))
+}
+
+/// Full-resolution frames of a decoded image with how long each is shown,
+/// kept so it can be downscaled again without decoding it again
+pub type SourceFrames = Vec<(DynamicImage, Duration)>;
+
+/// Read and decode an image without downscaling it, keeping every frame of
+/// an animated GIF. This blocks, so async callers should run it on a
+/// blocking task.
+pub fn load_source(path: &Path) -> Result<SourceFrames> {
+    decode_source(&std::fs::read(path)?)
+}
+
+/// Decode and downscale an image already in memory, e.g. one downloaded
+/// from the web. Like `load_source`, this blocks.
+pub fn decode_frames(
+    img_bytes: &[u8],
+    target_size: u32,
+    correct_aspect: bool,
+) -> Result<PixelFrames> {
+    Ok(resize_frames(
+        &decode_source(img_bytes)?,
+        target_size,
+        correct_aspect,
+    ))
+}
+
+fn decode_source(img_bytes: &[u8]) -> Result<SourceFrames> {
+    if image::guess_format(img_bytes)? == image::ImageFormat::Gif {
+        let decoder = GifDecoder::new(Cursor::new(img_bytes))?;
+        let frames = decoder.into_frames().collect_frames()?;
+        if !frames.is_empty() {
+            return Ok(frames
+                .into_iter()
+                .map(|frame| {
+                    let delay = match Duration::from(frame.delay()) {
+                        Duration::ZERO => DEFAULT_FRAME_DELAY,
+                        delay => delay,
+                    };
+                    (DynamicImage::ImageRgba8(frame.into_buffer()), delay)
+                })
+                .collect());
+        }
+    }
+
+    Ok(vec![(image::load_from_memory(img_bytes)?, Duration::ZERO)])
+}
+
+/// Downscale every frame of a decoded image
+pub fn resize_frames(source: &SourceFrames, target_size: u32, correct_aspect: bool) -> PixelFrames {
+    source
+        .iter()
+        .map(|(img, delay)| (to_pixel_data(img, target_size, correct_aspect), *delay))
+        .collect()
+}
+
+fn to_pixel_data(img: &DynamicImage, target_size: u32, correct_aspect: bool) -> PixelData {
+    let original_width = img.width();
+    let original_height = img.height();
+
+    // Calculate aspect-preserving dimensions
+    let (new_width, new_height) =
+        target_dimensions(original_width, original_height, target_size, correct_aspect);
+
+    // Resize using nearest neighbor for pixel art effect
+    let resized = img.resize_exact(new_width, new_height, image::imageops::FilterType::Nearest);
+
+    // Convert to RGB
+    let rgb_img = resized.to_rgb8();
+
+    // Extract pixel data
+    let mut pixels = Vec::new();
+    for y in 0..new_height {
+        let mut row = Vec::new();
+        for x in 0..new_width {
+            let pixel = rgb_img.get_pixel(x, y);
+            row.push(PixelColor {
+                r: pixel[0],
+                g: pixel[1],
+                b: pixel[2],
+            });
+        }
+        pixels.push(row);
+    }
+
+    PixelData {
+        pixels,
+        width: new_width,
+        height: new_height,
+        original_width,
+        original_height,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_dimensions_preserve_aspect() {
+        assert_eq!(target_dimensions(200, 100, 32, false), (32, 16));
+        assert_eq!(target_dimensions(100, 200, 32, false), (16, 32));
+    }
+
+    #[test]
+    fn test_target_dimensions_correct_for_tall_cells() {
+        assert_eq!(target_dimensions(100, 100, 32, true), (32, 16));
+        assert_eq!(target_dimensions(200, 100, 32, true), (32, 8));
+        // Never collapses to zero rows
+        assert_eq!(target_dimensions(1000, 10, 8, true), (8, 1));
+    }
+
+    #[tokio::test]
+    async fn test_fetcher_decodes_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("red.png");
+        image::RgbImage::from_pixel(4, 2, image::Rgb([255, 0, 0]))
+            .save(&path)
+            .unwrap();
+
+        let fetcher = PixelArtFetcher::new(Some(path), 4, false);
+        let FeedData::PixelArt(image) = fetcher.fetch().await.unwrap() else {
+            panic!("expected pixel data");
+        };
+        assert_eq!(image.frames.len(), 1);
+        assert_eq!(image.source.len(), 1);
+        let data = &image.frames[0].0;
+        assert_eq!((data.width, data.height), (4, 2));
+        assert_eq!(data.pixels[0][0].r, 255);
+
+        // Already loaded; later refreshes don't decode again
+        assert!(matches!(fetcher.fetch().await.unwrap(), FeedData::Loading));
+    }
+
+    #[test]
+    fn test_load_source_keeps_gif_animation() {
+        use image::codecs::gif::GifEncoder;
+        use image::{Delay, Frame, Rgba, RgbaImage};
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("blink.gif");
+        let mut encoder = GifEncoder::new(std::fs::File::create(&path).unwrap());
+        for (color, ms) in [([255, 0, 0, 255], 200), ([0, 0, 255, 255], 0)] {
+            let buffer = RgbaImage::from_pixel(2, 2, Rgba(color));
+            let delay = Delay::from_numer_denom_ms(ms, 1);
+            encoder
+                .encode_frame(Frame::from_parts(buffer, 0, 0, delay))
+                .unwrap();
+        }
+        drop(encoder);
+
+        let frames = resize_frames(&load_source(&path).unwrap(), 2, false);
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].1, Duration::from_millis(200));
+        assert_eq!(frames[1].1, DEFAULT_FRAME_DELAY);
+        assert_eq!(frames[0].0.pixels[0][0].r, 255);
+        assert_eq!(frames[1].0.pixels[0][0].b, 255);
+    }
+}
@@ -0,0 +1,149 @@
+use super::rss::fetch_feeds;
+use super::{FeedData, FeedFetcher, PodcastEpisode};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Episodes from podcast RSS feeds: the same feeds the RSS widget reads,
+/// keeping each item's `<enclosure>` audio and `<itunes:duration>`
+pub struct PodcastFetcher {
+    feeds: Vec<String>,
+    max_items: usize,
+    client: Arc<reqwest::Client>,
+}
+
+impl PodcastFetcher {
+    pub fn new(feeds: Vec<String>, max_items: usize, client: Arc<reqwest::Client>) -> Self {
+        Self {
+            feeds,
+            max_items,
+            client,
+        }
+    }
+}
+
+/// Convert a parsed feed into episodes tagged with the show's title, keeping
+/// at most `max_items`. Items without audio are still listed so show notes
+/// and announcements aren't lost.
+fn map_episodes(feed: feed_rs::model::Feed, max_items: usize) -> Vec<PodcastEpisode> {
+    let show = feed
+        .title
+        .map(|t| t.content)
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    feed.entries
+        .into_iter()
+        .take(max_items)
+        .map(|entry| {
+            let audio = entry.media.iter().find_map(|media| {
+                media
+                    .content
+                    .iter()
+                    .find_map(|content| content.url.as_ref())
+                    .map(|url| (url.to_string(), media.duration))
+            });
+
+            PodcastEpisode {
+                title: entry
+                    .title
+                    .map(|t| t.content)
+                    .unwrap_or_else(|| "No title".to_string()),
+                show: show.clone(),
+                audio_url: audio.as_ref().map(|(url, _)| url.clone()),
+                duration_secs: audio
+                    .and_then(|(_, duration)| duration)
+                    .map(|d| d.as_secs()),
+                link: entry.links.first().map(|l| l.href.clone()),
+                published: entry.published.map(|d| d.timestamp()),
+            }
+        })
+        .collect()
+}
+
+#[async_trait]
+impl FeedFetcher for PodcastFetcher {
+    fn source_name(&self) -> &'static str {
+        "podcast"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        // Fetched like the RSS widget's feeds: all at once, skipping ones
+        // that fail unless they all do
+        let urls: Vec<&str> = self.feeds.iter().map(String::as_str).collect();
+        let mut episodes: Vec<PodcastEpisode> = fetch_feeds(&self.client, &urls)
+            .await?
+            .into_iter()
+            .flatten()
+            .flat_map(|feed| map_episodes(feed, self.max_items))
+            .collect();
+
+        // Newest first across all shows
+        episodes.sort_by_key(|episode| std::cmp::Reverse(episode.published));
+        episodes.truncate(self.max_items);
+
+        Ok(FeedData::Podcast(episodes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED: &str = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+  <channel>
+    <title>Rust Talk</title>
+    <item>
+      <title>Episode 2: Async</title>
+      <link>https://example.com/2</link>
+      <pubDate>Wed, 01 May 2024 12:00:00 GMT</pubDate>
+      <enclosure url="https://cdn.example.com/ep2.mp3" length="1000" type="audio/mpeg"/>
+      <itunes:duration>1:02:03</itunes:duration>
+    </item>
+    <item>
+      <title>Show notes only</title>
+    </item>
+  </channel>
+</rss>"#;
+
+    #[test]
+    fn test_map_episodes() {
+        let feed = feed_rs::parser::parse(FEED.as_bytes()).unwrap();
+        let episodes = map_episodes(feed, 10);
+
+        assert_eq!(episodes.len(), 2);
+        let episode = &episodes[0];
+        assert_eq!(episode.title, "Episode 2: Async");
+        assert_eq!(episode.show, "Rust Talk");
+        assert_eq!(
+            episode.audio_url.as_deref(),
+            Some("https://cdn.example.com/ep2.mp3")
+        );
+        assert_eq!(episode.duration_secs, Some(3723));
+        assert_eq!(episode.published, Some(1714564800));
+
+        assert_eq!(episodes[1].audio_url, None);
+        assert_eq!(episodes[1].duration_secs, None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_reports_error_when_every_feed_fails() {
+        let base = crate::feeds::http::serve(&[("/show.xml", "200 OK", FEED)]).await;
+        let fetcher =
+            |feeds: Vec<String>| PodcastFetcher::new(feeds, 10, Arc::new(reqwest::Client::new()));
+
+        let missing = format!("{}/gone.xml", base);
+        let FeedData::Podcast(episodes) =
+            fetcher(vec![missing.clone(), format!("{}/show.xml", base)])
+                .fetch()
+                .await
+                .unwrap()
+        else {
+            panic!("expected episodes");
+        };
+        assert_eq!(episodes.len(), 2);
+
+        let err = fetcher(vec![missing]).fetch().await.unwrap_err();
+        assert!(err.to_string().ends_with("404 Not Found"), "{}", err);
+    }
+}
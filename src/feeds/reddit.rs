@@ -0,0 +1,152 @@
+use super::{http_status_error, FeedData, FeedFetcher, RedditPost};
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::sync::Arc;
+
+const REDDIT_BASE: &str = "https://www.reddit.com";
+
+pub struct RedditFetcher {
+    subreddit: String,
+    max_items: usize,
+    client: Arc<reqwest::Client>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Listing {
+    data: ListingData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListingData {
+    children: Vec<Child>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Child {
+    data: ApiPost,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiPost {
+    title: String,
+    permalink: String,
+    score: i64,
+    author: String,
+    num_comments: u32,
+    subreddit: String,
+    created_utc: Option<f64>,
+}
+
+/// Convert a listing into posts, keeping at most `max_items`
+fn map_listing(listing: Listing, max_items: usize) -> Vec<RedditPost> {
+    listing
+        .data
+        .children
+        .into_iter()
+        .take(max_items)
+        .map(|child| {
+            let post = child.data;
+            RedditPost {
+                title: post.title,
+                permalink: format!("{}{}", REDDIT_BASE, post.permalink),
+                score: post.score,
+                author: post.author,
+                num_comments: post.num_comments,
+                subreddit: post.subreddit,
+                timestamp: post.created_utc.map(|t| t as i64),
+            }
+        })
+        .collect()
+}
+
+impl RedditFetcher {
+    pub fn new(subreddit: String, max_items: usize, client: Arc<reqwest::Client>) -> Self {
+        Self {
+            subreddit,
+            max_items,
+            client,
+        }
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for RedditFetcher {
+    fn source_name(&self) -> &'static str {
+        "reddit"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        let subreddit = self.subreddit.trim_start_matches("r/");
+        let url = format!(
+            "{}/r/{}/hot.json?limit={}",
+            REDDIT_BASE, subreddit, self.max_items
+        );
+
+        let response = self.client.get(&url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(http_status_error(
+                response.status(),
+                format!("Reddit API error (r/{}): {}", subreddit, response.status()),
+            ));
+        }
+
+        let listing: Listing = response.json().await?;
+        Ok(FeedData::Reddit(map_listing(listing, self.max_items)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LISTING_FIXTURE: &str = r#"{
+        "kind": "Listing",
+        "data": {
+            "children": [
+                {"kind": "t3", "data": {
+                    "title": "Announcing Rust 1.80",
+                    "permalink": "/r/rust/comments/abc/announcing_rust_180/",
+                    "score": 1520,
+                    "author": "ferris",
+                    "num_comments": 210,
+                    "subreddit": "rust",
+                    "created_utc": 1721900000.0
+                }},
+                {"kind": "t3", "data": {
+                    "title": "Weekly questions thread",
+                    "permalink": "/r/rust/comments/def/weekly/",
+                    "score": -3,
+                    "author": "AutoModerator",
+                    "num_comments": 4,
+                    "subreddit": "rust"
+                }}
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn test_map_listing() {
+        let listing: Listing = serde_json::from_str(LISTING_FIXTURE).unwrap();
+        let posts = map_listing(listing, 10);
+
+        assert_eq!(posts.len(), 2);
+        assert_eq!(posts[0].title, "Announcing Rust 1.80");
+        assert_eq!(
+            posts[0].permalink,
+            "https://www.reddit.com/r/rust/comments/abc/announcing_rust_180/"
+        );
+        assert_eq!(posts[0].score, 1520);
+        assert_eq!(posts[0].num_comments, 210);
+        assert_eq!(posts[0].timestamp, Some(1721900000));
+        assert_eq!(posts[1].score, -3);
+        assert_eq!(posts[1].timestamp, None);
+    }
+
+    #[test]
+    fn test_map_listing_respects_max_items() {
+        let listing: Listing = serde_json::from_str(LISTING_FIXTURE).unwrap();
+        assert_eq!(map_listing(listing, 1).len(), 1);
+    }
+}
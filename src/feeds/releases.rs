@@ -0,0 +1,209 @@
+use super::{http_status_error, FeedData, FeedFetcher, ReleaseInfo};
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::future::join_all;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct ReleasesFetcher {
+    /// Package identifiers like `crate:serde` or `npm:react`
+    packages: Vec<String>,
+    client: Arc<reqwest::Client>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Registry {
+    Crates,
+    Npm,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrateResponse {
+    #[serde(rename = "crate")]
+    krate: CrateData,
+    versions: Vec<CrateVersion>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrateData {
+    max_stable_version: Option<String>,
+    max_version: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrateVersion {
+    num: String,
+    created_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmPackage {
+    #[serde(rename = "dist-tags")]
+    dist_tags: HashMap<String, String>,
+    /// Publish time of each version
+    #[serde(default)]
+    time: HashMap<String, String>,
+}
+
+/// Split `crate:<name>` / `npm:<name>` into its registry and package name
+fn parse_package(package: &str) -> Option<(Registry, &str)> {
+    let (prefix, name) = package.split_once(':')?;
+    let registry = match prefix {
+        "crate" => Registry::Crates,
+        "npm" => Registry::Npm,
+        _ => return None,
+    };
+    (!name.is_empty()).then_some((registry, name))
+}
+
+fn parse_time(time: &str) -> Option<i64> {
+    chrono::DateTime::parse_from_rfc3339(time)
+        .ok()
+        .map(|t| t.timestamp())
+}
+
+/// Latest stable version of a crate, or the latest of any kind if it has
+/// only pre-releases
+fn map_crate(package: &str, name: &str, response: CrateResponse) -> ReleaseInfo {
+    let version = response
+        .krate
+        .max_stable_version
+        .unwrap_or(response.krate.max_version);
+    let published = response
+        .versions
+        .iter()
+        .find(|v| v.num == version)
+        .and_then(|v| parse_time(&v.created_at));
+
+    ReleaseInfo {
+        package: package.to_string(),
+        url: format!("https://crates.io/crates/{}", name),
+        version,
+        published,
+    }
+}
+
+/// The version tagged `latest` on npm
+fn map_npm(package: &str, name: &str, response: NpmPackage) -> Option<ReleaseInfo> {
+    let version = response.dist_tags.get("latest")?.clone();
+    let published = response.time.get(&version).and_then(|t| parse_time(t));
+
+    Some(ReleaseInfo {
+        package: package.to_string(),
+        url: format!("https://www.npmjs.com/package/{}", name),
+        version,
+        published,
+    })
+}
+
+impl ReleasesFetcher {
+    pub fn new(packages: Vec<String>, client: Arc<reqwest::Client>) -> Self {
+        Self { packages, client }
+    }
+
+    async fn fetch_package(&self, package: &str) -> Result<ReleaseInfo> {
+        let Some((registry, name)) = parse_package(package) else {
+            anyhow::bail!(
+                "Unknown package '{}', expected crate:<name> or npm:<name>",
+                package
+            );
+        };
+        let url = match registry {
+            Registry::Crates => format!("https://crates.io/api/v1/crates/{}", name),
+            // Scoped packages keep their `@` but escape the slash
+            Registry::Npm => format!("https://registry.npmjs.org/{}", name.replace('/', "%2F")),
+        };
+
+        let response = self.client.get(&url).send().await?;
+        if !response.status().is_success() {
+            return Err(http_status_error(
+                response.status(),
+                format!("Registry error for {}: {}", package, response.status()),
+            ));
+        }
+
+        match registry {
+            Registry::Crates => Ok(map_crate(package, name, response.json().await?)),
+            Registry::Npm => map_npm(package, name, response.json().await?)
+                .ok_or_else(|| anyhow::anyhow!("No latest release for {}", package)),
+        }
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for ReleasesFetcher {
+    fn source_name(&self) -> &'static str {
+        "releases"
+    }
+
+    /// Packages that fail to load are left out, unless they all fail
+    async fn fetch(&self) -> Result<FeedData> {
+        let futures: Vec<_> = self
+            .packages
+            .iter()
+            .map(|p| self.fetch_package(p))
+            .collect();
+        let mut releases = Vec::new();
+        let mut first_error = None;
+        for result in join_all(futures).await {
+            match result {
+                Ok(release) => releases.push(release),
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) if releases.is_empty() => Err(e),
+            _ => Ok(FeedData::Releases(releases)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_package() {
+        assert_eq!(
+            parse_package("crate:serde"),
+            Some((Registry::Crates, "serde"))
+        );
+        assert_eq!(
+            parse_package("npm:@types/node"),
+            Some((Registry::Npm, "@types/node"))
+        );
+        assert_eq!(parse_package("pypi:requests"), None);
+        assert_eq!(parse_package("crate:"), None);
+        assert_eq!(parse_package("serde"), None);
+    }
+
+    #[test]
+    fn test_map_registry_responses() {
+        let response: CrateResponse = serde_json::from_str(
+            r#"{"crate": {"max_stable_version": "1.0.210", "max_version": "2.0.0-rc.1"},
+                "versions": [
+                    {"num": "2.0.0-rc.1", "created_at": "2024-09-01T00:00:00.000000+00:00"},
+                    {"num": "1.0.210", "created_at": "2024-08-15T12:00:00.000000+00:00"}
+                ]}"#,
+        )
+        .unwrap();
+        let release = map_crate("crate:serde", "serde", response);
+        assert_eq!(release.version, "1.0.210");
+        assert_eq!(release.published, Some(1723723200));
+        assert_eq!(release.url, "https://crates.io/crates/serde");
+
+        let response: NpmPackage = serde_json::from_str(
+            r#"{"dist-tags": {"latest": "18.3.1", "next": "19.0.0-rc"},
+                "time": {"18.3.1": "2024-04-26T16:42:00.000Z"}}"#,
+        )
+        .unwrap();
+        let release = map_npm("npm:react", "react", response).unwrap();
+        assert_eq!(release.version, "18.3.1");
+        assert_eq!(release.published, Some(1714149720));
+        assert_eq!(release.url, "https://www.npmjs.com/package/react");
+    }
+}
@@ -1,83 +1,431 @@
-use super::{FeedData, FeedFetcher, RssItem};
-use anyhow::Result;
+use super::opml::load_opml;
+use super::{http_status_error, FeedData, FeedFetcher, RssItem};
+use crate::logging;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
+use futures::future::join_all;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Upper bound for fetching one feed. Feeds are fetched at once, so this
+/// also bounds the whole fetch, and it is kept below `FETCH_TIMEOUT`.
+const FEED_TIMEOUT: Duration = Duration::from_secs(20);
 
 pub struct RssFetcher {
     feeds: Vec<String>,
+    /// Read on every fetch, so edits to the file apply without a restart
+    opml_path: Option<PathBuf>,
     max_items: usize,
-    client: reqwest::Client,
+    client: Arc<reqwest::Client>,
 }
 
 impl RssFetcher {
-    pub fn new(feeds: Vec<String>, max_items: usize) -> Self {
+    pub fn new(
+        feeds: Vec<String>,
+        opml_path: Option<PathBuf>,
+        max_items: usize,
+        client: Arc<reqwest::Client>,
+    ) -> Self {
         Self {
             feeds,
+            opml_path,
             max_items,
-            client: reqwest::Client::new(),
+            client,
         }
     }
+}
 
-    async fn fetch_feed(&self, url: &str) -> Result<Vec<RssItem>> {
-        let response = self
-            .client
-            .get(url)
-            .header("User-Agent", "feedtui/1.0")
-            .send()
-            .await?;
-
+/// Fetch and parse one feed. An unsuccessful response is reported by its
+/// status rather than as a parse error on the error page.
+async fn fetch_feed(client: &reqwest::Client, url: &str) -> Result<feed_rs::model::Feed> {
+    let request = async {
+        let response = client.get(url).send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(http_status_error(status, format!("{}: {}", url, status)));
+        }
         let body = response.bytes().await?;
-        let feed = feed_rs::parser::parse(&body[..])?;
-
-        let source_name = feed
-            .title
-            .map(|t| t.content)
-            .unwrap_or_else(|| "Unknown".to_string());
-
-        let items: Vec<RssItem> = feed
-            .entries
-            .into_iter()
-            .take(self.max_items)
-            .map(|entry| {
-                // Get description from summary or content
-                let description = entry
-                    .summary
-                    .map(|s| s.content)
-                    .or_else(|| entry.content.and_then(|c| c.body));
-
-                RssItem {
-                    title: entry
-                        .title
-                        .map(|t| t.content)
-                        .unwrap_or_else(|| "No title".to_string()),
-                    link: entry.links.first().map(|l| l.href.clone()),
-                    published: entry
-                        .published
-                        .map(|d| d.format("%Y-%m-%d %H:%M").to_string()),
-                    source: source_name.clone(),
-                    description,
-                }
-            })
-            .collect();
+        feed_rs::parser::parse(&body[..]).with_context(|| format!("Invalid feed at {}", url))
+    };
+    tokio::time::timeout(FEED_TIMEOUT, request)
+        .await
+        .unwrap_or_else(|_| {
+            Err(anyhow::anyhow!(
+                "{}: timed out after {}s",
+                url,
+                FEED_TIMEOUT.as_secs()
+            ))
+        })
+}
+
+/// Fetch all `urls` at once, giving each parsed feed in the same order.
+/// A feed that fails is skipped (`None`) so the others still show; the
+/// first error is returned only when every feed failed.
+pub async fn fetch_feeds(
+    client: &reqwest::Client,
+    urls: &[&str],
+) -> Result<Vec<Option<feed_rs::model::Feed>>> {
+    let results = join_all(urls.iter().map(|url| fetch_feed(client, url))).await;
+
+    let mut feeds = Vec::with_capacity(results.len());
+    let mut first_error = None;
+    for result in results {
+        match result {
+            Ok(feed) => feeds.push(Some(feed)),
+            Err(e) => {
+                logging::warn!("{:#}", e);
+                first_error.get_or_insert(e);
+                feeds.push(None);
+            }
+        }
+    }
+    match first_error {
+        Some(e) if feeds.iter().all(Option::is_none) => Err(e),
+        _ => Ok(feeds),
+    }
+}
+
+/// Convert a parsed feed into items tagged with the feed's title, keeping at
+/// most `max_items`
+fn map_feed(feed: feed_rs::model::Feed, max_items: usize) -> Vec<RssItem> {
+    let source_name = feed
+        .title
+        .map(|t| t.content)
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    feed.entries
+        .into_iter()
+        .take(max_items)
+        .map(|entry| {
+            // Get description from summary or content
+            let description = entry
+                .summary
+                .map(|s| s.content)
+                .or_else(|| entry.content.and_then(|c| c.body));
+
+            RssItem {
+                title: entry
+                    .title
+                    .map(|t| t.content)
+                    .unwrap_or_else(|| "No title".to_string()),
+                link: entry.links.first().map(|l| l.href.clone()),
+                published: entry
+                    .published
+                    .map(|d| d.format("%Y-%m-%d %H:%M").to_string()),
+                timestamp: entry.published.map(|d| d.timestamp()),
+                source: source_name.clone(),
+                description,
+            }
+        })
+        .collect()
+}
 
-        Ok(items)
+/// Parse a feed date into a Unix timestamp. Accepts RFC 2822 (RSS),
+/// RFC 3339 / ISO 8601 (Atom) and the `%Y-%m-%d %H:%M` display form, which
+/// is taken as UTC.
+pub fn parse_pub_date(date: &str) -> Option<i64> {
+    let date = date.trim();
+    chrono::DateTime::parse_from_rfc2822(date)
+        .or_else(|_| chrono::DateTime::parse_from_rfc3339(date))
+        .map(|d| d.timestamp())
+        .ok()
+        .or_else(|| {
+            ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"]
+                .iter()
+                .find_map(|fmt| chrono::NaiveDateTime::parse_from_str(date, fmt).ok())
+                .map(|d| d.and_utc().timestamp())
+        })
+        .or_else(|| {
+            chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .map(|d| d.and_utc().timestamp())
+        })
+}
+
+/// Order items newest first; items without a usable date go last, keeping
+/// their feed order
+fn sort_by_date(items: &mut [RssItem]) {
+    items.sort_by_cached_key(|item| {
+        let timestamp = item
+            .timestamp
+            .or_else(|| item.published.as_deref().and_then(parse_pub_date));
+        std::cmp::Reverse(timestamp)
+    });
+}
+
+/// Query parameters that only track where a click came from
+fn is_tracking_param(name: &str) -> bool {
+    name.starts_with("utm_") || matches!(name, "fbclid" | "gclid" | "mc_cid" | "mc_eid" | "ref_src")
+}
+
+/// Reduce a link to the form used to spot duplicates: no tracking params,
+/// fragment or trailing slash, and http treated the same as https
+fn normalize_link(link: &str) -> String {
+    let Ok(mut url) = reqwest::Url::parse(link.trim()) else {
+        return link.trim().trim_end_matches('/').to_string();
+    };
+
+    let query: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(name, _)| !is_tracking_param(name))
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    if query.is_empty() {
+        url.set_query(None);
+    } else {
+        url.query_pairs_mut().clear().extend_pairs(query);
+    }
+    url.set_fragment(None);
+
+    let mut normalized = url.host_str().unwrap_or_default().to_string();
+    if let Some(port) = url.port() {
+        normalized.push_str(&format!(":{}", port));
     }
+    normalized.push_str(url.path().trim_end_matches('/'));
+    if let Some(query) = url.query() {
+        normalized.push('?');
+        normalized.push_str(query);
+    }
+    normalized
+}
+
+/// Drop items whose link was already seen, keeping the first occurrence.
+/// Items without a link are always kept.
+pub fn dedupe_items(items: Vec<RssItem>) -> Vec<RssItem> {
+    let mut seen = HashSet::new();
+    items
+        .into_iter()
+        .filter(|item| match &item.link {
+            Some(link) => seen.insert(normalize_link(link)),
+            None => true,
+        })
+        .collect()
 }
 
 #[async_trait]
 impl FeedFetcher for RssFetcher {
+    fn source_name(&self) -> &'static str {
+        "rss"
+    }
+
     async fn fetch(&self) -> Result<FeedData> {
-        let mut all_items = Vec::new();
+        // Each feed paired with the source name to show instead of the
+        // feed's own title
+        let mut feeds: Vec<(String, Option<String>)> =
+            self.feeds.iter().map(|url| (url.clone(), None)).collect();
+        // An unreadable OPML file still leaves the configured feeds; its
+        // error is only reported if nothing else could be fetched
+        let mut opml_error = None;
+        if let Some(path) = &self.opml_path {
+            match load_opml(path) {
+                Ok(opml) => feeds.extend(opml.into_iter().map(|f| (f.url, f.title))),
+                Err(e) => {
+                    logging::warn!("{:#}", e);
+                    opml_error = Some(e);
+                }
+            }
+        }
 
-        for feed_url in &self.feeds {
-            match self.fetch_feed(feed_url).await {
-                Ok(items) => all_items.extend(items),
-                Err(_) => continue,
+        let urls: Vec<&str> = feeds.iter().map(|(url, _)| url.as_str()).collect();
+        let parsed = match fetch_feeds(&self.client, &urls).await {
+            Ok(parsed) => parsed,
+            Err(e) => return Err(opml_error.unwrap_or(e)),
+        };
+        if let Some(e) = opml_error.filter(|_| parsed.iter().all(Option::is_none)) {
+            return Err(e);
+        }
+
+        let mut all_items = Vec::new();
+        for ((_, source), feed) in feeds.iter().zip(parsed) {
+            let Some(feed) = feed else {
+                continue;
+            };
+            let mut items = map_feed(feed, self.max_items);
+            if let Some(source) = source {
+                for item in &mut items {
+                    item.source = source.clone();
+                }
             }
+            all_items.extend(items);
         }
 
-        // Sort by date if available, limit to max_items
+        let mut all_items = dedupe_items(all_items);
+
+        sort_by_date(&mut all_items);
         all_items.truncate(self.max_items);
 
         Ok(FeedData::Rss(all_items))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = r#"<?xml version="1.0"?>
+<rss version="2.0">
+  <channel>
+    <title>Example Feed</title>
+    <item>
+      <title>First post</title>
+      <link>https://example.com/1</link>
+      <description>Hello</description>
+      <pubDate>Mon, 01 Jan 2024 12:00:00 GMT</pubDate>
+    </item>
+    <item>
+      <link>https://example.com/2</link>
+    </item>
+  </channel>
+</rss>"#;
+
+    fn parse() -> feed_rs::model::Feed {
+        feed_rs::parser::parse(FIXTURE.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_map_feed() {
+        let items = map_feed(parse(), 10);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].title, "First post");
+        assert_eq!(items[0].source, "Example Feed");
+        assert_eq!(items[0].link.as_deref(), Some("https://example.com/1"));
+        assert_eq!(items[0].description.as_deref(), Some("Hello"));
+        assert_eq!(items[0].published.as_deref(), Some("2024-01-01 12:00"));
+        assert_eq!(items[0].timestamp, Some(1704110400));
+        assert_eq!(items[1].title, "No title");
+        assert_eq!(items[1].timestamp, None);
+    }
+
+    #[tokio::test]
+    async fn test_failed_feeds_are_skipped_unless_all_fail() {
+        let base = crate::feeds::http::serve(&[("/feed.xml", "200 OK", FIXTURE)]).await;
+        let client = reqwest::Client::new();
+        let good = format!("{}/feed.xml", base);
+        let missing = format!("{}/missing.xml", base);
+
+        let feeds = fetch_feeds(&client, &[&missing, &good]).await.unwrap();
+        assert!(feeds[0].is_none());
+        assert!(feeds[1].is_some());
+
+        // A 404 page is reported by status, not as an unparseable feed
+        let err = fetch_feeds(&client, &[&missing]).await.unwrap_err();
+        assert!(err.to_string().ends_with("404 Not Found"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_unreadable_opml_keeps_configured_feeds() {
+        let base = crate::feeds::http::serve(&[("/feed.xml", "200 OK", FIXTURE)]).await;
+        let dir = tempfile::tempdir().unwrap();
+        let fetcher = RssFetcher::new(
+            vec![format!("{}/feed.xml", base)],
+            Some(dir.path().join("missing.opml")),
+            10,
+            Arc::new(reqwest::Client::new()),
+        );
+        let FeedData::Rss(items) = fetcher.fetch().await.unwrap() else {
+            panic!("expected rss items");
+        };
+        assert_eq!(items.len(), 2);
+
+        let opml_only = RssFetcher::new(
+            Vec::new(),
+            Some(dir.path().join("missing.opml")),
+            10,
+            Arc::new(reqwest::Client::new()),
+        );
+        let err = opml_only.fetch().await.unwrap_err();
+        assert!(err.to_string().contains("missing.opml"));
+    }
+
+    #[test]
+    fn test_map_feed_respects_max_items() {
+        assert_eq!(map_feed(parse(), 1).len(), 1);
+    }
+
+    #[test]
+    fn test_normalize_link() {
+        let canonical = "example.com/post";
+        for link in [
+            "https://example.com/post",
+            "https://example.com/post/",
+            "http://Example.com/post",
+            "https://example.com/post?utm_source=rss&utm_medium=feed",
+            "https://example.com/post/?utm_campaign=x#comments",
+        ] {
+            assert_eq!(normalize_link(link), canonical, "{}", link);
+        }
+
+        // Meaningful params are kept, tracking ones dropped around them
+        assert_eq!(
+            normalize_link("https://example.com/item?id=42&utm_source=hn"),
+            "example.com/item?id=42"
+        );
+        assert_ne!(
+            normalize_link("https://example.com/item?id=42"),
+            normalize_link("https://example.com/item?id=43")
+        );
+        // Unparseable links are compared as written, minus a trailing slash
+        assert_eq!(normalize_link("/relative/path/"), "/relative/path");
+    }
+
+    fn item(title: &str, link: Option<&str>) -> RssItem {
+        RssItem {
+            title: title.to_string(),
+            link: link.map(str::to_string),
+            published: None,
+            timestamp: None,
+            source: "Test".to_string(),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_dedupe_items_keeps_first() {
+        let items = dedupe_items(vec![
+            item("site", Some("https://example.com/a")),
+            item(
+                "comments",
+                Some("https://example.com/a/?utm_source=comments"),
+            ),
+            item("other", Some("https://example.com/b")),
+            item("no link", None),
+            item("no link either", None),
+        ]);
+
+        let titles: Vec<&str> = items.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(titles, vec!["site", "other", "no link", "no link either"]);
+    }
+
+    #[test]
+    fn test_parse_pub_date() {
+        let expected = Some(1704110400);
+        assert_eq!(parse_pub_date("Mon, 01 Jan 2024 12:00:00 GMT"), expected);
+        assert_eq!(parse_pub_date("Mon, 01 Jan 2024 13:00:00 +0100"), expected);
+        assert_eq!(parse_pub_date("2024-01-01T12:00:00Z"), expected);
+        assert_eq!(parse_pub_date("2024-01-01T07:00:00-05:00"), expected);
+        assert_eq!(parse_pub_date("2024-01-01 12:00"), expected);
+        assert_eq!(parse_pub_date("2024-01-01"), Some(1704067200));
+        assert_eq!(parse_pub_date("yesterday"), None);
+    }
+
+    #[test]
+    fn test_sort_by_date_newest_first() {
+        let dated = |title: &str, published: Option<&str>| RssItem {
+            published: published.map(str::to_string),
+            ..item(title, None)
+        };
+        let mut items = vec![
+            dated("undated", None),
+            dated("old", Some("Mon, 01 Jan 2024 12:00:00 GMT")),
+            dated("bad date", Some("soon")),
+            dated("new", Some("2024-02-01T00:00:00Z")),
+        ];
+        sort_by_date(&mut items);
+
+        let titles: Vec<&str> = items.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(titles, vec!["new", "old", "undated", "bad date"]);
+    }
+}
@@ -1,11 +1,40 @@
-use super::{FeedData, FeedFetcher, SportsEvent};
+use super::{FeedData, FeedFetcher, NonRetryable, SportsEvent};
+use crate::config::SportsProvider;
 use anyhow::Result;
 use async_trait::async_trait;
 use serde::Deserialize;
+use std::sync::Arc;
+
+/// League names accepted for ESPN, mapped to their scoreboard path
+const ESPN_LEAGUES: &[(&str, &str)] = &[
+    ("nba", "basketball/nba"),
+    ("nfl", "football/nfl"),
+    ("mlb", "baseball/mlb"),
+    ("nhl", "hockey/nhl"),
+    ("mls", "soccer/usa.1"),
+    ("epl", "soccer/eng.1"),
+    ("premier-league", "soccer/eng.1"),
+    ("ncaaf", "football/college-football"),
+    ("college-football", "football/college-football"),
+    ("ncaab", "basketball/mens-college-basketball"),
+    ("college-basketball", "basketball/mens-college-basketball"),
+];
+
+/// League names accepted for TheSportsDB, mapped to their league id
+const THESPORTSDB_LEAGUES: &[(&str, &str)] = &[
+    ("nba", "4387"),
+    ("nfl", "4391"),
+    ("mlb", "4424"),
+    ("nhl", "4380"),
+    ("mls", "4346"),
+    ("epl", "4328"),
+    ("premier-league", "4328"),
+];
 
 pub struct SportsFetcher {
+    provider: SportsProvider,
     leagues: Vec<String>,
-    client: reqwest::Client,
+    client: Arc<reqwest::Client>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -53,32 +82,85 @@ struct EspnTeam {
     display_name: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct SportsDbResponse {
+    events: Option<Vec<SportsDbEvent>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SportsDbEvent {
+    #[serde(rename = "strHomeTeam")]
+    home_team: String,
+    #[serde(rename = "strAwayTeam")]
+    away_team: String,
+    #[serde(rename = "intHomeScore")]
+    home_score: Option<String>,
+    #[serde(rename = "intAwayScore")]
+    away_score: Option<String>,
+    #[serde(rename = "strStatus")]
+    status: Option<String>,
+    #[serde(rename = "strTimestamp")]
+    timestamp: Option<String>,
+}
+
 impl SportsFetcher {
-    pub fn new(leagues: Vec<String>) -> Self {
+    pub fn new(
+        provider: SportsProvider,
+        leagues: Vec<String>,
+        client: Arc<reqwest::Client>,
+    ) -> Self {
         Self {
+            provider,
             leagues,
-            client: reqwest::Client::new(),
+            client,
+        }
+    }
+
+    fn provider_name(provider: SportsProvider) -> &'static str {
+        match provider {
+            SportsProvider::Espn => "espn",
+            SportsProvider::Thesportsdb => "thesportsdb",
         }
     }
 
-    fn league_to_espn_endpoint(league: &str) -> Option<&'static str> {
-        match league.to_lowercase().as_str() {
-            "nba" => Some("basketball/nba"),
-            "nfl" => Some("football/nfl"),
-            "mlb" => Some("baseball/mlb"),
-            "nhl" => Some("hockey/nhl"),
-            "mls" => Some("soccer/usa.1"),
-            "epl" | "premier-league" => Some("soccer/eng.1"),
-            "ncaaf" | "college-football" => Some("football/college-football"),
-            "ncaab" | "college-basketball" => Some("basketball/mens-college-basketball"),
-            _ => None,
+    fn supported_leagues(provider: SportsProvider) -> &'static [(&'static str, &'static str)] {
+        match provider {
+            SportsProvider::Espn => ESPN_LEAGUES,
+            SportsProvider::Thesportsdb => THESPORTSDB_LEAGUES,
         }
     }
 
+    /// Look up the provider-specific endpoint for a league, failing with a
+    /// message that lists what the provider does support. That is a config
+    /// mistake, so the error is `NonRetryable`.
+    fn league_endpoint(provider: SportsProvider, league: &str) -> Result<&'static str> {
+        let leagues = Self::supported_leagues(provider);
+        let league = league.to_lowercase();
+        leagues
+            .iter()
+            .find(|(name, _)| *name == league)
+            .map(|(_, endpoint)| *endpoint)
+            .ok_or_else(|| {
+                let supported: Vec<&str> = leagues.iter().map(|(name, _)| *name).collect();
+                NonRetryable(format!(
+                    "League '{}' is not supported by provider '{}' (supported: {})",
+                    league,
+                    Self::provider_name(provider),
+                    supported.join(", ")
+                ))
+                .into()
+            })
+    }
+
     async fn fetch_league(&self, league: &str) -> Result<Vec<SportsEvent>> {
-        let endpoint = Self::league_to_espn_endpoint(league)
-            .ok_or_else(|| anyhow::anyhow!("Unknown league: {}", league))?;
+        let endpoint = Self::league_endpoint(self.provider, league)?;
+        match self.provider {
+            SportsProvider::Espn => self.fetch_espn(league, endpoint).await,
+            SportsProvider::Thesportsdb => self.fetch_thesportsdb(league, endpoint).await,
+        }
+    }
 
+    async fn fetch_espn(&self, league: &str, endpoint: &str) -> Result<Vec<SportsEvent>> {
         let url = format!(
             "https://site.api.espn.com/apis/site/v2/sports/{}/scoreboard",
             endpoint
@@ -107,8 +189,8 @@ impl SportsFetcher {
                     league: league.to_uppercase(),
                     home_team: home.team.display_name.clone(),
                     away_team: away.team.display_name.clone(),
-                    home_score: away.score.as_ref().and_then(|s| s.parse().ok()),
-                    away_score: home.score.as_ref().and_then(|s| s.parse().ok()),
+                    home_score: home.score.as_ref().and_then(|s| s.parse().ok()),
+                    away_score: away.score.as_ref().and_then(|s| s.parse().ok()),
                     status: event.status.status_type.description.clone(),
                     start_time: competition.start_date.clone(),
                 })
@@ -117,11 +199,48 @@ impl SportsFetcher {
 
         Ok(sports_events)
     }
+
+    async fn fetch_thesportsdb(&self, league: &str, league_id: &str) -> Result<Vec<SportsEvent>> {
+        let url = format!(
+            "https://www.thesportsdb.com/api/v1/json/3/eventspastleague.php?id={}",
+            league_id
+        );
+
+        let response = self.client.get(&url).send().await?;
+        let data: SportsDbResponse = response.json().await?;
+
+        Ok(Self::normalize_thesportsdb(league, data))
+    }
+
+    fn normalize_thesportsdb(league: &str, data: SportsDbResponse) -> Vec<SportsEvent> {
+        data.events
+            .unwrap_or_default()
+            .into_iter()
+            .map(|event| SportsEvent {
+                league: league.to_uppercase(),
+                home_team: event.home_team,
+                away_team: event.away_team,
+                home_score: event.home_score.and_then(|s| s.parse().ok()),
+                away_score: event.away_score.and_then(|s| s.parse().ok()),
+                status: event.status.unwrap_or_else(|| "Final".to_string()),
+                start_time: event.timestamp,
+            })
+            .collect()
+    }
 }
 
 #[async_trait]
 impl FeedFetcher for SportsFetcher {
+    fn source_name(&self) -> &'static str {
+        "sports"
+    }
+
     async fn fetch(&self) -> Result<FeedData> {
+        // Catch config mistakes up front instead of silently showing nothing
+        for league in &self.leagues {
+            Self::league_endpoint(self.provider, league)?;
+        }
+
         let mut all_events = Vec::new();
 
         for league in &self.leagues {
@@ -134,3 +253,52 @@ impl FeedFetcher for SportsFetcher {
         Ok(FeedData::Sports(all_events))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_league_endpoint_per_provider() {
+        assert_eq!(
+            SportsFetcher::league_endpoint(SportsProvider::Espn, "EPL").unwrap(),
+            "soccer/eng.1"
+        );
+        assert_eq!(
+            SportsFetcher::league_endpoint(SportsProvider::Thesportsdb, "nba").unwrap(),
+            "4387"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_league_is_an_error() {
+        let fetcher = SportsFetcher::new(
+            SportsProvider::Thesportsdb,
+            vec!["ncaaf".to_string()],
+            Arc::new(reqwest::Client::new()),
+        );
+        let err = fetcher.fetch().await.unwrap_err();
+        assert!(err.is::<NonRetryable>());
+        let err = err.to_string();
+        assert!(err.contains("'ncaaf'"));
+        assert!(err.contains("thesportsdb"));
+    }
+
+    #[test]
+    fn test_normalize_thesportsdb_event() {
+        let data: SportsDbResponse = serde_json::from_str(
+            r#"{"events":[{"strHomeTeam":"Arsenal","strAwayTeam":"Chelsea",
+                "intHomeScore":"2","intAwayScore":null,"strStatus":"Match Finished",
+                "strTimestamp":"2024-05-01T19:00:00"}]}"#,
+        )
+        .unwrap();
+
+        let events = SportsFetcher::normalize_thesportsdb("epl", data);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].league, "EPL");
+        assert_eq!(events[0].home_team, "Arsenal");
+        assert_eq!(events[0].home_score, Some(2));
+        assert_eq!(events[0].away_score, None);
+        assert_eq!(events[0].status, "Match Finished");
+    }
+}
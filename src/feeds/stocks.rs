@@ -3,10 +3,11 @@ use anyhow::Result;
 use async_trait::async_trait;
 use futures::future::join_all;
 use serde::Deserialize;
+use std::sync::Arc;
 
 pub struct StocksFetcher {
     symbols: Vec<String>,
-    client: reqwest::Client,
+    client: Arc<reqwest::Client>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -22,6 +23,20 @@ struct ChartBody {
 #[derive(Debug, Deserialize)]
 struct ChartResult {
     meta: ChartMeta,
+    #[serde(default)]
+    indicators: Option<ChartIndicators>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChartIndicators {
+    quote: Vec<ChartQuote>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChartQuote {
+    /// Close of each interval; null where there was no trading
+    #[serde(default)]
+    close: Vec<Option<f64>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -36,16 +51,13 @@ struct ChartMeta {
 }
 
 impl StocksFetcher {
-    pub fn new(symbols: Vec<String>) -> Self {
-        Self {
-            symbols,
-            client: reqwest::Client::new(),
-        }
+    pub fn new(symbols: Vec<String>, client: Arc<reqwest::Client>) -> Self {
+        Self { symbols, client }
     }
 
     async fn fetch_symbol(&self, symbol: &str) -> Option<StockQuote> {
         let url = format!(
-            "https://query1.finance.yahoo.com/v8/finance/chart/{}?interval=1d&range=1d",
+            "https://query1.finance.yahoo.com/v8/finance/chart/{}?interval=5m&range=1d",
             symbol
         );
 
@@ -58,30 +70,46 @@ impl StocksFetcher {
             .ok()?;
 
         let data: YahooChartResponse = response.json().await.ok()?;
-        let result = data.chart.result?.into_iter().next()?;
-        let meta = result.meta;
-
-        let price = meta.regular_market_price.unwrap_or(0.0);
-        let prev_close = meta.chart_previous_close.unwrap_or(price);
-        let change = price - prev_close;
-        let change_percent = if prev_close != 0.0 {
-            (change / prev_close) * 100.0
-        } else {
-            0.0
-        };
-
-        Some(StockQuote {
-            symbol: meta.symbol,
-            name: meta.short_name.unwrap_or_else(|| "Unknown".to_string()),
-            price,
-            change,
-            change_percent,
-        })
+        map_quote(data)
     }
 }
 
+/// Build a quote from a chart response, computing the change against the
+/// previous close
+fn map_quote(data: YahooChartResponse) -> Option<StockQuote> {
+    let result = data.chart.result?.into_iter().next()?;
+    let meta = result.meta;
+    let history: Vec<f64> = result
+        .indicators
+        .and_then(|i| i.quote.into_iter().next())
+        .map(|q| q.close.into_iter().flatten().collect())
+        .unwrap_or_default();
+
+    let price = meta.regular_market_price.unwrap_or(0.0);
+    let prev_close = meta.chart_previous_close.unwrap_or(price);
+    let change = price - prev_close;
+    let change_percent = if prev_close != 0.0 {
+        (change / prev_close) * 100.0
+    } else {
+        0.0
+    };
+
+    Some(StockQuote {
+        symbol: meta.symbol,
+        name: meta.short_name.unwrap_or_else(|| "Unknown".to_string()),
+        price,
+        change,
+        change_percent,
+        history,
+    })
+}
+
 #[async_trait]
 impl FeedFetcher for StocksFetcher {
+    fn source_name(&self) -> &'static str {
+        "stocks"
+    }
+
     async fn fetch(&self) -> Result<FeedData> {
         let futures: Vec<_> = self.symbols.iter().map(|s| self.fetch_symbol(s)).collect();
         let results = join_all(futures).await;
@@ -90,3 +118,54 @@ impl FeedFetcher for StocksFetcher {
         Ok(FeedData::Stocks(quotes))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> YahooChartResponse {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_map_quote_computes_change() {
+        let quote = map_quote(parse(
+            r#"{"chart":{"result":[{"meta":{"symbol":"AAPL","shortName":"Apple Inc.",
+                "regularMarketPrice":110.0,"chartPreviousClose":100.0}}]}}"#,
+        ))
+        .unwrap();
+
+        assert_eq!(quote.symbol, "AAPL");
+        assert_eq!(quote.name, "Apple Inc.");
+        assert_eq!(quote.change, 10.0);
+        assert_eq!(quote.change_percent, 10.0);
+    }
+
+    #[test]
+    fn test_map_quote_without_previous_close() {
+        let quote = map_quote(parse(
+            r#"{"chart":{"result":[{"meta":{"symbol":"X","regularMarketPrice":5.0}}]}}"#,
+        ))
+        .unwrap();
+
+        assert_eq!(quote.name, "Unknown");
+        assert_eq!(quote.change, 0.0);
+        assert_eq!(quote.change_percent, 0.0);
+    }
+
+    #[test]
+    fn test_map_quote_collects_history() {
+        let quote = map_quote(parse(
+            r#"{"chart":{"result":[{"meta":{"symbol":"X","regularMarketPrice":5.0},
+                "indicators":{"quote":[{"close":[4.5,null,4.8,5.0]}]}}]}}"#,
+        ))
+        .unwrap();
+
+        assert_eq!(quote.history, vec![4.5, 4.8, 5.0]);
+    }
+
+    #[test]
+    fn test_map_quote_empty_result() {
+        assert!(map_quote(parse(r#"{"chart":{"result":null}}"#)).is_none());
+    }
+}
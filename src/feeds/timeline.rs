@@ -0,0 +1,267 @@
+use super::{FeedData, FeedFetcher, NonRetryable, TimelineItem};
+use anyhow::Result;
+use async_trait::async_trait;
+
+/// A widget whose items are merged into a timeline, with the latest data
+/// the app routed to it
+#[derive(Clone)]
+pub struct TimelineSource {
+    pub id: String,
+    /// Shown as the source tag on each item
+    pub name: String,
+    pub data: Option<FeedData>,
+}
+
+impl TimelineSource {
+    pub fn new(id: String, name: String) -> Self {
+        Self {
+            id,
+            name,
+            data: None,
+        }
+    }
+}
+
+/// A timeline's items come from the data its source widgets already
+/// fetched, so its own fetcher only reports configuration problems
+pub struct TimelineFetcher {
+    has_sources: bool,
+    unknown_sources: Vec<String>,
+}
+
+impl TimelineFetcher {
+    pub fn new(has_sources: bool, unknown_sources: Vec<String>) -> Self {
+        Self {
+            has_sources,
+            unknown_sources,
+        }
+    }
+}
+
+/// Normalize one source's feed data into timeline items. Feeds that have no
+/// notion of individual items (stocks, sports, ...) contribute nothing.
+fn timeline_items(source: &str, data: FeedData) -> Vec<TimelineItem> {
+    match data {
+        FeedData::HackerNews(stories) => stories
+            .into_iter()
+            .map(|story| TimelineItem {
+                url: Some(story.url.unwrap_or_else(|| {
+                    format!("https://news.ycombinator.com/item?id={}", story.id)
+                })),
+                title: story.title,
+                description: None,
+                source: source.to_string(),
+                timestamp: story.time,
+            })
+            .collect(),
+        FeedData::Rss(items) => items
+            .into_iter()
+            .map(|item| TimelineItem {
+                title: item.title,
+                url: item.link,
+                description: item.description,
+                source: source.to_string(),
+                timestamp: item.timestamp,
+            })
+            .collect(),
+        FeedData::Youtube(videos) => videos
+            .into_iter()
+            .map(|video| TimelineItem {
+                url: Some(format!("https://www.youtube.com/watch?v={}", video.id)),
+                title: video.title,
+                description: Some(video.description),
+                source: source.to_string(),
+                timestamp: video.timestamp,
+            })
+            .collect(),
+        FeedData::Reddit(posts) => posts
+            .into_iter()
+            .map(|post| TimelineItem {
+                title: post.title,
+                url: Some(post.permalink),
+                description: None,
+                source: source.to_string(),
+                timestamp: post.timestamp,
+            })
+            .collect(),
+        FeedData::Mastodon(toots) => toots
+            .into_iter()
+            .map(|toot| TimelineItem {
+                title: format!("{}: {}", toot.author, toot.content),
+                url: Some(toot.url),
+                description: Some(toot.content),
+                source: source.to_string(),
+                timestamp: toot.timestamp,
+            })
+            .collect(),
+        FeedData::Bluesky(posts) => posts
+            .into_iter()
+            .map(|post| TimelineItem {
+                title: format!("{}: {}", post.author, post.text),
+                url: Some(post.url),
+                description: Some(post.text),
+                source: source.to_string(),
+                timestamp: post.timestamp,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Sort newest first, keeping items without a timestamp at the end
+fn merge(mut items: Vec<TimelineItem>, max_items: usize) -> Vec<TimelineItem> {
+    items.sort_by_key(|item| std::cmp::Reverse(item.timestamp));
+    items.truncate(max_items);
+    items
+}
+
+/// Merge the data of the sources that have reported so far. `None` until
+/// one has; an error only once every source has failed.
+pub fn merge_sources(sources: &[TimelineSource], max_items: usize) -> Option<FeedData> {
+    let mut items = Vec::new();
+    let mut first_error = None;
+    let mut succeeded = false;
+    for source in sources {
+        match &source.data {
+            Some(FeedData::Error(e)) => {
+                first_error.get_or_insert(format!("{}: {}", source.name, e));
+            }
+            Some(data) => {
+                succeeded = true;
+                items.extend(timeline_items(&source.name, data.clone()));
+            }
+            None => {}
+        }
+    }
+
+    if succeeded {
+        return Some(FeedData::Timeline(merge(items, max_items)));
+    }
+    let all_reported = sources.iter().all(|s| s.data.is_some());
+    match first_error {
+        Some(e) if all_reported => Some(FeedData::Error(format!(
+            "Every timeline source failed. {}",
+            e
+        ))),
+        _ => None,
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for TimelineFetcher {
+    fn source_name(&self) -> &'static str {
+        "timeline"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        // Configuration problems: fetching again won't fix them
+        if !self.unknown_sources.is_empty() {
+            return Err(NonRetryable(format!(
+                "Unknown timeline source: {}",
+                self.unknown_sources.join(", ")
+            ))
+            .into());
+        }
+        if !self.has_sources {
+            return Err(NonRetryable("No timeline sources configured".to_string()).into());
+        }
+        // Items arrive as the source widgets receive their data
+        Ok(FeedData::Loading)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feeds::RssItem;
+
+    fn rss_item(title: &str, timestamp: Option<i64>) -> RssItem {
+        RssItem {
+            title: title.to_string(),
+            link: None,
+            published: None,
+            timestamp,
+            source: "feed".to_string(),
+            description: None,
+        }
+    }
+
+    fn source(name: &str, data: Option<FeedData>) -> TimelineSource {
+        TimelineSource {
+            id: format!("rss-{}", name),
+            name: name.to_string(),
+            data,
+        }
+    }
+
+    #[test]
+    fn test_merges_sources_newest_first() {
+        let sources = [
+            source(
+                "A",
+                Some(FeedData::Rss(vec![
+                    rss_item("a1", Some(100)),
+                    rss_item("a2", None),
+                ])),
+            ),
+            source(
+                "B",
+                Some(FeedData::Rss(vec![
+                    rss_item("b1", Some(300)),
+                    rss_item("b2", Some(200)),
+                ])),
+            ),
+        ];
+
+        let Some(FeedData::Timeline(items)) = merge_sources(&sources, 10) else {
+            panic!("expected timeline data");
+        };
+        let titles: Vec<&str> = items.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(titles, vec!["b1", "b2", "a1", "a2"]);
+        assert_eq!(items[0].source, "B");
+    }
+
+    #[test]
+    fn test_respects_max_items() {
+        let sources = [source(
+            "A",
+            Some(FeedData::Rss(vec![
+                rss_item("a1", Some(1)),
+                rss_item("a2", Some(2)),
+            ])),
+        )];
+
+        let Some(FeedData::Timeline(items)) = merge_sources(&sources, 1) else {
+            panic!("expected timeline data");
+        };
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].title, "a2");
+    }
+
+    #[test]
+    fn test_error_only_once_every_source_failed() {
+        let failed = || Some(FeedData::Error("HTTP 503".to_string()));
+        let mut sources = vec![source("A", failed()), source("B", None)];
+        assert!(merge_sources(&sources, 10).is_none());
+
+        sources[1].data = Some(FeedData::Rss(vec![rss_item("b1", Some(1))]));
+        assert!(matches!(
+            merge_sources(&sources, 10),
+            Some(FeedData::Timeline(items)) if items.len() == 1
+        ));
+
+        sources[1].data = failed();
+        let Some(FeedData::Error(e)) = merge_sources(&sources, 10) else {
+            panic!("expected an error");
+        };
+        assert!(e.contains("A: HTTP 503"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_source_is_an_error() {
+        let fetcher = TimelineFetcher::new(false, vec!["rss-9-9".to_string()]);
+        let err = fetcher.fetch().await.unwrap_err();
+        assert!(err.is::<NonRetryable>());
+        assert!(err.to_string().contains("rss-9-9"));
+    }
+}
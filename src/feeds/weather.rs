@@ -0,0 +1,206 @@
+use super::{http_status_error, FeedData, FeedFetcher, HourlyForecast, WeatherReport};
+use crate::config::TemperatureUnit;
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::sync::Arc;
+
+const OPEN_METEO_URL: &str = "https://api.open-meteo.com/v1/forecast";
+
+pub struct WeatherFetcher {
+    latitude: f64,
+    longitude: f64,
+    units: TemperatureUnit,
+    hours: usize,
+    client: Arc<reqwest::Client>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Forecast {
+    current_units: CurrentUnits,
+    current: Current,
+    hourly: Hourly,
+}
+
+#[derive(Debug, Deserialize)]
+struct CurrentUnits {
+    temperature_2m: String,
+    wind_speed_10m: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Current {
+    temperature_2m: f64,
+    weather_code: u8,
+    wind_speed_10m: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct Hourly {
+    /// Local times like `2024-05-01T14:00`
+    time: Vec<String>,
+    temperature_2m: Vec<f64>,
+    weather_code: Vec<u8>,
+}
+
+/// Emoji and description for a WMO weather interpretation code
+pub fn weather_condition(code: u8) -> (&'static str, &'static str) {
+    match code {
+        0 => ("☀", "Clear sky"),
+        1 => ("🌤", "Mainly clear"),
+        2 => ("⛅", "Partly cloudy"),
+        3 => ("☁", "Overcast"),
+        45 | 48 => ("🌫", "Fog"),
+        51 | 53 | 55 => ("🌦", "Drizzle"),
+        56 | 57 => ("🌧", "Freezing drizzle"),
+        61 | 63 | 65 => ("🌧", "Rain"),
+        66 | 67 => ("🌧", "Freezing rain"),
+        71 | 73 | 75 => ("🌨", "Snow"),
+        77 => ("🌨", "Snow grains"),
+        80..=82 => ("🌦", "Rain showers"),
+        85 | 86 => ("🌨", "Snow showers"),
+        95 => ("⛈", "Thunderstorm"),
+        96 | 99 => ("⛈", "Thunderstorm with hail"),
+        _ => ("?", "Unknown"),
+    }
+}
+
+fn map_forecast(forecast: Forecast) -> WeatherReport {
+    let hourly = forecast
+        .hourly
+        .time
+        .iter()
+        .zip(&forecast.hourly.temperature_2m)
+        .zip(&forecast.hourly.weather_code)
+        .map(|((time, &temperature), &weather_code)| HourlyForecast {
+            // Keep just the hour from the ISO time
+            time: time
+                .split_once('T')
+                .map_or(time.as_str(), |(_, t)| t)
+                .to_string(),
+            temperature,
+            weather_code,
+        })
+        .collect();
+
+    WeatherReport {
+        temperature: forecast.current.temperature_2m,
+        weather_code: forecast.current.weather_code,
+        wind_speed: forecast.current.wind_speed_10m,
+        temperature_unit: forecast.current_units.temperature_2m,
+        wind_speed_unit: forecast.current_units.wind_speed_10m,
+        hourly,
+    }
+}
+
+impl WeatherFetcher {
+    pub fn new(
+        latitude: f64,
+        longitude: f64,
+        units: TemperatureUnit,
+        hours: usize,
+        client: Arc<reqwest::Client>,
+    ) -> Self {
+        Self {
+            latitude,
+            longitude,
+            units,
+            hours,
+            client,
+        }
+    }
+}
+
+#[async_trait]
+impl FeedFetcher for WeatherFetcher {
+    fn source_name(&self) -> &'static str {
+        "weather"
+    }
+
+    async fn fetch(&self) -> Result<FeedData> {
+        let unit = match self.units {
+            TemperatureUnit::Celsius => "celsius",
+            TemperatureUnit::Fahrenheit => "fahrenheit",
+        };
+        let response = self
+            .client
+            .get(OPEN_METEO_URL)
+            .query(&[
+                ("latitude", self.latitude.to_string()),
+                ("longitude", self.longitude.to_string()),
+                (
+                    "current",
+                    "temperature_2m,weather_code,wind_speed_10m".to_string(),
+                ),
+                ("hourly", "temperature_2m,weather_code".to_string()),
+                // Hours after the current one
+                ("forecast_hours", (self.hours + 1).to_string()),
+                ("temperature_unit", unit.to_string()),
+                ("timezone", "auto".to_string()),
+            ])
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(http_status_error(
+                response.status(),
+                format!("Open-Meteo API error: {}", response.status()),
+            ));
+        }
+
+        let forecast: Forecast = response.json().await?;
+        let mut report = map_forecast(forecast);
+        // The first hourly entry is the current hour, already shown as "now"
+        if !report.hourly.is_empty() {
+            report.hourly.remove(0);
+        }
+        report.hourly.truncate(self.hours);
+        Ok(FeedData::Weather(report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORECAST_FIXTURE: &str = r#"{
+        "latitude": 52.52,
+        "longitude": 13.41,
+        "current_units": {"time": "iso8601", "temperature_2m": "°C", "weather_code": "wmo code", "wind_speed_10m": "km/h"},
+        "current": {"time": "2024-05-01T14:15", "temperature_2m": 18.4, "weather_code": 2, "wind_speed_10m": 11.2},
+        "hourly_units": {"time": "iso8601", "temperature_2m": "°C", "weather_code": "wmo code"},
+        "hourly": {
+            "time": ["2024-05-01T14:00", "2024-05-01T15:00", "2024-05-01T16:00"],
+            "temperature_2m": [18.1, 19.0, 17.6],
+            "weather_code": [2, 61, 95]
+        }
+    }"#;
+
+    #[test]
+    fn test_map_forecast() {
+        let forecast: Forecast = serde_json::from_str(FORECAST_FIXTURE).unwrap();
+        let report = map_forecast(forecast);
+
+        assert_eq!(report.temperature, 18.4);
+        assert_eq!(report.weather_code, 2);
+        assert_eq!(report.temperature_unit, "°C");
+        assert_eq!(report.wind_speed_unit, "km/h");
+        let hours: Vec<(&str, u8)> = report
+            .hourly
+            .iter()
+            .map(|h| (h.time.as_str(), h.weather_code))
+            .collect();
+        assert_eq!(hours, vec![("14:00", 2), ("15:00", 61), ("16:00", 95)]);
+    }
+
+    #[test]
+    fn test_weather_condition() {
+        assert_eq!(weather_condition(0), ("☀", "Clear sky"));
+        assert_eq!(weather_condition(3).1, "Overcast");
+        assert_eq!(weather_condition(48).1, "Fog");
+        assert_eq!(weather_condition(63).1, "Rain");
+        assert_eq!(weather_condition(81).1, "Rain showers");
+        assert_eq!(weather_condition(99).1, "Thunderstorm with hail");
+        assert_eq!(weather_condition(42), ("?", "Unknown"));
+    }
+}
@@ -1,16 +1,23 @@
-use super::{FeedData, FeedFetcher, YoutubeVideo};
-use anyhow::{anyhow, Result};
+use super::pixelart::{decode_frames, PixelData};
+use super::{http_status_error, FeedData, FeedFetcher, YoutubeVideo};
+use anyhow::Result;
 use async_trait::async_trait;
+use futures::future::join_all;
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
 
 const YOUTUBE_API_BASE: &str = "https://www.googleapis.com/youtube/v3";
+const CHANNEL_FEED_URL: &str = "https://www.youtube.com/feeds/videos.xml";
+/// Most video ids the Data API accepts in one `videos` call
+const MAX_IDS_PER_REQUEST: usize = 50;
 
 pub struct YoutubeFetcher {
     api_key: String,
     channels: Vec<String>,
     search_query: Option<String>,
     max_videos: usize,
-    client: reqwest::Client,
+    client: Arc<reqwest::Client>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -98,13 +105,14 @@ impl YoutubeFetcher {
         channels: Vec<String>,
         search_query: Option<String>,
         max_videos: usize,
+        client: Arc<reqwest::Client>,
     ) -> Self {
         Self {
             api_key,
             channels,
             search_query,
             max_videos,
-            client: reqwest::Client::new(),
+            client,
         }
     }
 
@@ -122,10 +130,9 @@ impl YoutubeFetcher {
         if !response.status().is_success() {
             let status = response.status();
             let error_text = response.text().await.unwrap_or_default();
-            return Err(anyhow!(
-                "YouTube API error (status {}): {}",
+            return Err(http_status_error(
                 status,
-                error_text
+                format!("YouTube API error (status {}): {}", status, error_text),
             ));
         }
 
@@ -150,43 +157,58 @@ impl YoutubeFetcher {
         self.get_video_details(&video_ids).await
     }
 
+    /// A channel's latest uploads from its public RSS feed, which needs no
+    /// API key or quota
     async fn get_channel_videos(&self, channel_id: &str) -> Result<Vec<YoutubeVideo>> {
-        let url = format!(
-            "{}/search?part=snippet&channelId={}&type=video&order=date&maxResults={}&key={}",
-            YOUTUBE_API_BASE, channel_id, self.max_videos, self.api_key
-        );
-
-        let response = self.client.get(&url).send().await?;
+        let response = self
+            .client
+            .get(CHANNEL_FEED_URL)
+            .query(&[("channel_id", channel_id)])
+            .send()
+            .await?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.unwrap_or_default();
-            return Err(anyhow!(
-                "YouTube API error (status {}): {}",
-                status,
-                error_text
+            return Err(http_status_error(
+                response.status(),
+                format!(
+                    "YouTube feed error for {}: {}",
+                    channel_id,
+                    response.status()
+                ),
             ));
         }
 
-        let search_response: YoutubeSearchResponse = response.json().await?;
+        let body = response.bytes().await?;
+        let feed = feed_rs::parser::parse(&body[..])?;
+        Ok(map_channel_feed(feed))
+    }
 
-        let video_ids: Vec<String> = search_response
-            .items
+    /// Fill in durations and view counts, which channel feeds lack, from the
+    /// Data API. Needs an API key; without one, or if the call fails, the
+    /// videos are left as they are.
+    async fn add_video_details(&self, videos: &mut [YoutubeVideo]) {
+        if self.api_key.is_empty() {
+            return;
+        }
+        let missing: Vec<String> = videos
             .iter()
-            .filter_map(|item| {
-                if let VideoId::Video { video_id } = &item.id {
-                    Some(video_id.clone())
-                } else {
-                    None
-                }
-            })
+            .filter(|v| v.duration.is_none())
+            .map(|v| v.id.clone())
             .collect();
 
-        if video_ids.is_empty() {
-            return Ok(vec![]);
+        let mut details = HashMap::new();
+        for ids in missing.chunks(MAX_IDS_PER_REQUEST) {
+            if let Ok(fetched) = self.get_video_details(ids).await {
+                details.extend(fetched.into_iter().map(|v| (v.id.clone(), v)));
+            }
         }
 
-        self.get_video_details(&video_ids).await
+        for video in videos.iter_mut() {
+            if let Some(detail) = details.remove(&video.id) {
+                video.duration = detail.duration;
+                video.view_count = detail.view_count.or(video.view_count.take());
+            }
+        }
     }
 
     async fn get_video_details(&self, video_ids: &[String]) -> Result<Vec<YoutubeVideo>> {
@@ -201,10 +223,9 @@ impl YoutubeFetcher {
         if !response.status().is_success() {
             let status = response.status();
             let error_text = response.text().await.unwrap_or_default();
-            return Err(anyhow!(
-                "YouTube API error (status {}): {}",
+            return Err(http_status_error(
                 status,
-                error_text
+                format!("YouTube API error (status {}): {}", status, error_text),
             ));
         }
 
@@ -223,7 +244,8 @@ impl YoutubeFetcher {
                 let view_count = video
                     .statistics
                     .and_then(|s| s.view_count)
-                    .map(|v| format_view_count(&v));
+                    .and_then(|v| v.parse().ok())
+                    .map(format_view_count);
 
                 let duration = video
                     .content_details
@@ -238,6 +260,9 @@ impl YoutubeFetcher {
                     thumbnail_url,
                     view_count,
                     duration,
+                    timestamp: chrono::DateTime::parse_from_rfc3339(&video.snippet.published_at)
+                        .ok()
+                        .map(|d| d.timestamp()),
                 }
             })
             .collect())
@@ -246,6 +271,10 @@ impl YoutubeFetcher {
 
 #[async_trait]
 impl FeedFetcher for YoutubeFetcher {
+    fn source_name(&self) -> &'static str {
+        "youtube"
+    }
+
     async fn fetch(&self) -> Result<FeedData> {
         let mut all_videos = Vec::new();
 
@@ -257,19 +286,22 @@ impl FeedFetcher for YoutubeFetcher {
             }
         }
 
-        // Fetch from channels
-        for channel_id in &self.channels {
-            match self.get_channel_videos(channel_id).await {
+        // Merge every channel's uploads, skipping channels that fail unless
+        // there is nothing else to show
+        let results = join_all(self.channels.iter().map(|c| self.get_channel_videos(c))).await;
+        let mut first_error = None;
+        for result in results {
+            match result {
                 Ok(mut videos) => all_videos.append(&mut videos),
                 Err(e) => {
-                    eprintln!("Error fetching channel {}: {}", channel_id, e);
-                    continue;
+                    first_error.get_or_insert(e);
                 }
             }
         }
-
-        // Limit total videos
-        all_videos.truncate(self.max_videos);
+        if let Some(e) = first_error.filter(|_| all_videos.is_empty()) {
+            return Err(e);
+        }
+        self.add_video_details(&mut all_videos).await;
 
         if all_videos.is_empty() && self.search_query.is_none() && self.channels.is_empty() {
             return Ok(FeedData::Error(
@@ -277,22 +309,104 @@ impl FeedFetcher for YoutubeFetcher {
             ));
         }
 
+        // Newest first across all sources
+        all_videos.sort_by_key(|video| std::cmp::Reverse(video.timestamp));
+        all_videos.truncate(self.max_videos);
+
         Ok(FeedData::Youtube(all_videos))
     }
 }
 
-fn format_view_count(count: &str) -> String {
-    if let Ok(num) = count.parse::<u64>() {
-        if num >= 1_000_000 {
-            format!("{:.1}M views", num as f64 / 1_000_000.0)
-        } else if num >= 1_000 {
-            format!("{:.1}K views", num as f64 / 1_000.0)
-        } else {
-            format!("{} views", num)
+/// Download a video thumbnail and shrink it to `size` pixels wide for
+/// half-block rendering
+pub async fn fetch_thumbnail(client: &reqwest::Client, url: &str, size: u32) -> Result<PixelData> {
+    let response = client.get(url).send().await?;
+    if !response.status().is_success() {
+        return Err(http_status_error(
+            response.status(),
+            format!("Thumbnail error: {}", response.status()),
+        ));
+    }
+    let bytes = response.bytes().await?;
+
+    let frames = tokio::task::spawn_blocking(move || decode_frames(&bytes, size, false)).await??;
+    frames
+        .into_iter()
+        .next()
+        .map(|(data, _)| data)
+        .ok_or_else(|| anyhow::anyhow!("Thumbnail has no image data"))
+}
+
+/// Convert a channel's Atom feed into videos. Entry ids look like
+/// `yt:video:<id>`; views and the thumbnail come from `media:group`.
+fn map_channel_feed(feed: feed_rs::model::Feed) -> Vec<YoutubeVideo> {
+    let channel = feed.title.map(|t| t.content).unwrap_or_default();
+
+    feed.entries
+        .into_iter()
+        .map(|entry| {
+            let media = entry.media.into_iter().next();
+            let published = entry.published.or(entry.updated);
+
+            YoutubeVideo {
+                id: entry
+                    .id
+                    .strip_prefix("yt:video:")
+                    .unwrap_or(&entry.id)
+                    .to_string(),
+                title: entry.title.map(|t| t.content).unwrap_or_default(),
+                channel: entry
+                    .authors
+                    .into_iter()
+                    .next()
+                    .map(|a| a.name)
+                    .unwrap_or_else(|| channel.clone()),
+                published: published
+                    .map(|d| d.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default(),
+                description: media
+                    .as_ref()
+                    .and_then(|m| m.description.as_ref())
+                    .map(|d| truncate_description(&d.content))
+                    .unwrap_or_default(),
+                thumbnail_url: media
+                    .as_ref()
+                    .and_then(|m| m.thumbnails.first())
+                    .map(|t| t.image.uri.clone()),
+                view_count: media
+                    .as_ref()
+                    .and_then(|m| m.community.as_ref())
+                    .and_then(|c| c.stats_views)
+                    .map(format_view_count),
+                duration: None,
+                timestamp: published.map(|d| d.timestamp()),
+            }
+        })
+        .collect()
+}
+
+fn format_view_count(count: u64) -> String {
+    format!("{} views", humanize_view_count(count))
+}
+
+/// Shorten a count to one decimal place with a K/M/B suffix, e.g.
+/// `1200000` to `1.2M`. Counts under a thousand are shown as they are.
+pub fn humanize_view_count(count: u64) -> String {
+    if count < 1_000 {
+        return count.to_string();
+    }
+    for (size, suffix) in [(1e9, "B"), (1e6, "M"), (1e3, "K")] {
+        // Round first, so 999,950 becomes 1M rather than 1000K
+        let scaled = (count as f64 / size * 10.0).round() / 10.0;
+        if scaled >= 1.0 {
+            return if scaled.fract() == 0.0 {
+                format!("{:.0}{}", scaled, suffix)
+            } else {
+                format!("{:.1}{}", scaled, suffix)
+            };
         }
-    } else {
-        count.to_string()
     }
+    count.to_string()
 }
 
 fn format_duration(iso_duration: &str) -> String {
@@ -339,3 +453,62 @@ fn truncate_description(desc: &str) -> String {
         desc.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns:yt="http://www.youtube.com/xml/schemas/2015" xmlns:media="http://search.yahoo.com/mrss/" xmlns="http://www.w3.org/2005/Atom">
+  <id>yt:channel:UCabc</id>
+  <title>Rust Channel</title>
+  <entry>
+    <id>yt:video:dQw4w9WgXcQ</id>
+    <yt:videoId>dQw4w9WgXcQ</yt:videoId>
+    <title>Async in depth</title>
+    <link rel="alternate" href="https://www.youtube.com/watch?v=dQw4w9WgXcQ"/>
+    <author><name>Rust Channel</name></author>
+    <published>2024-05-01T12:00:00+00:00</published>
+    <updated>2024-05-02T12:00:00+00:00</updated>
+    <media:group>
+      <media:title>Async in depth</media:title>
+      <media:thumbnail url="https://i1.ytimg.com/vi/dQw4w9WgXcQ/hqdefault.jpg" width="480" height="360"/>
+      <media:description>All about futures.</media:description>
+      <media:community>
+        <media:starRating count="10" average="5.00" min="1" max="5"/>
+        <media:statistics views="12345"/>
+      </media:community>
+    </media:group>
+  </entry>
+</feed>"#;
+
+    #[test]
+    fn test_humanize_view_count() {
+        assert_eq!(humanize_view_count(999), "999");
+        assert_eq!(humanize_view_count(1_000), "1K");
+        assert_eq!(humanize_view_count(12_345), "12.3K");
+        assert_eq!(humanize_view_count(999_950), "1M");
+        assert_eq!(humanize_view_count(1_200_000), "1.2M");
+        assert_eq!(humanize_view_count(3_400_000_000), "3.4B");
+    }
+
+    #[test]
+    fn test_map_channel_feed() {
+        let feed = feed_rs::parser::parse(FEED.as_bytes()).unwrap();
+        let videos = map_channel_feed(feed);
+
+        assert_eq!(videos.len(), 1);
+        let video = &videos[0];
+        assert_eq!(video.id, "dQw4w9WgXcQ");
+        assert_eq!(video.title, "Async in depth");
+        assert_eq!(video.channel, "Rust Channel");
+        assert_eq!(video.published, "2024-05-01");
+        assert_eq!(video.description, "All about futures.");
+        assert_eq!(
+            video.thumbnail_url.as_deref(),
+            Some("https://i1.ytimg.com/vi/dQw4w9WgXcQ/hqdefault.jpg")
+        );
+        assert_eq!(video.view_count.as_deref(), Some("12.3K views"));
+        assert_eq!(video.timestamp, Some(1714564800));
+    }
+}
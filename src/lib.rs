@@ -9,6 +9,8 @@ pub mod config;
 pub mod creature;
 pub mod event;
 pub mod feeds;
+pub mod logging;
+pub mod snapshot;
 pub mod twitter_message;
 pub mod twitter_parser;
 pub mod ui;
@@ -65,7 +67,7 @@ async fn run_app(config_path: Option<String>, refresh_interval: Option<u64>) ->
     }
 
     // Run the app
-    let mut app = app::App::new(config);
+    let mut app = app::App::new(config, config_path);
     app.run().await
 }
 
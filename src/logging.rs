@@ -0,0 +1,72 @@
+//! Diagnostics that must not be written to the terminal while the TUI owns it.
+//!
+//! With the `logging` feature, `--log-file <PATH>` sends warnings from the
+//! fetchers to a file. Without it, `warn!` type-checks its arguments and
+//! drops them.
+
+/// Log a warning. Only the format-string form is supported so the call
+/// compiles the same with and without the `logging` feature.
+macro_rules! log_warn {
+    ($($arg:tt)+) => {{
+        #[cfg(feature = "logging")]
+        tracing::warn!($($arg)+);
+        #[cfg(not(feature = "logging"))]
+        let _ = format_args!($($arg)+);
+    }};
+}
+pub(crate) use log_warn as warn;
+
+#[cfg(feature = "logging")]
+pub use file::init;
+
+#[cfg(feature = "logging")]
+mod file {
+    use anyhow::{Context, Result};
+    use std::fs::File;
+    use std::path::Path;
+    use std::sync::Mutex;
+    use tracing::{Level, Subscriber};
+
+    /// Append events at `INFO` and above to `path`
+    pub fn init(path: &Path) -> Result<()> {
+        let file = File::options()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Opening log file {}", path.display()))?;
+        tracing::subscriber::set_global_default(subscriber(file))
+            .context("Installing the log subscriber")
+    }
+
+    /// One plain-text line per event, written to `file`
+    pub(super) fn subscriber(file: File) -> impl Subscriber {
+        tracing_subscriber::fmt()
+            .with_writer(Mutex::new(file))
+            .with_max_level(Level::INFO)
+            .with_ansi(false)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "logging"))]
+mod tests {
+    use super::file::subscriber;
+    use super::warn;
+
+    #[test]
+    fn test_writes_warnings_to_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("feedtui.log");
+        let file = std::fs::File::create(&path).unwrap();
+
+        tracing::subscriber::with_default(subscriber(file), || {
+            warn!("fetch failed for {}: {}", "github", "timed out");
+            tracing::debug!("too verbose to keep");
+        });
+
+        let log = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(log.lines().count(), 1);
+        assert!(log.contains("WARN"));
+        assert!(log.ends_with("fetch failed for github: timed out\n"));
+    }
+}
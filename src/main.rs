@@ -3,6 +3,8 @@ mod config;
 mod creature;
 mod event;
 mod feeds;
+mod logging;
+mod snapshot;
 mod twitter_message;
 mod twitter_parser;
 mod ui;
@@ -24,6 +26,19 @@ struct Args {
     #[arg(short, long)]
     refresh: Option<u64>,
 
+    /// Fetch every widget once, print the results and exit without the TUI
+    #[arg(long)]
+    snapshot: bool,
+
+    /// Output format for --snapshot
+    #[arg(long, value_enum, default_value_t, requires = "snapshot")]
+    format: snapshot::SnapshotFormat,
+
+    /// Write warnings, such as failed fetches, to this file
+    #[cfg(feature = "logging")]
+    #[arg(long)]
+    log_file: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -46,6 +61,11 @@ enum Commands {
 async fn main() -> Result<()> {
     let args = Args::parse();
 
+    #[cfg(feature = "logging")]
+    if let Some(path) = &args.log_file {
+        logging::init(path)?;
+    }
+
     // Handle subcommands
     if let Some(command) = args.command {
         match command {
@@ -84,8 +104,12 @@ async fn main() -> Result<()> {
         config.general.refresh_interval_secs = refresh;
     }
 
+    if args.snapshot {
+        return snapshot::run(&config, args.format).await;
+    }
+
     // Run the app
-    let mut app = app::App::new(config);
+    let mut app = app::App::new(config, config_path);
     app.run().await
 }
 
@@ -118,7 +142,7 @@ fn init_config(force: bool) -> Result<()> {
     let refresh_interval = refresh_input.trim().parse::<u64>().unwrap_or(60);
 
     // Prompt for theme
-    print!("Theme (dark/light) [dark]: ");
+    print!("Theme (dark/light/monochrome) [dark]: ");
     io::stdout().flush()?;
     let mut theme_input = String::new();
     io::stdin().read_line(&mut theme_input)?;
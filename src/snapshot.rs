@@ -0,0 +1,206 @@
+//! Headless mode: fetch every widget once, print the results and exit
+
+use crate::app::App;
+use crate::config::Config;
+use crate::creature::Creature;
+use crate::feeds::export;
+use crate::feeds::github::short_sha;
+use crate::feeds::http::shared_client;
+use crate::feeds::{fetch_with_timeout, FeedData, FETCH_RETRIES, FETCH_TIMEOUT};
+use anyhow::Result;
+use chrono::{Local, TimeZone};
+use clap::ValueEnum;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum SnapshotFormat {
+    /// A short plain-text summary per widget
+    #[default]
+    Text,
+    /// The fetched data keyed by widget id, as written by the export key
+    Json,
+}
+
+/// Fetch every widget that talks to the network once, concurrently, and
+/// print the results to stdout. Local widgets (clock, pixel art, Tui) have
+/// nothing to report and are skipped.
+pub async fn run(config: &Config, format: SnapshotFormat) -> Result<()> {
+    let client = shared_client();
+    let mut widgets = App::build_widgets(config, &Creature::default());
+    App::connect_timelines(&mut widgets);
+
+    let fetches = widgets.iter().filter_map(|widget| {
+        let fetcher = App::create_fetcher(config, widget.as_ref(), &client);
+        if fetcher.is_local() {
+            return None;
+        }
+        Some(async move {
+            let data = fetch_with_timeout(fetcher.as_ref(), FETCH_RETRIES, FETCH_TIMEOUT).await;
+            if let FeedData::Error(e) = &data {
+                eprintln!(
+                    "fetch failed for {} ({}): {}",
+                    fetcher.source_name(),
+                    widget.id(),
+                    e
+                );
+            }
+            (widget.id(), widget.title().to_string(), data)
+        })
+    });
+    let mut results = futures::future::join_all(fetches).await;
+
+    // Timelines merge what their sources fetched
+    let mut merged = HashMap::new();
+    for (id, _, data) in &results {
+        merged.extend(App::route_to_timelines(&mut widgets, id, data));
+    }
+    for (id, _, data) in &mut results {
+        if let (FeedData::Loading, Some(timeline)) = (&data, merged.remove(id)) {
+            *data = timeline;
+        }
+    }
+
+    match format {
+        SnapshotFormat::Json => {
+            let json = export::to_json(results.iter().map(|(id, _, data)| (id.clone(), data)))?;
+            println!("{}", json);
+        }
+        SnapshotFormat::Text => {
+            for (i, (id, title, data)) in results.iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                println!("== {} ({}) ==", title, id);
+                for line in summary_lines(data) {
+                    println!("  {}", line);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Collapse newlines so every item stays on one line
+fn one_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// One line per item of a widget's data
+fn summary_lines(data: &FeedData) -> Vec<String> {
+    match data {
+        FeedData::HackerNews(stories) => stories
+            .iter()
+            .map(|s| format!("{} ({} pts, {} comments)", s.title, s.score, s.descendants))
+            .collect(),
+        FeedData::Stocks(quotes) => quotes
+            .iter()
+            .map(|q| {
+                format!(
+                    "{} {:.2} {:+.2} ({:+.2}%)",
+                    q.symbol, q.price, q.change, q.change_percent
+                )
+            })
+            .collect(),
+        FeedData::Rss(items) => items
+            .iter()
+            .map(|item| format!("{} - {}", item.title, item.source))
+            .collect(),
+        FeedData::Sports(events) => events
+            .iter()
+            .map(|e| {
+                let score = match (e.home_score, e.away_score) {
+                    (Some(h), Some(a)) => format!("{} - {}", a, h),
+                    _ => "vs".to_string(),
+                };
+                format!(
+                    "[{}] {} {} {} ({})",
+                    e.league, e.away_team, score, e.home_team, e.status
+                )
+            })
+            .collect(),
+        FeedData::Github(dashboard) => {
+            let notifications = dashboard.notifications.iter().map(|n| {
+                let marker = if n.unread { "*" } else { " " };
+                format!("{} {}: {}", marker, n.repository, n.title)
+            });
+            let pull_requests = dashboard
+                .pull_requests
+                .iter()
+                .map(|pr| format!("PR #{} {} ({})", pr.number, pr.title, pr.repository));
+            let commits = dashboard.commits.iter().map(|c| {
+                let summary = c.message.lines().next().unwrap_or_default();
+                format!("{} {} ({})", short_sha(&c.sha), summary, c.repository)
+            });
+            notifications.chain(pull_requests).chain(commits).collect()
+        }
+        FeedData::Youtube(videos) => videos
+            .iter()
+            .map(|v| format!("{} - {}", v.title, v.channel))
+            .collect(),
+        FeedData::Timeline(items) => items
+            .iter()
+            .map(|item| format!("{} - {}", item.title, item.source))
+            .collect(),
+        FeedData::Reddit(posts) => posts
+            .iter()
+            .map(|p| format!("{} (r/{}, {} pts)", p.title, p.subreddit, p.score))
+            .collect(),
+        FeedData::Mastodon(toots) => toots
+            .iter()
+            .map(|t| format!("{}: {}", t.author, one_line(&t.content)))
+            .collect(),
+        FeedData::Bluesky(posts) => posts
+            .iter()
+            .map(|p| format!("{}: {}", p.author, one_line(&p.text)))
+            .collect(),
+        FeedData::Weather(report) => vec![format!(
+            "{}{}, wind {}{}",
+            report.temperature, report.temperature_unit, report.wind_speed, report.wind_speed_unit
+        )],
+        FeedData::Calendar(events) => events
+            .iter()
+            .map(|e| {
+                let start = Local
+                    .timestamp_opt(e.start, 0)
+                    .single()
+                    .map(|t| t.format("%a %b %d %H:%M").to_string())
+                    .unwrap_or_default();
+                format!("{} {}", start, e.summary)
+            })
+            .collect(),
+        FeedData::Releases(releases) => releases
+            .iter()
+            .map(|r| format!("{} {}", r.package, r.version))
+            .collect(),
+        FeedData::Podcast(episodes) => episodes
+            .iter()
+            .map(|e| format!("{} - {}", e.title, e.show))
+            .collect(),
+        FeedData::Command(lines) => lines.clone(),
+        FeedData::Error(e) => vec![format!("Error: {}", e)],
+        FeedData::PixelArt(_) | FeedData::Loading => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feeds::StockQuote;
+
+    #[test]
+    fn test_summary_lines() {
+        let quotes = FeedData::Stocks(vec![StockQuote {
+            symbol: "AAPL".to_string(),
+            price: 190.5,
+            change: -1.25,
+            change_percent: -0.65,
+            name: "Apple".to_string(),
+            history: Vec::new(),
+        }]);
+        assert_eq!(summary_lines(&quotes), ["AAPL 190.50 -1.25 (-0.65%)"]);
+
+        let error = FeedData::Error("timed out".to_string());
+        assert_eq!(summary_lines(&error), ["Error: timed out"]);
+        assert!(summary_lines(&FeedData::Loading).is_empty());
+    }
+}
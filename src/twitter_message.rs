@@ -17,6 +17,8 @@ pub struct TwitterMessage {
 pub enum TwitterData {
     TweetPosted(String),
     ReplyPosted(String),
+    Liked(String),
+    Retweeted(String),
     SearchResults(Vec<Tweet>),
     Mentions(Vec<Tweet>),
     TweetDetail(String),
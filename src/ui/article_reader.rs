@@ -1,3 +1,4 @@
+use crate::feeds::html::html_to_text;
 use crate::ui::widgets::SelectedItem;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -136,15 +137,12 @@ impl ArticleReader {
 
         // Description/content
         if let Some(ref description) = item.description {
-            // Strip HTML tags for cleaner display
-            let clean_text = strip_html_tags(description);
-            for line in clean_text.lines() {
-                if !line.trim().is_empty() {
-                    lines.push(Line::from(Span::styled(
-                        line.to_string(),
-                        Style::default().fg(Color::White),
-                    )));
-                }
+            // Render the HTML as plain text, keeping its paragraph breaks
+            for line in html_to_text(description).lines() {
+                lines.push(Line::from(Span::styled(
+                    line.to_string(),
+                    Style::default().fg(Color::White),
+                )));
             }
         } else {
             lines.push(Line::from(Span::styled(
@@ -208,7 +206,7 @@ impl ArticleReader {
 }
 
 /// Create a centered rectangle with given percentage of width and height
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -227,66 +225,3 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         ])
         .split(popup_layout[1])[1]
 }
-
-/// Simple HTML tag stripping
-fn strip_html_tags(html: &str) -> String {
-    let mut result = String::new();
-    let mut in_tag = false;
-    let mut in_entity = false;
-    let mut entity = String::new();
-
-    for ch in html.chars() {
-        if ch == '<' {
-            in_tag = true;
-        } else if ch == '>' {
-            in_tag = false;
-        } else if ch == '&' && !in_tag {
-            in_entity = true;
-            entity.clear();
-        } else if ch == ';' && in_entity {
-            in_entity = false;
-            // Convert common HTML entities
-            match entity.as_str() {
-                "amp" => result.push('&'),
-                "lt" => result.push('<'),
-                "gt" => result.push('>'),
-                "quot" => result.push('"'),
-                "apos" => result.push('\''),
-                "nbsp" => result.push(' '),
-                "#39" => result.push('\''),
-                _ => {
-                    // Try numeric entities
-                    if let Some(stripped) = entity.strip_prefix('#') {
-                        if let Ok(code) = stripped.parse::<u32>() {
-                            if let Some(c) = char::from_u32(code) {
-                                result.push(c);
-                            }
-                        }
-                    }
-                }
-            }
-            entity.clear();
-        } else if in_entity {
-            entity.push(ch);
-        } else if !in_tag {
-            result.push(ch);
-        }
-    }
-
-    // Clean up multiple whitespace
-    let mut clean = String::new();
-    let mut last_was_space = false;
-    for ch in result.chars() {
-        if ch.is_whitespace() {
-            if !last_was_space {
-                clean.push(if ch == '\n' { '\n' } else { ' ' });
-                last_was_space = true;
-            }
-        } else {
-            clean.push(ch);
-            last_was_space = false;
-        }
-    }
-
-    clean.trim().to_string()
-}
@@ -0,0 +1,223 @@
+use crate::feeds::hackernews::HnComment;
+use crate::ui::article_reader::centered_rect;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
+};
+
+/// Columns each reply level is indented by
+const INDENT: usize = 2;
+
+enum Comments {
+    Loading,
+    Loaded(Vec<HnComment>),
+    Failed(String),
+}
+
+/// Overlay showing a Hacker News story's comment thread
+pub struct CommentsView {
+    pub visible: bool,
+    story_id: u64,
+    title: String,
+    comments: Comments,
+    scroll_offset: u16,
+    content_height: u16,
+}
+
+impl Default for CommentsView {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            story_id: 0,
+            title: String::new(),
+            comments: Comments::Loading,
+            scroll_offset: 0,
+            content_height: 0,
+        }
+    }
+}
+
+impl CommentsView {
+    /// Open the view for a story while its comments load
+    pub fn show_loading(&mut self, story_id: u64, title: String) {
+        self.story_id = story_id;
+        self.title = title;
+        self.comments = Comments::Loading;
+        self.scroll_offset = 0;
+        self.visible = true;
+    }
+
+    /// Fill in the comments, unless the view has since moved to another story
+    pub fn set_comments(&mut self, story_id: u64, result: Result<Vec<HnComment>, String>) {
+        if !self.visible || story_id != self.story_id {
+            return;
+        }
+        self.comments = match result {
+            Ok(comments) => Comments::Loaded(comments),
+            Err(e) => Comments::Failed(e),
+        };
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.comments = Comments::Loading;
+    }
+
+    /// The story's discussion page
+    pub fn url(&self) -> String {
+        format!("https://news.ycombinator.com/item?id={}", self.story_id)
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        if self.scroll_offset < self.content_height.saturating_sub(1) {
+            self.scroll_offset += 1;
+        }
+    }
+
+    pub fn page_up(&mut self, page_size: u16) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(page_size);
+    }
+
+    pub fn page_down(&mut self, page_size: u16) {
+        let max_scroll = self.content_height.saturating_sub(1);
+        self.scroll_offset = (self.scroll_offset + page_size).min(max_scroll);
+    }
+
+    /// Lines for one comment: a header with the author, then the wrapped
+    /// body. A bar down the left edge of each level shows the nesting.
+    fn comment_lines(comment: &HnComment, width: usize) -> Vec<Line<'static>> {
+        let gutter = "│ ".repeat(comment.depth);
+        let body_width = width.saturating_sub(comment.depth * INDENT).max(10);
+        let gutter_style = Style::default().fg(Color::DarkGray);
+
+        let time = comment
+            .time
+            .and_then(|t| chrono::DateTime::from_timestamp(t, 0))
+            .map(|t| {
+                t.with_timezone(&chrono::Local)
+                    .format("  %Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_default();
+
+        let mut lines = vec![Line::from(vec![
+            Span::styled(gutter.clone(), gutter_style),
+            Span::styled(
+                comment.by.clone(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(time, Style::default().fg(Color::DarkGray)),
+        ])];
+
+        for paragraph in comment.text.lines() {
+            for line in textwrap::wrap(paragraph, body_width) {
+                lines.push(Line::from(vec![
+                    Span::styled(gutter.clone(), gutter_style),
+                    Span::styled(line.into_owned(), Style::default().fg(Color::White)),
+                ]));
+            }
+        }
+        lines.push(Line::from(Span::styled(gutter, gutter_style)));
+        lines
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+
+        let popup_area = centered_rect(80, 85, area);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(format!(" {} ", self.title))
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(" [Esc/q] Close  [o] Open in browser  [j/k] Scroll ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow));
+
+        let inner = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+        let width = content_layout[0].width as usize;
+
+        let status = |text: &str, color: Color| {
+            vec![Line::from(Span::styled(
+                text.to_string(),
+                Style::default().fg(color).add_modifier(Modifier::ITALIC),
+            ))]
+        };
+        let lines: Vec<Line> = match &self.comments {
+            Comments::Loading => status("Loading comments...", Color::DarkGray),
+            Comments::Failed(e) => status(&format!("Error: {}", e), Color::Red),
+            Comments::Loaded(comments) if comments.is_empty() => {
+                status("No comments yet.", Color::DarkGray)
+            }
+            Comments::Loaded(comments) => comments
+                .iter()
+                .flat_map(|c| Self::comment_lines(c, width))
+                .collect(),
+        };
+
+        self.content_height = lines.len() as u16;
+
+        let paragraph = Paragraph::new(lines).scroll((self.scroll_offset, 0));
+        frame.render_widget(paragraph, content_layout[0]);
+
+        if self.content_height > inner.height {
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(Some("↑"))
+                .end_symbol(Some("↓"));
+
+            let mut scrollbar_state = ScrollbarState::new(self.content_height as usize)
+                .position(self.scroll_offset as usize);
+
+            frame.render_stateful_widget(scrollbar, content_layout[1], &mut scrollbar_state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comment_lines_indent_by_depth() {
+        let comment = HnComment {
+            by: "alice".to_string(),
+            text: "one two three four five six".to_string(),
+            time: None,
+            depth: 2,
+        };
+        let lines = CommentsView::comment_lines(&comment, 14);
+        let text: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+
+        assert_eq!(
+            text,
+            vec![
+                "│ │ alice",
+                "│ │ one two",
+                "│ │ three four",
+                "│ │ five six",
+                "│ │ ",
+            ]
+        );
+    }
+}
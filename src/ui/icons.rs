@@ -0,0 +1,126 @@
+use crate::config::IconSet;
+use std::collections::HashMap;
+
+/// Prefix glyphs for GitHub and GitLab rows, resolved from the global icon
+/// set plus the widget's own overrides
+#[derive(Debug, Clone, Default)]
+pub struct Icons {
+    set: IconSet,
+    overrides: HashMap<String, String>,
+}
+
+impl Icons {
+    pub fn new(set: IconSet, overrides: HashMap<String, String>) -> Self {
+        Self { set, overrides }
+    }
+
+    /// Glyph for an icon key such as `"unread"` or `"pull_request"`. Unknown
+    /// keys fall back to the generic `"notification"` glyph.
+    pub fn get(&self, key: &str) -> &str {
+        if let Some(icon) = self.overrides.get(key) {
+            return icon;
+        }
+        builtin(self.set, key)
+            .or_else(|| self.overrides.get("notification").map(String::as_str))
+            .or_else(|| builtin(self.set, "notification"))
+            .unwrap_or_default()
+    }
+
+    /// Glyph for a GitHub notification subject type (`PullRequest`, `Issue`,
+    /// ...) or GitLab to-do target type (`MergeRequest`, ...)
+    pub fn for_notification_type(&self, notification_type: &str) -> &str {
+        self.get(&snake_case(notification_type))
+    }
+}
+
+fn builtin(set: IconSet, key: &str) -> Option<&'static str> {
+    let icon = match (set, key) {
+        (IconSet::Unicode, "unread") => "●",
+        (IconSet::Unicode, "read") => "○",
+        (IconSet::Unicode, "pull_request" | "merge_request") => "⇄",
+        (IconSet::Unicode, "issue") => "◎",
+        (IconSet::Unicode, "release") => "◆",
+        (IconSet::Unicode, "commit") => "🔹",
+        (IconSet::Unicode, "discussion") => "✎",
+        (IconSet::Unicode, "check_suite") => "✓",
+        (IconSet::Unicode, "notification") => "•",
+        (IconSet::Unicode, "pr_open") => "🟢",
+        (IconSet::Unicode, "pr_draft") => "📝",
+        (IconSet::Unicode, "pr_closed") => "🔴",
+
+        (IconSet::Nerd, "unread") => "●",
+        (IconSet::Nerd, "read") => "○",
+        (IconSet::Nerd, "pull_request" | "merge_request") => "\u{f407}",
+        (IconSet::Nerd, "issue") => "\u{f41b}",
+        (IconSet::Nerd, "release") => "\u{f412}",
+        (IconSet::Nerd, "commit") => "\u{f417}",
+        (IconSet::Nerd, "discussion") => "\u{f442}",
+        (IconSet::Nerd, "check_suite") => "\u{f42e}",
+        (IconSet::Nerd, "notification") => "\u{f49a}",
+        (IconSet::Nerd, "pr_open") => "\u{f407}",
+        (IconSet::Nerd, "pr_draft") => "\u{f448}",
+        (IconSet::Nerd, "pr_closed") => "\u{f467}",
+
+        (IconSet::Ascii, "unread") => "*",
+        (IconSet::Ascii, "read") => "o",
+        (IconSet::Ascii, "pull_request") => "PR",
+        (IconSet::Ascii, "merge_request") => "MR",
+        (IconSet::Ascii, "issue") => "IS",
+        (IconSet::Ascii, "release") => "RL",
+        (IconSet::Ascii, "commit") => "-",
+        (IconSet::Ascii, "discussion") => "DS",
+        (IconSet::Ascii, "check_suite") => "CI",
+        (IconSet::Ascii, "notification") => "--",
+        (IconSet::Ascii, "pr_open") => "+",
+        (IconSet::Ascii, "pr_draft") => "~",
+        (IconSet::Ascii, "pr_closed") => "x",
+
+        _ => return None,
+    };
+    Some(icon)
+}
+
+/// `PullRequest` -> `pull_request`
+fn snake_case(s: &str) -> String {
+    let mut result = String::new();
+    for (i, ch) in s.chars().enumerate() {
+        if ch.is_uppercase() {
+            if i > 0 {
+                result.push('_');
+            }
+            result.extend(ch.to_lowercase());
+        } else {
+            result.push(ch);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notification_type_maps_to_icon() {
+        let icons = Icons::new(IconSet::Ascii, HashMap::new());
+        assert_eq!(icons.for_notification_type("PullRequest"), "PR");
+        assert_eq!(icons.for_notification_type("CheckSuite"), "CI");
+        assert_eq!(icons.for_notification_type("MergeRequest"), "MR");
+        assert_eq!(
+            icons.for_notification_type("RepositoryVulnerabilityAlert"),
+            "--"
+        );
+    }
+
+    #[test]
+    fn test_overrides_win_over_builtin_set() {
+        let overrides = HashMap::from([
+            ("issue".to_string(), "!".to_string()),
+            ("notification".to_string(), "?".to_string()),
+        ]);
+        let icons = Icons::new(IconSet::Unicode, overrides);
+        assert_eq!(icons.for_notification_type("Issue"), "!");
+        assert_eq!(icons.for_notification_type("SomethingNew"), "?");
+        assert_eq!(icons.get("unread"), "●");
+    }
+}
@@ -1,3 +1,8 @@
 pub mod article_reader;
+pub mod comments_view;
 pub mod creature_menu;
+pub mod icons;
+pub mod spinner;
+pub mod text;
+pub mod theme;
 pub mod widgets;
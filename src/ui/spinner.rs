@@ -0,0 +1,41 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Ticks seen by the event loop. Widgets render from `&self`, so the spinner
+/// reads a shared counter instead of keeping one per widget.
+static TICKS: AtomicUsize = AtomicUsize::new(0);
+
+/// Braille spinner shown in place of a static "Loading..." while a widget
+/// waits for its first fetch
+pub struct Spinner;
+
+impl Spinner {
+    /// Frame to draw on a given tick
+    pub fn frame(tick: usize) -> char {
+        FRAMES[tick % FRAMES.len()]
+    }
+
+    /// Step the animation; called on every event loop tick
+    pub fn advance() {
+        TICKS.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// `label` prefixed with the current frame, e.g. `"⠹ Loading..."`
+    pub fn text(label: &str) -> String {
+        format!("{} {}", Self::frame(TICKS.load(Ordering::Relaxed)), label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames_cycle_with_ticks() {
+        assert_eq!(Spinner::frame(0), '⠋');
+        assert_eq!(Spinner::frame(1), '⠙');
+        assert_eq!(Spinner::frame(FRAMES.len()), '⠋');
+        assert_eq!(Spinner::frame(FRAMES.len() + 2), '⠹');
+    }
+}
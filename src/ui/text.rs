@@ -0,0 +1,187 @@
+use crate::config::Overflow;
+use ratatui::text::{Line, Span};
+use unicode_width::UnicodeWidthChar;
+
+const ELLIPSIS: char = '…';
+
+/// Truncate a string to at most `max_width` terminal columns, ending with an
+/// ellipsis when anything was cut. Operates on characters and their display
+/// width, so multi-byte and wide (CJK, emoji) text is never split mid-glyph.
+pub fn truncate_with_ellipsis(s: &str, max_width: usize) -> String {
+    if display_width(s) <= max_width {
+        return s.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+
+    let mut result = take_width(s, max_width - 1);
+    result.push(ELLIPSIS);
+    result
+}
+
+/// Fit a styled line into `width` columns according to the overflow policy.
+/// Truncation always yields exactly one line; wrapping yields one or more,
+/// with continuation rows indented to match the original leading whitespace.
+pub fn fit_line<'a>(line: Line<'a>, width: usize, overflow: Overflow) -> Vec<Line<'a>> {
+    match overflow {
+        Overflow::Truncate => vec![truncate_line(line, width)],
+        Overflow::Wrap => wrap_line(line, width),
+    }
+}
+
+pub fn display_width(s: &str) -> usize {
+    s.chars().map(|c| c.width().unwrap_or(0)).sum()
+}
+
+/// Longest prefix of `s` that fits in `max_width` columns
+fn take_width(s: &str, max_width: usize) -> String {
+    let mut width = 0;
+    let mut result = String::new();
+    for ch in s.chars() {
+        let w = ch.width().unwrap_or(0);
+        if width + w > max_width {
+            break;
+        }
+        width += w;
+        result.push(ch);
+    }
+    result
+}
+
+fn truncate_line(line: Line<'_>, width: usize) -> Line<'_> {
+    if line.width() <= width {
+        return line;
+    }
+    // No room even for the ellipsis
+    if width == 0 {
+        return Line::default();
+    }
+
+    let mut remaining = width;
+    let mut spans = Vec::new();
+    for span in line.spans {
+        let span_width = span.width();
+        if span_width < remaining {
+            remaining -= span_width;
+            spans.push(span);
+            continue;
+        }
+        let content = if span_width > remaining {
+            truncate_with_ellipsis(&span.content, remaining)
+        } else {
+            // Fits exactly, but the spans after it are dropped, so make room
+            // for the ellipsis that signals the cut.
+            let mut content = take_width(&span.content, remaining - 1);
+            content.push(ELLIPSIS);
+            content
+        };
+        spans.push(Span::styled(content, span.style));
+        break;
+    }
+    Line::from(spans).style(line.style)
+}
+
+fn wrap_line(line: Line<'_>, width: usize) -> Vec<Line<'_>> {
+    if width == 0 || line.width() <= width {
+        return vec![line];
+    }
+
+    let indent = line
+        .spans
+        .first()
+        .map(|s| s.content.chars().take_while(|c| *c == ' ').count())
+        .unwrap_or(0)
+        .min(width / 2);
+
+    let mut lines = Vec::new();
+    let mut current: Vec<Span> = Vec::new();
+    let mut used = 0;
+
+    for span in line.spans {
+        let mut chunk = String::new();
+        for ch in span.content.chars() {
+            let w = ch.width().unwrap_or(0);
+            if used + w > width {
+                if !chunk.is_empty() {
+                    current.push(Span::styled(std::mem::take(&mut chunk), span.style));
+                }
+                lines.push(Line::from(std::mem::take(&mut current)).style(line.style));
+                current.push(Span::raw(" ".repeat(indent)));
+                used = indent;
+            }
+            chunk.push(ch);
+            used += w;
+        }
+        if !chunk.is_empty() {
+            current.push(Span::styled(chunk, span.style));
+        }
+    }
+    if !current.is_empty() {
+        lines.push(Line::from(current).style(line.style));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_short_string_unchanged() {
+        assert_eq!(truncate_with_ellipsis("hello", 10), "hello");
+        assert_eq!(truncate_with_ellipsis("hello", 5), "hello");
+    }
+
+    #[test]
+    fn test_truncate_adds_ellipsis() {
+        assert_eq!(truncate_with_ellipsis("hello world", 8), "hello w…");
+        assert_eq!(truncate_with_ellipsis("hello", 0), "");
+    }
+
+    #[test]
+    fn test_truncate_is_unicode_safe() {
+        // Multi-byte characters must not be split
+        assert_eq!(truncate_with_ellipsis("héllo wörld", 6), "héllo…");
+        // Wide characters take two columns each
+        assert_eq!(truncate_with_ellipsis("日本語テキスト", 7), "日本語…");
+    }
+
+    #[test]
+    fn test_truncate_line_across_spans() {
+        let line = Line::from(vec![Span::raw("   repo | "), Span::raw("PullRequest")]);
+        let fitted = fit_line(line, 14, Overflow::Truncate);
+        assert_eq!(fitted.len(), 1);
+        assert_eq!(fitted[0].to_string(), "   repo | Pul…");
+        assert_eq!(fitted[0].width(), 14);
+    }
+
+    #[test]
+    fn test_truncate_line_at_span_boundary() {
+        let line = Line::from(vec![Span::raw("abcd"), Span::raw("efgh")]);
+        let fitted = fit_line(line, 4, Overflow::Truncate);
+        assert_eq!(fitted[0].to_string(), "abc…");
+    }
+
+    #[test]
+    fn test_truncate_line_to_zero_width() {
+        let line = Line::from(vec![Span::raw(""), Span::raw("abc")]);
+        let fitted = fit_line(line, 0, Overflow::Truncate);
+        assert_eq!(fitted[0].width(), 0);
+    }
+
+    #[test]
+    fn test_wrap_line_indents_continuation() {
+        let line = Line::from(vec![Span::raw("   "), Span::raw("abcdefghij")]);
+        let wrapped = fit_line(line, 8, Overflow::Wrap);
+        let text: Vec<String> = wrapped.iter().map(|l| l.to_string()).collect();
+        assert_eq!(text, vec!["   abcde", "   fghij"]);
+    }
+
+    #[test]
+    fn test_wrap_line_that_fits_is_untouched() {
+        let line = Line::from("short");
+        let wrapped = fit_line(line, 20, Overflow::Wrap);
+        assert_eq!(wrapped.len(), 1);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::config::{GeneralConfig, PaletteConfig};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+};
+
+/// Theme name that disables color output
+pub const MONOCHROME: &str = "monochrome";
+
+/// Colors shared by widgets, from `[general.palette]` with the built-in
+/// colors filling any gaps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    pub border: Color,
+    pub border_selected: Color,
+    pub accent: Color,
+    pub dim: Color,
+    pub error: Color,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            border: Color::White,
+            border_selected: Color::Yellow,
+            accent: Color::Cyan,
+            dim: Color::DarkGray,
+            error: Color::Red,
+        }
+    }
+}
+
+impl Palette {
+    pub fn from_config(config: &PaletteConfig) -> Self {
+        let default = Self::default();
+        Self {
+            border: config.border.unwrap_or(default.border),
+            border_selected: config.border_selected.unwrap_or(default.border_selected),
+            accent: config.accent.unwrap_or(default.accent),
+            dim: config.dim.unwrap_or(default.dim),
+            error: config.error.unwrap_or(default.error),
+        }
+    }
+
+    /// Border of a widget, highlighted while it has focus
+    pub fn border_style(&self, selected: bool) -> Style {
+        let color = if selected {
+            self.border_selected
+        } else {
+            self.border
+        };
+        Style::default().fg(color)
+    }
+}
+
+/// The resolved display theme
+#[derive(Debug, Clone, Default)]
+pub struct Theme {
+    /// Render without any color, relying on bold/reverse modifiers only
+    pub monochrome: bool,
+    pub palette: Palette,
+}
+
+impl Theme {
+    /// Resolve the theme from config. A non-empty `NO_COLOR` environment
+    /// variable (see https://no-color.org) forces monochrome regardless of config.
+    pub fn resolve(general: &GeneralConfig) -> Self {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        Self {
+            monochrome: no_color || general.theme.eq_ignore_ascii_case(MONOCHROME),
+            palette: Palette::from_config(&general.palette),
+        }
+    }
+
+    /// Post-process a fully rendered frame. In monochrome mode every color is
+    /// removed: highlighted cells (anything drawn with a background) become
+    /// reversed, and the border of the focused widget is drawn bold so focus
+    /// stays visible without the usual yellow border.
+    pub fn apply(&self, buf: &mut Buffer, focused: Option<Rect>) {
+        if !self.monochrome {
+            return;
+        }
+
+        for cell in buf.content.iter_mut() {
+            if cell.bg != Color::Reset {
+                cell.modifier.insert(Modifier::REVERSED);
+            }
+            cell.fg = Color::Reset;
+            cell.bg = Color::Reset;
+            cell.underline_color = Color::Reset;
+        }
+
+        if let Some(area) = focused {
+            style_border(buf, area, Style::default().add_modifier(Modifier::BOLD));
+        }
+    }
+}
+
+/// Patch `style` onto the one-cell border around `area`
+pub fn style_border(buf: &mut Buffer, area: Rect, style: Style) {
+    let area = area.intersection(buf.area);
+    if area.is_empty() {
+        return;
+    }
+    buf.set_style(Rect::new(area.x, area.y, area.width, 1), style);
+    buf.set_style(Rect::new(area.x, area.bottom() - 1, area.width, 1), style);
+    buf.set_style(Rect::new(area.x, area.y, 1, area.height), style);
+    buf.set_style(Rect::new(area.right() - 1, area.y, 1, area.height), style);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_monochrome_strips_colors() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        buf.set_string(0, 0, "ab", Style::default().fg(Color::Yellow));
+        buf.set_string(2, 0, "cd", Style::default().bg(Color::DarkGray));
+
+        Theme {
+            monochrome: true,
+            ..Default::default()
+        }
+        .apply(&mut buf, None);
+
+        for cell in buf.content.iter() {
+            assert_eq!(cell.fg, Color::Reset);
+            assert_eq!(cell.bg, Color::Reset);
+        }
+        assert!(!buf[(0, 0)].modifier.contains(Modifier::REVERSED));
+        assert!(buf[(2, 0)].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_monochrome_bolds_focused_border() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 5));
+        Theme {
+            monochrome: true,
+            ..Default::default()
+        }
+        .apply(&mut buf, Some(Rect::new(1, 1, 3, 3)));
+
+        assert!(buf[(1, 1)].modifier.contains(Modifier::BOLD));
+        assert!(buf[(3, 3)].modifier.contains(Modifier::BOLD));
+        assert!(!buf[(2, 2)].modifier.contains(Modifier::BOLD));
+        assert!(!buf[(0, 0)].modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn test_color_theme_leaves_buffer_untouched() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buf.set_string(0, 0, "ab", Style::default().fg(Color::Yellow));
+
+        Theme::default().apply(&mut buf, None);
+
+        assert_eq!(buf[(0, 0)].fg, Color::Yellow);
+    }
+
+    #[test]
+    fn test_palette_fills_gaps_with_defaults() {
+        let config: PaletteConfig = toml::from_str(
+            r##"
+            border_selected = "#ffaf00"
+            dim = "dark gray"
+            "##,
+        )
+        .unwrap();
+        let palette = Palette::from_config(&config);
+
+        assert_eq!(palette.border_selected, Color::Rgb(0xff, 0xaf, 0x00));
+        assert_eq!(palette.dim, Color::DarkGray);
+        assert_eq!(palette.accent, Palette::default().accent);
+        assert!(toml::from_str::<PaletteConfig>("accent = \"not-a-color\"").is_err());
+    }
+}
@@ -0,0 +1,26 @@
+use crate::config::{ArxivConfig, HttpConfig, RssConfig};
+use crate::feeds::arxiv::ArxivFetcher;
+use crate::ui::widgets::{list::ItemSource, rss::RssWidget};
+
+/// New arXiv submissions. Papers arrive as `RssItem`s, so they are drawn by
+/// an `RssWidget` with the arXiv fetcher.
+pub fn widget(config: ArxivConfig) -> RssWidget {
+    let list = RssWidget::new(RssConfig {
+        title: config.title.clone(),
+        feeds: Vec::new(),
+        opml_path: None,
+        max_items: config.max_items,
+        overflow: config.overflow,
+        http: HttpConfig::default(),
+        refresh_secs: None,
+        position: config.position.clone(),
+    });
+
+    list.with_source(ItemSource::new("arxiv", move |client| {
+        Box::new(ArxivFetcher::new(
+            config.category.clone(),
+            config.max_items,
+            client,
+        ))
+    }))
+}
@@ -0,0 +1,284 @@
+use crate::config::CalendarConfig;
+use crate::feeds::calendar::CalendarFetcher;
+use crate::feeds::{CalendarEvent, FeedData, FeedFetcher};
+use crate::ui::spinner::Spinner;
+use crate::ui::widgets::{list, FeedWidget, SelectedItem};
+use chrono::{Local, TimeZone};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+use std::sync::Arc;
+
+pub struct CalendarWidget {
+    config: CalendarConfig,
+    events: Vec<CalendarEvent>,
+    loading: bool,
+    error: Option<String>,
+    scroll_state: ListState,
+    view_height: list::ViewHeight,
+    selected: bool,
+}
+
+/// How far off an event is, e.g. `in 45m`, `in 2h`, `in 3d`, or `now`
+/// while it is happening. All-day events count in days.
+fn relative_label(event: &CalendarEvent, now: i64) -> String {
+    if event.start <= now {
+        return if event.all_day { "today" } else { "now" }.to_string();
+    }
+    let minutes = (event.start - now) / 60;
+    match minutes {
+        _ if event.all_day && minutes < 24 * 60 => "tomorrow".to_string(),
+        m if m < 60 => format!("in {}m", m.max(1)),
+        m if m < 24 * 60 => format!("in {}h", m / 60),
+        m => format!("in {}d", m / (24 * 60)),
+    }
+}
+
+/// Start as a local day and time, or just the day for all-day events
+fn format_start(event: &CalendarEvent) -> String {
+    let format = if event.all_day {
+        "%a %b %d"
+    } else {
+        "%a %H:%M"
+    };
+    Local
+        .timestamp_opt(event.start, 0)
+        .single()
+        .map(|t| t.format(format).to_string())
+        .unwrap_or_default()
+}
+
+impl CalendarWidget {
+    pub fn new(config: CalendarConfig) -> Self {
+        let mut scroll_state = ListState::default();
+        scroll_state.select(Some(0));
+
+        Self {
+            config,
+            events: Vec::new(),
+            loading: true,
+            error: None,
+            scroll_state,
+            view_height: list::ViewHeight::default(),
+            selected: false,
+        }
+    }
+}
+
+impl FeedWidget for CalendarWidget {
+    fn id(&self) -> String {
+        format!(
+            "calendar-{}-{}",
+            self.config.position.row, self.config.position.col
+        )
+    }
+
+    fn title(&self) -> &str {
+        &self.config.title
+    }
+
+    fn position(&self) -> (usize, usize) {
+        (self.config.position.row, self.config.position.col)
+    }
+
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+        let border_style = if selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        let block = Block::default()
+            .title(format!(" {} ", self.config.title))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        if self.loading && self.events.is_empty() {
+            let loading_text =
+                List::new(vec![ListItem::new(Spinner::text("Loading..."))]).block(block);
+            frame.render_widget(loading_text, area);
+            return;
+        }
+
+        if let Some(ref error) = self.error {
+            let error_text =
+                List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+            frame.render_widget(error_text, area);
+            return;
+        }
+
+        if self.events.is_empty() {
+            let no_events = List::new(vec![ListItem::new("No upcoming events")]).block(block);
+            frame.render_widget(no_events, area);
+            return;
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        let items: Vec<ListItem> = self
+            .events
+            .iter()
+            .map(|event| {
+                let label = relative_label(event, now);
+                let label_color = if event.start <= now {
+                    Color::Green
+                } else {
+                    Color::Yellow
+                };
+
+                let title_line = Line::from(vec![
+                    Span::styled(
+                        format!("{:<9}", label),
+                        Style::default()
+                            .fg(label_color)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(&event.summary, Style::default().fg(Color::White)),
+                ]);
+
+                let mut meta = format_start(event);
+                if let Some(location) = &event.location {
+                    meta.push_str(&format!(" | {}", location));
+                }
+                let meta_line = Line::from(Span::styled(
+                    format!("         {}", meta),
+                    Style::default().fg(Color::DarkGray),
+                ));
+
+                ListItem::new(vec![title_line, meta_line])
+            })
+            .collect();
+
+        self.view_height
+            .record(area.height.saturating_sub(2), &items);
+        let list = List::new(items).block(block).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let mut state = self.scroll_state.clone();
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn update_data(&mut self, data: FeedData) {
+        self.loading = false;
+        match data {
+            FeedData::Calendar(mut events) => {
+                events.sort_by_key(|event| event.start);
+                self.events = events;
+                self.error = None;
+            }
+            FeedData::Error(e) => {
+                self.error = Some(e);
+            }
+            FeedData::Loading => {
+                self.loading = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn create_fetcher(&self, client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
+        Box::new(CalendarFetcher::new(
+            self.config.source.clone(),
+            self.config.max_items,
+            client,
+        ))
+    }
+
+    fn scroll_up(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected > 0 {
+                self.scroll_state.select(Some(selected - 1));
+            }
+        }
+    }
+
+    fn scroll_down(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected < self.events.len().saturating_sub(1) {
+                self.scroll_state.select(Some(selected + 1));
+            }
+        }
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        list::select_last(&mut self.scroll_state, self.events.len());
+    }
+
+    fn page_size(&self) -> usize {
+        self.view_height.page_size()
+    }
+
+    fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        vec![("Enter", "Read"), ("o", "Open"), ("y", "Copy URL")]
+    }
+
+    fn select_index(&mut self, index: usize) {
+        list::select_index(&mut self.scroll_state, index, self.events.len());
+    }
+
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        let idx = self.scroll_state.selected()?;
+        let event = self.events.get(idx)?;
+
+        Some(SelectedItem {
+            title: event.summary.clone(),
+            url: None,
+            description: event.location.clone(),
+            source: self.config.title.clone(),
+            metadata: Some(format_start(event)),
+        })
+    }
+
+    fn get_selected_discussion_url(&self) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(start: i64, all_day: bool) -> CalendarEvent {
+        CalendarEvent {
+            summary: "Standup".to_string(),
+            location: None,
+            start,
+            end: start + 900,
+            all_day,
+        }
+    }
+
+    #[test]
+    fn test_relative_label() {
+        let now = 1_000_000;
+        assert_eq!(relative_label(&event(now - 60, false), now), "now");
+        assert_eq!(relative_label(&event(now + 20, false), now), "in 1m");
+        assert_eq!(relative_label(&event(now + 45 * 60, false), now), "in 45m");
+        assert_eq!(
+            relative_label(&event(now + 2 * 3600 + 59, false), now),
+            "in 2h"
+        );
+        assert_eq!(
+            relative_label(&event(now + 3 * 86_400, false), now),
+            "in 3d"
+        );
+        assert_eq!(relative_label(&event(now - 60, true), now), "today");
+        assert_eq!(relative_label(&event(now + 3600, true), now), "tomorrow");
+        assert_eq!(relative_label(&event(now + 2 * 86_400, true), now), "in 2d");
+    }
+}
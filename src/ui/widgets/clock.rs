@@ -1,5 +1,6 @@
-use crate::config::ClockConfig;
+use crate::config::{default_date_format, default_time_format, ClockConfig};
 use crate::feeds::{FeedData, FeedFetcher};
+use crate::ui::theme::Palette;
 use crate::ui::widgets::FeedWidget;
 use async_trait::async_trait;
 use jiff::Timestamp;
@@ -10,17 +11,75 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
+use serde::{Deserialize, Serialize};
 use std::any::Any;
-use std::time::{Duration, Instant};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 
 #[derive(Debug, Clone)]
 pub struct Clock {
-    id: String,
     title: String,
     position: (usize, usize),
     timezones: Vec<String>,
+    time_format: String,
+    date_format: String,
+    big_font: bool,
     selected: bool,
-    stopwatch_state: StopwatchState,
+    palette: Palette,
+    /// Named stopwatches, starting with one unnamed entry
+    stopwatches: Vec<(String, StopwatchState)>,
+    /// Index of the stopwatch the controls act on
+    active_stopwatch: usize,
+    /// Name being typed for a new stopwatch, while the prompt is open
+    new_stopwatch_name: Option<String>,
+    /// Where the stopwatches are saved so they survive restarts; None to
+    /// keep them in memory only
+    state_path: Option<PathBuf>,
+}
+
+/// How many of the most recent laps are shown under the stopwatch
+const VISIBLE_LAPS: usize = 3;
+
+/// Rows in a big-font glyph
+const GLYPH_HEIGHT: usize = 5;
+
+/// Block-character glyph for a digit or colon, row by row
+fn glyph(c: char) -> [&'static str; GLYPH_HEIGHT] {
+    match c {
+        '0' => ["███", "█ █", "█ █", "█ █", "███"],
+        '1' => ["  █", "  █", "  █", "  █", "  █"],
+        '2' => ["███", "  █", "███", "█  ", "███"],
+        '3' => ["███", "  █", "███", "  █", "███"],
+        '4' => ["█ █", "█ █", "███", "  █", "  █"],
+        '5' => ["███", "█  ", "███", "  █", "███"],
+        '6' => ["███", "█  ", "███", "█ █", "███"],
+        '7' => ["███", "  █", "  █", "  █", "  █"],
+        '8' => ["███", "█ █", "███", "█ █", "███"],
+        '9' => ["███", "█ █", "███", "  █", "███"],
+        ':' => [" ", "█", " ", "█", " "],
+        _ => ["   "; GLYPH_HEIGHT],
+    }
+}
+
+/// Render `text` in block digits, each cell drawn `scale` columns wide and
+/// `scale` rows tall, with a one-cell gap between glyphs
+fn big_text_lines(text: &str, scale: usize) -> Vec<String> {
+    let mut rows = Vec::new();
+    for row in 0..GLYPH_HEIGHT {
+        let line = text
+            .chars()
+            .map(|c| {
+                glyph(c)[row]
+                    .chars()
+                    .flat_map(|cell| std::iter::repeat_n(cell, scale))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join(&" ".repeat(scale));
+        rows.extend(std::iter::repeat_n(line, scale));
+    }
+    rows
 }
 
 #[derive(Debug, Clone)]
@@ -28,6 +87,104 @@ struct StopwatchState {
     running: bool,
     elapsed: Duration,
     last_tick: Option<Instant>,
+    /// Elapsed time at each lap, oldest first
+    laps: Vec<Duration>,
+}
+
+impl StopwatchState {
+    fn current_elapsed(&self) -> Duration {
+        match self.last_tick {
+            Some(last_tick) if self.running => self.elapsed + last_tick.elapsed(),
+            _ => self.elapsed,
+        }
+    }
+
+    fn tick(&mut self) {
+        if self.running {
+            if let Some(last_tick) = self.last_tick {
+                self.elapsed += last_tick.elapsed();
+                self.last_tick = Some(Instant::now());
+            }
+        }
+    }
+
+    fn save(&self, saved_at: SystemTime) -> SavedStopwatch {
+        SavedStopwatch {
+            running: self.running,
+            elapsed: self.current_elapsed(),
+            saved_at,
+            laps: self.laps.clone(),
+        }
+    }
+}
+
+/// Stopwatch state as written to disk. `Instant`s can't be saved, so the
+/// elapsed time is stored with the wall-clock time it was measured at.
+#[derive(Debug, Serialize, Deserialize)]
+struct SavedStopwatch {
+    running: bool,
+    elapsed: Duration,
+    saved_at: SystemTime,
+    laps: Vec<Duration>,
+}
+
+impl SavedStopwatch {
+    /// Rebuild the live state. A stopwatch that was running has kept going
+    /// while feedtui was closed.
+    fn restore(self, now: SystemTime) -> StopwatchState {
+        let mut elapsed = self.elapsed;
+        if self.running {
+            elapsed += now.duration_since(self.saved_at).unwrap_or_default();
+        }
+        StopwatchState {
+            running: self.running,
+            elapsed,
+            last_tick: self.running.then(Instant::now),
+            laps: self.laps,
+        }
+    }
+}
+
+/// All of a clock's stopwatches as written to disk
+#[derive(Debug, Serialize, Deserialize)]
+struct SavedStopwatches {
+    active: usize,
+    stopwatches: Vec<(String, SavedStopwatch)>,
+}
+
+/// Get the default path for a clock widget's saved stopwatches
+pub fn default_stopwatch_path(widget_id: &str) -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".feedtui")
+        .join(format!("stopwatch-{}.json", widget_id))
+}
+
+/// Restore saved stopwatches and the active index. Files written before
+/// stopwatches were named hold a single stopwatch, which becomes the
+/// unnamed entry.
+fn load_stopwatches(path: &Path) -> Option<(Vec<(String, StopwatchState)>, usize)> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let now = SystemTime::now();
+
+    let saved = match serde_json::from_str::<SavedStopwatches>(&content) {
+        Ok(saved) => saved,
+        Err(_) => SavedStopwatches {
+            active: 0,
+            stopwatches: vec![(String::new(), serde_json::from_str(&content).ok()?)],
+        },
+    };
+    if saved.stopwatches.is_empty() {
+        return None;
+    }
+
+    let active = saved.active.min(saved.stopwatches.len() - 1);
+    let stopwatches = saved
+        .stopwatches
+        .into_iter()
+        .map(|(name, stopwatch)| (name, stopwatch.restore(now)))
+        .collect();
+    Some((stopwatches, active))
 }
 
 impl Default for StopwatchState {
@@ -36,60 +193,221 @@ impl Default for StopwatchState {
             running: false,
             elapsed: Duration::ZERO,
             last_tick: None,
+            laps: Vec::new(),
         }
     }
 }
 
 impl Clock {
     pub fn new(config: ClockConfig) -> Self {
+        let id = format!("clock-{}-{}", config.position.row, config.position.col);
+        let state_path = default_stopwatch_path(&id);
+        Self::with_state_path(config, Some(state_path))
+    }
+
+    /// Create a clock whose stopwatches are restored from and saved to
+    /// `state_path`
+    pub fn with_state_path(config: ClockConfig, state_path: Option<PathBuf>) -> Self {
+        let (stopwatches, active_stopwatch) = state_path
+            .as_deref()
+            .and_then(load_stopwatches)
+            .unwrap_or_else(|| (vec![(String::new(), StopwatchState::default())], 0));
+
         Self {
-            id: format!("clock-{}-{}", config.position.row, config.position.col),
             title: config.title,
             position: (config.position.row, config.position.col),
             timezones: config.timezones,
+            time_format: config.time_format,
+            date_format: config.date_format,
+            big_font: config.big_font,
             selected: false,
-            stopwatch_state: StopwatchState::default(),
+            palette: Palette::default(),
+            stopwatches,
+            active_stopwatch,
+            new_stopwatch_name: None,
+            state_path,
+        }
+    }
+
+    fn active(&self) -> &StopwatchState {
+        &self.stopwatches[self.active_stopwatch].1
+    }
+
+    fn active_mut(&mut self) -> &mut StopwatchState {
+        &mut self.stopwatches[self.active_stopwatch].1
+    }
+
+    /// Write the stopwatches to their state file. Failures are ignored; the
+    /// stopwatches keep working, they just won't survive a restart.
+    pub fn save_stopwatch(&self) {
+        let Some(path) = &self.state_path else {
+            return;
+        };
+        let now = SystemTime::now();
+        let saved = SavedStopwatches {
+            active: self.active_stopwatch,
+            stopwatches: self
+                .stopwatches
+                .iter()
+                .map(|(name, stopwatch)| (name.clone(), stopwatch.save(now)))
+                .collect(),
+        };
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        if let Ok(json) = serde_json::to_string_pretty(&saved) {
+            let _ = std::fs::write(path, json);
         }
     }
 
     pub fn toggle_stopwatch(&mut self) {
-        if self.stopwatch_state.running {
+        let stopwatch = self.active_mut();
+        if stopwatch.running {
             // Pause
-            if let Some(last_tick) = self.stopwatch_state.last_tick {
-                self.stopwatch_state.elapsed += last_tick.elapsed();
+            if let Some(last_tick) = stopwatch.last_tick {
+                stopwatch.elapsed += last_tick.elapsed();
             }
-            self.stopwatch_state.running = false;
-            self.stopwatch_state.last_tick = None;
+            stopwatch.running = false;
+            stopwatch.last_tick = None;
         } else {
             // Start/Resume
-            self.stopwatch_state.running = true;
-            self.stopwatch_state.last_tick = Some(Instant::now());
+            stopwatch.running = true;
+            stopwatch.last_tick = Some(Instant::now());
         }
+        self.save_stopwatch();
     }
 
     pub fn reset_stopwatch(&mut self) {
-        self.stopwatch_state = StopwatchState::default();
+        *self.active_mut() = StopwatchState::default();
+        self.save_stopwatch();
+    }
+
+    /// Record the active stopwatch's elapsed time as a lap. Only a running
+    /// stopwatch takes laps; returns whether one was recorded.
+    pub fn lap_stopwatch(&mut self) -> bool {
+        if !self.active().running {
+            return false;
+        }
+        let elapsed = self.active().current_elapsed();
+        self.active_mut().laps.push(elapsed);
+        self.save_stopwatch();
+        true
+    }
+
+    /// Make the next stopwatch active. Returns false, going back to the
+    /// first, when the last one was active so focus can move on.
+    pub fn next_stopwatch(&mut self) -> bool {
+        let moved = self.active_stopwatch + 1 < self.stopwatches.len();
+        self.active_stopwatch = if moved { self.active_stopwatch + 1 } else { 0 };
+        self.save_stopwatch();
+        moved
+    }
+
+    /// Start typing the name of a new stopwatch
+    pub fn open_name_prompt(&mut self) {
+        self.new_stopwatch_name = Some(String::new());
+    }
+
+    pub fn is_naming(&self) -> bool {
+        self.new_stopwatch_name.is_some()
+    }
+
+    pub fn name_push(&mut self, c: char) {
+        if let Some(name) = &mut self.new_stopwatch_name {
+            name.push(c);
+        }
     }
 
+    pub fn name_pop(&mut self) {
+        if let Some(name) = &mut self.new_stopwatch_name {
+            name.pop();
+        }
+    }
+
+    pub fn cancel_name(&mut self) {
+        self.new_stopwatch_name = None;
+    }
+
+    /// Add a stopwatch with the typed name and make it active. A blank name
+    /// is replaced with a numbered one.
+    pub fn confirm_name(&mut self) {
+        let Some(name) = self.new_stopwatch_name.take() else {
+            return;
+        };
+        let name = match name.trim() {
+            "" => format!("Timer {}", self.stopwatches.len() + 1),
+            name => name.to_string(),
+        };
+        self.stopwatches.push((name, StopwatchState::default()));
+        self.active_stopwatch = self.stopwatches.len() - 1;
+        self.save_stopwatch();
+    }
+
+    /// Label for a stopwatch; the default entry is unnamed
+    fn stopwatch_label(name: &str) -> &str {
+        if name.is_empty() {
+            "Stopwatch"
+        } else {
+            name
+        }
+    }
+
+    /// The most recent laps, newest first, as (lap number, split since the
+    /// previous lap, total elapsed)
+    fn recent_laps(&self) -> Vec<(usize, Duration, Duration)> {
+        let laps = &self.active().laps;
+        (0..laps.len())
+            .rev()
+            .take(VISIBLE_LAPS)
+            .map(|i| {
+                let previous = if i == 0 { Duration::ZERO } else { laps[i - 1] };
+                (i + 1, laps[i] - previous, laps[i])
+            })
+            .collect()
+    }
+
+    /// Advance every running stopwatch, not just the active one
     pub fn tick_stopwatch(&mut self) {
-        if self.stopwatch_state.running {
-            if let Some(last_tick) = self.stopwatch_state.last_tick {
-                let delta = last_tick.elapsed();
-                self.stopwatch_state.elapsed += delta;
-                self.stopwatch_state.last_tick = Some(Instant::now());
-            }
+        for (_, stopwatch) in &mut self.stopwatches {
+            stopwatch.tick();
         }
     }
 
     fn get_current_elapsed(&self) -> Duration {
-        if self.stopwatch_state.running {
-            if let Some(last_tick) = self.stopwatch_state.last_tick {
-                self.stopwatch_state.elapsed + last_tick.elapsed()
-            } else {
-                self.stopwatch_state.elapsed
-            }
+        self.active().current_elapsed()
+    }
+
+    /// Rows for the stopwatch list and name prompt under the active stopwatch
+    fn stopwatch_list_height(&self) -> u16 {
+        let list = if self.stopwatches.len() > 1 {
+            self.stopwatches.len() + 1
         } else {
-            self.stopwatch_state.elapsed
+            0
+        };
+        (list + usize::from(self.is_naming())) as u16
+    }
+
+    /// Format a time with a configured strftime string, falling back to the
+    /// default format if the configured one is invalid
+    fn format_zoned(zoned: &jiff::Zoned, format: &str, fallback: fn() -> String) -> String {
+        jiff::fmt::strtime::format(format, zoned)
+            .or_else(|_| jiff::fmt::strtime::format(fallback(), zoned))
+            .unwrap_or_default()
+    }
+
+    /// UTC offset as `+05:30` / `-08:00`
+    fn format_offset(seconds: i32) -> String {
+        let sign = if seconds < 0 { '-' } else { '+' };
+        let minutes = seconds.unsigned_abs() / 60;
+        format!("{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+    }
+
+    /// `(+1d)` / `(-1d)` when a zone's date is ahead of or behind the local date
+    fn day_marker(local: jiff::civil::Date, other: jiff::civil::Date) -> Option<&'static str> {
+        match other.cmp(&local) {
+            std::cmp::Ordering::Greater => Some("(+1d)"),
+            std::cmp::Ordering::Less => Some("(-1d)"),
+            std::cmp::Ordering::Equal => None,
         }
     }
 
@@ -106,15 +424,23 @@ struct ClockFetcher;
 
 #[async_trait]
 impl FeedFetcher for ClockFetcher {
+    fn source_name(&self) -> &'static str {
+        "clock"
+    }
+
     async fn fetch(&self) -> anyhow::Result<FeedData> {
         // Clock doesn't need to fetch data
         Ok(FeedData::Loading)
     }
+
+    fn is_local(&self) -> bool {
+        true
+    }
 }
 
 impl FeedWidget for Clock {
     fn id(&self) -> String {
-        self.id.clone()
+        format!("clock-{}-{}", self.position.0, self.position.1)
     }
 
     fn title(&self) -> &str {
@@ -125,9 +451,20 @@ impl FeedWidget for Clock {
         self.position
     }
 
+    /// The state file is named after the id, so the stopwatches move to
+    /// the file for the new cell
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.position = (row, col);
+        if let Some(path) = self.state_path.take() {
+            let _ = std::fs::remove_file(&path);
+            self.state_path = Some(path.with_file_name(format!("stopwatch-{}.json", self.id())));
+            self.save_stopwatch();
+        }
+    }
+
     fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
-            Style::default().fg(Color::Cyan)
+            Style::default().fg(self.palette.accent)
         } else {
             Style::default().fg(Color::Gray)
         };
@@ -140,17 +477,31 @@ impl FeedWidget for Clock {
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
-        // Split the area for clocks and stopwatch
+        let big_clock = self.big_font && self.timezones.len() == 1;
+
+        // Split the area for clocks and stopwatch. A big clock takes all the
+        // room the stopwatch doesn't need.
+        let clock_constraint = if big_clock {
+            Constraint::Min(GLYPH_HEIGHT as u16)
+        } else {
+            Constraint::Length(self.timezones.len() as u16 * 3)
+        };
+        let stopwatch_constraint = if big_clock {
+            Constraint::Length(6 + VISIBLE_LAPS as u16 + self.stopwatch_list_height())
+        } else {
+            Constraint::Min(4 + VISIBLE_LAPS as u16 + self.stopwatch_list_height())
+        };
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(self.timezones.len() as u16 * 3),
-                Constraint::Min(4),
-            ])
+            .constraints([clock_constraint, stopwatch_constraint])
             .split(inner);
 
         // Render timezone clocks
-        self.render_clocks(frame, chunks[0]);
+        if big_clock {
+            self.render_big_clock(frame, chunks[0]);
+        } else {
+            self.render_clocks(frame, chunks[0]);
+        }
 
         // Render stopwatch
         self.render_stopwatch(frame, chunks[1]);
@@ -161,7 +512,7 @@ impl FeedWidget for Clock {
         // It uses system time
     }
 
-    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
+    fn create_fetcher(&self, _client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
         Box::new(ClockFetcher)
     }
 
@@ -177,6 +528,19 @@ impl FeedWidget for Clock {
         self.selected = selected;
     }
 
+    fn set_palette(&mut self, palette: Palette) {
+        self.palette = palette;
+    }
+
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        vec![
+            ("s", "Stopwatch"),
+            ("l", "Lap"),
+            ("r", "Reset"),
+            ("n", "Name"),
+        ]
+    }
+
     fn as_any(&self) -> Option<&dyn Any> {
         Some(self)
     }
@@ -195,10 +559,9 @@ impl Clock {
         let now = Timestamp::now();
 
         // Try to detect local timezone (fallback to UTC if detection fails)
-        let local_tz_name = jiff::tz::TimeZone::system()
-            .iana_name()
-            .unwrap_or("UTC")
-            .to_string();
+        let local_tz = jiff::tz::TimeZone::system();
+        let local_tz_name = local_tz.iana_name().unwrap_or("UTC").to_string();
+        let local_date = now.to_zoned(local_tz).date();
 
         let mut text_lines = Vec::new();
 
@@ -207,10 +570,10 @@ impl Clock {
                 let time_in_tz = now.to_zoned(tz);
                 let is_local = timezone_str == &local_tz_name;
 
-                // Format time as HH:MM:SS
-                let time_str = time_in_tz.strftime("%H:%M:%S").to_string();
-                // Format date as MMM DD
-                let date_str = time_in_tz.strftime("%b %d").to_string();
+                let time_str =
+                    Self::format_zoned(&time_in_tz, &self.time_format, default_time_format);
+                let date_str =
+                    Self::format_zoned(&time_in_tz, &self.date_format, default_date_format);
 
                 let tz_name = timezone_str
                     .split('/')
@@ -226,11 +589,20 @@ impl Clock {
                     Style::default().fg(Color::White)
                 };
 
-                text_lines.push(Line::from(vec![
+                let dim = Style::default().fg(self.palette.dim);
+                let mut spans = vec![
                     Span::styled(format!("{:<15}", tz_name), style),
-                    Span::styled(format!("{:<10}", time_str), style),
+                    Span::styled(format!("{:<9} ", time_str), style),
                     Span::styled(date_str, style),
-                ]));
+                    Span::styled(
+                        format!(" {}", Self::format_offset(time_in_tz.offset().seconds())),
+                        dim,
+                    ),
+                ];
+                if let Some(marker) = Self::day_marker(local_date, time_in_tz.date()) {
+                    spans.push(Span::styled(format!(" {}", marker), dim));
+                }
+                text_lines.push(Line::from(spans));
                 text_lines.push(Line::from(""));
             }
         }
@@ -239,11 +611,70 @@ impl Clock {
         frame.render_widget(paragraph, area);
     }
 
+    /// Draw the single configured timezone's time in block digits at the
+    /// largest scale that fits, dropping the seconds before shrinking the
+    /// digits. Falls back to the normal clock line when even the smallest
+    /// size doesn't fit.
+    fn render_big_clock(&self, frame: &mut Frame, area: Rect) {
+        let Some(tz) = self
+            .timezones
+            .first()
+            .and_then(|name| jiff::tz::TimeZone::get(name).ok())
+        else {
+            self.render_clocks(frame, area);
+            return;
+        };
+        let now = Timestamp::now().to_zoned(tz);
+
+        // Leave a row for the date under the digits
+        let max_height = area.height.saturating_sub(1) as usize;
+        let candidates = (1..=4)
+            .rev()
+            .flat_map(|scale| ["%H:%M:%S", "%H:%M"].map(|format| (scale, format)));
+        let mut rendered = candidates.map(|(scale, format)| {
+            let text = Self::format_zoned(&now, format, default_time_format);
+            big_text_lines(&text, scale)
+        });
+        let fits = |rows: &Vec<String>| {
+            rows.len() <= max_height
+                && rows
+                    .first()
+                    .is_some_and(|row| row.chars().count() <= area.width as usize)
+        };
+        let Some(rows) = rendered.find(fits) else {
+            self.render_clocks(frame, area);
+            return;
+        };
+
+        // Center the digits and date vertically
+        let top_padding = (area.height as usize).saturating_sub(rows.len() + 1) / 2;
+        let style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let mut lines = vec![Line::from(""); top_padding];
+        lines.extend(
+            rows.into_iter()
+                .map(|row| Line::from(Span::styled(row, style))),
+        );
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{}  {}",
+                Self::format_zoned(&now, &self.date_format, default_date_format),
+                self.timezones[0]
+            ),
+            Style::default().fg(self.palette.dim),
+        )));
+
+        let paragraph = Paragraph::new(lines).alignment(Alignment::Center);
+        frame.render_widget(paragraph, area);
+    }
+
     fn render_stopwatch(&self, frame: &mut Frame, area: Rect) {
         let elapsed = self.get_current_elapsed();
         let time_str = Self::format_duration(elapsed);
 
-        let status = if self.stopwatch_state.running {
+        let running = self.active().running;
+        let status = if running {
             "[Running]"
         } else if elapsed.as_secs() > 0 {
             "[Paused]"
@@ -251,7 +682,7 @@ impl Clock {
             "[Stopped]"
         };
 
-        let status_color = if self.stopwatch_state.running {
+        let status_color = if running {
             Color::Green
         } else if elapsed.as_secs() > 0 {
             Color::Yellow
@@ -259,11 +690,11 @@ impl Clock {
             Color::Gray
         };
 
-        let text = vec![
+        let mut text = vec![
             Line::from(Span::styled(
-                "Stopwatch",
+                Self::stopwatch_label(&self.stopwatches[self.active_stopwatch].0).to_string(),
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(self.palette.accent)
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
@@ -274,14 +705,249 @@ impl Clock {
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(Span::styled(status, Style::default().fg(status_color))),
-            Line::from(""),
-            Line::from(Span::styled(
-                "s: Start/Pause | r: Reset",
-                Style::default().fg(Color::DarkGray),
-            )),
         ];
 
+        for (number, split, total) in self.recent_laps() {
+            text.push(Line::from(vec![
+                Span::styled(
+                    format!("Lap {:<3} ", number),
+                    Style::default().fg(self.palette.dim),
+                ),
+                Span::styled(
+                    format!("+{}  ", Self::format_duration(split)),
+                    Style::default().fg(Color::Green),
+                ),
+                Span::styled(
+                    Self::format_duration(total),
+                    Style::default().fg(Color::White),
+                ),
+            ]));
+        }
+
+        if self.stopwatches.len() > 1 {
+            text.push(Line::from(""));
+            for (i, (name, stopwatch)) in self.stopwatches.iter().enumerate() {
+                let style = if i == self.active_stopwatch {
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Gray)
+                };
+                let marker = if stopwatch.running { "▶" } else { " " };
+                text.push(Line::from(Span::styled(
+                    format!(
+                        "{} {:<12} {}",
+                        marker,
+                        Self::stopwatch_label(name),
+                        Self::format_duration(stopwatch.current_elapsed())
+                    ),
+                    style,
+                )));
+            }
+        }
+
+        if let Some(name) = &self.new_stopwatch_name {
+            text.push(Line::from(vec![
+                Span::styled("New timer: ", Style::default().fg(self.palette.accent)),
+                Span::styled(format!("{}_", name), Style::default().fg(Color::White)),
+            ]));
+        }
+
+        text.push(Line::from(""));
+        text.push(Line::from(Span::styled(
+            "s: Start/Pause | l: Lap | r: Reset | n: New | Tab: Next",
+            Style::default().fg(self.palette.dim),
+        )));
+
         let paragraph = Paragraph::new(text).alignment(Alignment::Center);
         frame.render_widget(paragraph, area);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Position;
+
+    #[test]
+    fn test_format_zoned_uses_configured_format() {
+        let zoned: jiff::Zoned = "2024-03-05T14:07:09[UTC]".parse().unwrap();
+        assert_eq!(
+            Clock::format_zoned(&zoned, "%I:%M %p", default_time_format),
+            "02:07 PM"
+        );
+        assert_eq!(
+            Clock::format_zoned(&zoned, &default_date_format(), default_date_format),
+            "Mar 05"
+        );
+        // An incomplete directive falls back to the default format
+        assert_eq!(
+            Clock::format_zoned(&zoned, "%H:%", default_time_format),
+            "14:07:09"
+        );
+    }
+
+    #[test]
+    fn test_offset_and_day_marker() {
+        assert_eq!(Clock::format_offset(19_800), "+05:30");
+        assert_eq!(Clock::format_offset(-28_800), "-08:00");
+        assert_eq!(Clock::format_offset(0), "+00:00");
+
+        let local = jiff::civil::date(2024, 3, 5);
+        assert_eq!(
+            Clock::day_marker(local, jiff::civil::date(2024, 3, 6)),
+            Some("(+1d)")
+        );
+        assert_eq!(
+            Clock::day_marker(local, jiff::civil::date(2024, 3, 4)),
+            Some("(-1d)")
+        );
+        assert_eq!(Clock::day_marker(local, local), None);
+    }
+
+    #[test]
+    fn test_big_text_lines() {
+        assert_eq!(
+            big_text_lines("1:0", 1),
+            vec![
+                "  █   ███",
+                "  █ █ █ █",
+                "  █   █ █",
+                "  █ █ █ █",
+                "  █   ███"
+            ]
+        );
+
+        let scaled = big_text_lines("7", 2);
+        assert_eq!(scaled.len(), GLYPH_HEIGHT * 2);
+        assert_eq!(scaled[0], "██████");
+        assert_eq!(scaled[1], "██████");
+        assert_eq!(scaled[2], "    ██");
+    }
+
+    fn config() -> ClockConfig {
+        ClockConfig {
+            title: "Clock".to_string(),
+            timezones: Vec::new(),
+            time_format: default_time_format(),
+            date_format: default_date_format(),
+            big_font: false,
+            position: Position { row: 0, col: 0 },
+        }
+    }
+
+    #[test]
+    fn test_stopwatch_survives_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stopwatch.json");
+
+        let mut clock = Clock::with_state_path(config(), Some(path.clone()));
+        clock.active_mut().elapsed = Duration::from_secs(90);
+        clock.toggle_stopwatch();
+        assert!(clock.lap_stopwatch());
+
+        let restored = Clock::with_state_path(config(), Some(path.clone()));
+        assert!(restored.active().running);
+        assert!(restored.get_current_elapsed() >= Duration::from_secs(90));
+        assert_eq!(restored.active().laps.len(), 1);
+
+        clock.reset_stopwatch();
+        let restored = Clock::with_state_path(config(), Some(path));
+        assert!(!restored.active().running);
+        assert_eq!(restored.get_current_elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_stopwatch_file_follows_the_clock() {
+        let dir = tempfile::tempdir().unwrap();
+        let old_path = dir.path().join("stopwatch-clock-0-0.json");
+        let new_path = dir.path().join("stopwatch-clock-1-2.json");
+
+        let mut clock = Clock::with_state_path(config(), Some(old_path.clone()));
+        clock.active_mut().elapsed = Duration::from_secs(90);
+        clock.toggle_stopwatch();
+        assert!(clock.lap_stopwatch());
+        clock.set_position(1, 2);
+        assert_eq!(clock.id(), "clock-1-2");
+
+        assert!(!old_path.exists());
+        let restored = Clock::with_state_path(config(), Some(new_path));
+        assert!(restored.active().running);
+        assert!(restored.get_current_elapsed() >= Duration::from_secs(90));
+        assert_eq!(restored.active().laps.len(), 1);
+    }
+
+    #[test]
+    fn test_running_stopwatch_advances_while_closed() {
+        let saved_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let saved = |running| SavedStopwatch {
+            running,
+            elapsed: Duration::from_secs(30),
+            saved_at,
+            laps: Vec::new(),
+        };
+        let later = saved_at + Duration::from_secs(60);
+
+        assert_eq!(saved(true).restore(later).elapsed, Duration::from_secs(90));
+        assert_eq!(saved(false).restore(later).elapsed, Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_laps_record_splits_and_clear_on_reset() {
+        let mut clock = Clock::with_state_path(config(), None);
+        assert!(!clock.lap_stopwatch());
+
+        // Drive the elapsed time by hand rather than sleeping
+        clock.active_mut().running = true;
+        for secs in [10, 25, 45, 80] {
+            clock.active_mut().elapsed = Duration::from_secs(secs);
+            assert!(clock.lap_stopwatch());
+        }
+
+        let secs = |d: Duration| d.as_secs();
+        let laps: Vec<(usize, u64, u64)> = clock
+            .recent_laps()
+            .into_iter()
+            .map(|(n, split, total)| (n, secs(split), secs(total)))
+            .collect();
+        assert_eq!(laps, vec![(4, 35, 80), (3, 20, 45), (2, 15, 25)]);
+
+        clock.reset_stopwatch();
+        assert!(clock.recent_laps().is_empty());
+    }
+
+    #[test]
+    fn test_named_stopwatches_act_independently() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stopwatch.json");
+        let mut clock = Clock::with_state_path(config(), Some(path.clone()));
+
+        // A single stopwatch lets Tab move on to the next widget
+        assert!(!clock.next_stopwatch());
+
+        clock.open_name_prompt();
+        for c in "Review".chars() {
+            clock.name_push(c);
+        }
+        clock.confirm_name();
+        clock.open_name_prompt();
+        clock.confirm_name();
+        let names: Vec<&str> = clock.stopwatches.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["", "Review", "Timer 3"]);
+        assert_eq!(clock.active_stopwatch, 2);
+
+        // Only the active stopwatch starts
+        assert!(!clock.next_stopwatch());
+        assert!(clock.next_stopwatch());
+        clock.toggle_stopwatch();
+        let running: Vec<bool> = clock.stopwatches.iter().map(|(_, s)| s.running).collect();
+        assert_eq!(running, vec![false, true, false]);
+
+        let restored = Clock::with_state_path(config(), Some(path));
+        assert_eq!(restored.stopwatches.len(), 3);
+        assert_eq!(restored.active_stopwatch, 1);
+        assert!(restored.active().running);
+        assert_eq!(restored.stopwatches[1].0, "Review");
+    }
+}
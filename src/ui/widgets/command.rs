@@ -0,0 +1,142 @@
+use crate::config::CommandConfig;
+use crate::feeds::command::CommandFetcher;
+use crate::feeds::{FeedData, FeedFetcher};
+use crate::ui::spinner::Spinner;
+use crate::ui::widgets::FeedWidget;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A shell command's output, refreshed with the other widgets. Lines are
+/// shown as-is without wrapping so tabular output like `df -h` stays aligned.
+pub struct CommandWidget {
+    config: CommandConfig,
+    lines: Vec<String>,
+    loading: bool,
+    error: Option<String>,
+    /// First line shown
+    scroll: usize,
+    selected: bool,
+}
+
+impl CommandWidget {
+    pub fn new(config: CommandConfig) -> Self {
+        Self {
+            config,
+            lines: Vec::new(),
+            loading: true,
+            error: None,
+            scroll: 0,
+            selected: false,
+        }
+    }
+}
+
+impl FeedWidget for CommandWidget {
+    fn id(&self) -> String {
+        format!(
+            "command-{}-{}",
+            self.config.position.row, self.config.position.col
+        )
+    }
+
+    fn title(&self) -> &str {
+        &self.config.title
+    }
+
+    fn position(&self) -> (usize, usize) {
+        (self.config.position.row, self.config.position.col)
+    }
+
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+        let border_style = if selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        let block = Block::default()
+            .title(format!(" {} ", self.config.title))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        let text: Vec<Line> = if self.loading && self.lines.is_empty() {
+            vec![Line::from(Spinner::text("Loading..."))]
+        } else if let Some(ref error) = self.error {
+            vec![Line::styled(
+                format!("Error: {}", error),
+                Style::default().fg(Color::Red),
+            )]
+        } else {
+            self.lines
+                .iter()
+                .skip(self.scroll)
+                .map(|line| Line::from(line.as_str()))
+                .collect()
+        };
+
+        frame.render_widget(Paragraph::new(text).block(block), area);
+    }
+
+    fn update_data(&mut self, data: FeedData) {
+        self.loading = false;
+        match data {
+            FeedData::Command(lines) => {
+                self.scroll = self.scroll.min(lines.len().saturating_sub(1));
+                self.lines = lines;
+                self.error = None;
+            }
+            FeedData::Error(e) => {
+                self.error = Some(e);
+            }
+            FeedData::Loading => {
+                self.loading = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn create_fetcher(&self, _client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
+        Box::new(CommandFetcher::new(
+            self.config.command.clone(),
+            Duration::from_secs(self.config.timeout_secs),
+        ))
+    }
+
+    fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    fn scroll_down(&mut self) {
+        if self.scroll < self.lines.len().saturating_sub(1) {
+            self.scroll += 1;
+        }
+    }
+
+    fn scroll_to_top(&mut self) {
+        self.scroll = 0;
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        self.scroll = self.lines.len().saturating_sub(1);
+    }
+
+    fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    fn get_selected_discussion_url(&self) -> Option<String> {
+        None
+    }
+}
@@ -10,6 +10,7 @@ use ratatui::{
     widgets::{Block, Borders, Gauge, Paragraph},
     Frame,
 };
+use std::sync::Arc;
 use std::time::Instant;
 
 pub struct CreatureWidget {
@@ -77,6 +78,11 @@ impl FeedWidget for CreatureWidget {
         (self.config.position.row, self.config.position.col)
     }
 
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
     fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
             Style::default().fg(Color::Yellow)
@@ -124,7 +130,7 @@ impl FeedWidget for CreatureWidget {
         // It's updated through its own mechanism
     }
 
-    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
+    fn create_fetcher(&self, _client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
         // Return a dummy fetcher since creature doesn't fetch external data
         Box::new(CreatureFetcher {})
     }
@@ -141,6 +147,10 @@ impl FeedWidget for CreatureWidget {
         self.selected = selected;
     }
 
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        vec![("t", "Menu")]
+    }
+
     fn as_any(&self) -> Option<&dyn std::any::Any> {
         Some(self)
     }
@@ -247,8 +257,16 @@ struct CreatureFetcher;
 
 #[async_trait::async_trait]
 impl FeedFetcher for CreatureFetcher {
+    fn source_name(&self) -> &'static str {
+        "creature"
+    }
+
     async fn fetch(&self) -> anyhow::Result<FeedData> {
         // Return loading to indicate this widget manages its own state
         Ok(FeedData::Loading)
     }
+
+    fn is_local(&self) -> bool {
+        true
+    }
 }
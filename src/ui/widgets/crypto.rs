@@ -0,0 +1,62 @@
+use crate::config::{CryptoConfig, StocksConfig};
+use crate::feeds::crypto::CryptoFetcher;
+use crate::ui::widgets::{list::ItemSource, stocks::StocksWidget};
+
+/// Coin prices. They arrive as stock quotes, so they are drawn by a
+/// `StocksWidget` with the CoinGecko fetcher.
+pub fn widget(config: CryptoConfig) -> StocksWidget {
+    let list = StocksWidget::new(StocksConfig {
+        title: config.title.clone(),
+        symbols: config.coins.iter().map(|c| c.symbol.clone()).collect(),
+        alerts: config.alerts.clone(),
+        refresh_secs: None,
+        position: config.position.clone(),
+    });
+
+    list.with_currency(&config.currency)
+        .with_source(ItemSource::new("crypto", move |client| {
+            Box::new(CryptoFetcher::new(
+                config.coins.clone(),
+                config.currency.clone(),
+                client,
+            ))
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feeds::{FeedData, StockQuote};
+    use crate::ui::widgets::FeedWidget;
+
+    #[test]
+    fn test_alerts_use_configured_currency() {
+        let config: CryptoConfig = toml::from_str(
+            r#"
+            coins = [{ id = "bitcoin", symbol = "BTC" }]
+            currency = "eur"
+            alerts = [{ symbol = "BTC", direction = "below", price = 50000.0 }]
+            position = { row = 0, col = 0 }
+            "#,
+        )
+        .unwrap();
+        let mut widget = widget(config);
+        let quote = |price| {
+            FeedData::Stocks(vec![StockQuote {
+                symbol: "BTC".to_string(),
+                price,
+                change: 0.0,
+                change_percent: 0.0,
+                name: "bitcoin".to_string(),
+                history: Vec::new(),
+            }])
+        };
+
+        widget.update_data(quote(51000.0));
+        widget.update_data(quote(49000.0));
+        assert_eq!(
+            widget.take_alerts(),
+            vec!["Alert: BTC below 50000.00 (now €49000.00)"]
+        );
+    }
+}
@@ -1,7 +1,11 @@
-use crate::config::GithubConfig;
-use crate::feeds::github::GithubFetcher;
-use crate::feeds::{FeedData, FeedFetcher, GithubDashboard};
-use crate::ui::widgets::FeedWidget;
+use crate::config::{GithubConfig, GithubSort, IconSet};
+use crate::feeds::github::{short_sha, GithubFetcher};
+use crate::feeds::{FeedData, FeedFetcher, GithubDashboard, GithubNotification};
+use crate::ui::icons::Icons;
+use crate::ui::spinner::Spinner;
+use crate::ui::text::{display_width, fit_line, truncate_with_ellipsis};
+use crate::ui::theme::Palette;
+use crate::ui::widgets::{list, FeedWidget, SelectedItem};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -9,6 +13,7 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState, Tabs},
     Frame,
 };
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum DashboardTab {
@@ -20,15 +25,100 @@ enum DashboardTab {
 pub struct GithubWidget {
     config: GithubConfig,
     dashboard: GithubDashboard,
+    icons: Icons,
     current_tab: DashboardTab,
     loading: bool,
     error: Option<String>,
     scroll_state: ListState,
+    view_height: list::ViewHeight,
     selected: bool,
+    has_new_items: bool,
+    /// Substring notifications must contain in their title or repository
+    filter: Option<String>,
+    /// Keys go to the filter input rather than the dashboard
+    editing_filter: bool,
+    sort: GithubSort,
+    /// Notifications are listed under a header row per repository
+    grouped: bool,
+    /// Read notifications are hidden
+    unread_only: bool,
+    palette: Palette,
+    /// Set when the notifications come from elsewhere (GitLab to-dos)
+    source: Option<list::ItemSource>,
+}
+
+/// First line of a commit message, cut with an ellipsis to fit `max_width`
+/// columns
+fn commit_summary(message: &str, max_width: usize) -> String {
+    truncate_with_ellipsis(message.lines().next().unwrap_or_default(), max_width)
+}
+
+/// A row of the notifications list
+#[derive(Debug, PartialEq)]
+enum NotificationRow<'a> {
+    /// Names the repository of the notifications below it; can't be selected
+    Header(&'a str),
+    /// Index into the visible notifications
+    Notification(usize),
+}
+
+/// Rows for the notifications tab. When grouped, a header is inserted
+/// wherever the repository changes; notifications are already ordered so
+/// each repository's are together.
+fn notification_rows<'a>(
+    notifications: &[&'a GithubNotification],
+    grouped: bool,
+) -> Vec<NotificationRow<'a>> {
+    let mut rows = Vec::new();
+    let mut current_repo = None;
+    for (i, &notif) in notifications.iter().enumerate() {
+        if grouped && current_repo != Some(notif.repository.as_str()) {
+            current_repo = Some(notif.repository.as_str());
+            rows.push(NotificationRow::Header(&notif.repository));
+        }
+        rows.push(NotificationRow::Notification(i));
+    }
+    rows
+}
+
+impl GithubSort {
+    /// The next order in the cycle Unread → Updated → Repository → Unread
+    pub fn next(self) -> Self {
+        match self {
+            GithubSort::Unread => GithubSort::Updated,
+            GithubSort::Updated => GithubSort::Repository,
+            GithubSort::Repository => GithubSort::Unread,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GithubSort::Unread => "unread first",
+            GithubSort::Updated => "updated",
+            GithubSort::Repository => "repository",
+        }
+    }
+
+    /// Sort notifications in place. `updated_at` is an RFC 3339 timestamp in
+    /// UTC, so comparing the strings orders them by time.
+    fn apply(self, notifications: &mut [&GithubNotification]) {
+        notifications.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        match self {
+            GithubSort::Unread => notifications.sort_by_key(|n| !n.unread),
+            GithubSort::Updated => {}
+            GithubSort::Repository => notifications.sort_by(|a, b| a.repository.cmp(&b.repository)),
+        }
+    }
+}
+
+/// Case-insensitive match of a notification's title or repository
+fn matches_filter(notif: &GithubNotification, query: &str) -> bool {
+    let query = query.to_lowercase();
+    notif.title.to_lowercase().contains(&query) || notif.repository.to_lowercase().contains(&query)
 }
 
 impl GithubWidget {
-    pub fn new(config: GithubConfig) -> Self {
+    pub fn new(config: GithubConfig, icon_set: IconSet) -> Self {
         let mut scroll_state = ListState::default();
         scroll_state.select(Some(0));
 
@@ -44,16 +134,165 @@ impl GithubWidget {
         };
 
         Self {
-            config,
+            icons: Icons::new(icon_set, config.icons.clone()),
             dashboard: GithubDashboard::default(),
             current_tab,
             loading: true,
             error: None,
             scroll_state,
+            view_height: list::ViewHeight::default(),
             selected: false,
+            has_new_items: false,
+            filter: None,
+            editing_filter: false,
+            sort: config.sort,
+            grouped: config.grouped,
+            unread_only: false,
+            palette: Palette::default(),
+            source: None,
+            config,
+        }
+    }
+
+    /// Draw another source's notifications instead of GitHub's
+    pub fn with_source(mut self, source: list::ItemSource) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Whether notifications can be marked read, which goes through the
+    /// GitHub API
+    pub fn can_mark_read(&self) -> bool {
+        self.source.is_none()
+    }
+
+    /// Start typing a notification filter, switching to the notifications
+    /// tab if it is shown
+    pub fn open_filter(&mut self) {
+        if !self.config.show_notifications {
+            return;
+        }
+        self.current_tab = DashboardTab::Notifications;
+        self.filter.get_or_insert_with(String::new);
+        self.editing_filter = true;
+    }
+
+    pub fn is_editing_filter(&self) -> bool {
+        self.editing_filter
+    }
+
+    pub fn filter_push(&mut self, c: char) {
+        if let Some(filter) = &mut self.filter {
+            filter.push(c);
+            self.scroll_state.select(Some(0));
+        }
+    }
+
+    pub fn filter_pop(&mut self) {
+        if let Some(filter) = &mut self.filter {
+            filter.pop();
+            self.scroll_state.select(Some(0));
+        }
+    }
+
+    /// Stop typing but keep the filter applied
+    pub fn confirm_filter(&mut self) {
+        self.editing_filter = false;
+        if self.filter.as_deref() == Some("") {
+            self.filter = None;
+        }
+    }
+
+    pub fn clear_filter(&mut self) {
+        self.filter = None;
+        self.editing_filter = false;
+        self.scroll_state.select(Some(0));
+    }
+
+    pub fn token(&self) -> &str {
+        &self.config.token
+    }
+
+    /// Thread id of the selected notification, if it is still unread
+    pub fn selected_unread_notification(&self) -> Option<String> {
+        if self.current_tab != DashboardTab::Notifications {
+            return None;
+        }
+        let notif = *self
+            .visible_notifications()
+            .get(self.scroll_state.selected()?)?;
+        notif.unread.then(|| notif.id.clone())
+    }
+
+    /// Reflect a thread the API has marked as read
+    pub fn mark_read(&mut self, thread_id: &str) {
+        for notif in &mut self.dashboard.notifications {
+            if notif.id == thread_id {
+                notif.unread = false;
+            }
+        }
+        // When only unread notifications are shown, it drops out of the list
+        if self.unread_only {
+            self.clamp_selection();
         }
     }
 
+    /// Show only unread notifications, or all of them again
+    pub fn toggle_unread_only(&mut self) {
+        self.unread_only = !self.unread_only;
+        self.scroll_state.select(Some(0));
+    }
+
+    /// Keep the selection within a list that may have shrunk
+    fn clamp_selection(&mut self) {
+        let len = self.current_tab_len();
+        if self.scroll_state.selected().is_some_and(|i| i >= len) {
+            self.scroll_state.select(Some(len.saturating_sub(1)));
+        }
+    }
+
+    /// Switch the notifications tab to the next sort order
+    pub fn cycle_sort(&mut self) {
+        self.sort = self.sort.next();
+        self.scroll_state.select(Some(0));
+    }
+
+    /// Switch between the flat and the per-repository notification list
+    pub fn toggle_grouped(&mut self) {
+        self.grouped = !self.grouped;
+        self.scroll_state.select(Some(0));
+    }
+
+    /// Notifications matching the current filter (and unread, if only those
+    /// are shown), in the current sort order.
+    /// When grouped, each repository's notifications are moved together,
+    /// repositories in the order they first appear.
+    fn visible_notifications(&self) -> Vec<&GithubNotification> {
+        let mut notifications: Vec<&GithubNotification> = self
+            .dashboard
+            .notifications
+            .iter()
+            .filter(|n| !self.unread_only || n.unread)
+            .filter(|n| {
+                self.filter
+                    .as_deref()
+                    .is_none_or(|query| matches_filter(n, query))
+            })
+            .collect();
+        self.sort.apply(&mut notifications);
+
+        if self.grouped {
+            let mut repos: Vec<&str> = Vec::new();
+            for &notif in &notifications {
+                if !repos.contains(&notif.repository.as_str()) {
+                    repos.push(&notif.repository);
+                }
+            }
+            notifications.sort_by_key(|n| repos.iter().position(|r| *r == n.repository));
+        }
+        notifications
+    }
+
     pub fn next_tab(&mut self) {
         let available_tabs = self.get_available_tabs();
         if available_tabs.is_empty() {
@@ -92,6 +331,14 @@ impl GithubWidget {
         self.scroll_state.select(Some(0));
     }
 
+    fn current_tab_len(&self) -> usize {
+        match self.current_tab {
+            DashboardTab::Notifications => self.visible_notifications().len(),
+            DashboardTab::PullRequests => self.dashboard.pull_requests.len(),
+            DashboardTab::Commits => self.dashboard.commits.len(),
+        }
+    }
+
     fn get_available_tabs(&self) -> Vec<DashboardTab> {
         let mut tabs = Vec::new();
         if self.config.show_notifications {
@@ -106,22 +353,34 @@ impl GithubWidget {
         tabs
     }
 
-    fn render_notifications(&self) -> Vec<ListItem<'_>> {
-        self.dashboard
-            .notifications
-            .iter()
-            .enumerate()
-            .map(|(i, notif)| {
-                let unread_indicator = if notif.unread { "● " } else { "○ " };
+    fn render_notifications(&self, width: usize) -> Vec<ListItem<'_>> {
+        let notifications = self.visible_notifications();
+        notification_rows(&notifications, self.grouped)
+            .into_iter()
+            .map(|row| {
+                let i = match row {
+                    NotificationRow::Header(repo) => {
+                        return ListItem::new(Line::styled(
+                            repo,
+                            Style::default()
+                                .fg(self.palette.accent)
+                                .add_modifier(Modifier::BOLD),
+                        ));
+                    }
+                    NotificationRow::Notification(i) => i,
+                };
+                let notif = notifications[i];
+                let unread_indicator = self.icons.get(if notif.unread { "unread" } else { "read" });
+                let type_icon = self.icons.for_notification_type(&notif.notification_type);
                 let title_line = Line::from(vec![
                     Span::styled(
-                        format!("{}{} ", unread_indicator, i + 1),
+                        format!("{} {} {} ", unread_indicator, type_icon, i + 1),
                         if notif.unread {
                             Style::default()
                                 .fg(Color::Green)
                                 .add_modifier(Modifier::BOLD)
                         } else {
-                            Style::default().fg(Color::DarkGray)
+                            Style::default().fg(self.palette.dim)
                         },
                     ),
                     Span::styled(&notif.title, Style::default().fg(Color::White)),
@@ -130,36 +389,38 @@ impl GithubWidget {
                 let meta_line = Line::from(vec![
                     Span::styled(
                         format!("   {} | ", notif.repository),
-                        Style::default().fg(Color::Cyan),
+                        Style::default().fg(self.palette.accent),
                     ),
                     Span::styled(
                         format!("{} | ", notif.notification_type),
                         Style::default().fg(Color::Yellow),
                     ),
-                    Span::styled(&notif.reason, Style::default().fg(Color::DarkGray)),
+                    Span::styled(&notif.reason, Style::default().fg(self.palette.dim)),
                 ]);
 
-                ListItem::new(vec![title_line, meta_line])
+                let mut lines = vec![title_line];
+                lines.extend(fit_line(meta_line, width, self.config.overflow));
+                ListItem::new(lines)
             })
             .collect()
     }
 
-    fn render_pull_requests(&self) -> Vec<ListItem<'_>> {
+    fn render_pull_requests(&self, width: usize) -> Vec<ListItem<'_>> {
         self.dashboard
             .pull_requests
             .iter()
             .map(|pr| {
-                let status_icon = if pr.draft {
-                    "📝 "
+                let status_icon = self.icons.get(if pr.draft {
+                    "pr_draft"
                 } else if pr.state == "open" {
-                    "🟢 "
+                    "pr_open"
                 } else {
-                    "🔴 "
-                };
+                    "pr_closed"
+                });
 
                 let title_line = Line::from(vec![
                     Span::styled(
-                        format!("{}#{} ", status_icon, pr.number),
+                        format!("{} #{} ", status_icon, pr.number),
                         Style::default()
                             .fg(Color::Green)
                             .add_modifier(Modifier::BOLD),
@@ -170,7 +431,7 @@ impl GithubWidget {
                 let meta_line = Line::from(vec![
                     Span::styled(
                         format!("   {} | ", pr.repository),
-                        Style::default().fg(Color::Cyan),
+                        Style::default().fg(self.palette.accent),
                     ),
                     Span::styled(
                         format!("by {} | ", pr.author),
@@ -178,43 +439,52 @@ impl GithubWidget {
                     ),
                     Span::styled(
                         format!("{} comments", pr.comments),
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(self.palette.dim),
                     ),
                 ]);
 
-                ListItem::new(vec![title_line, meta_line])
+                let mut lines = vec![title_line];
+                lines.extend(fit_line(meta_line, width, self.config.overflow));
+                ListItem::new(lines)
             })
             .collect()
     }
 
-    fn render_commits(&self) -> Vec<ListItem<'_>> {
+    fn render_commits(&self, width: usize) -> Vec<ListItem<'_>> {
         self.dashboard
             .commits
             .iter()
             .map(|commit| {
+                let prefix = format!("{} {} ", self.icons.get("commit"), short_sha(&commit.sha));
+                let summary = commit_summary(
+                    &commit.message,
+                    width.saturating_sub(display_width(&prefix)),
+                );
                 let title_line = Line::from(vec![
                     Span::styled(
-                        format!("🔹 {} ", &commit.sha),
+                        prefix,
                         Style::default()
                             .fg(Color::Yellow)
                             .add_modifier(Modifier::BOLD),
                     ),
-                    Span::styled(&commit.message, Style::default().fg(Color::White)),
+                    Span::styled(summary, Style::default().fg(Color::White)),
                 ]);
 
                 let meta_line = Line::from(vec![
                     Span::styled(
                         format!("   {} | ", commit.repository),
-                        Style::default().fg(Color::Cyan),
+                        Style::default().fg(self.palette.accent),
                     ),
                     Span::styled(
                         format!("by {} | ", commit.author),
                         Style::default().fg(Color::Green),
                     ),
-                    Span::styled(&commit.branch, Style::default().fg(Color::DarkGray)),
+                    Span::styled(&commit.branch, Style::default().fg(self.palette.dim)),
                 ]);
 
-                ListItem::new(vec![title_line, meta_line])
+                let mut lines = vec![title_line];
+                lines.extend(fit_line(meta_line, width, self.config.overflow));
+                ListItem::new(lines)
             })
             .collect()
     }
@@ -223,8 +493,10 @@ impl GithubWidget {
 impl FeedWidget for GithubWidget {
     fn id(&self) -> String {
         format!(
-            "github-{}-{}",
-            self.config.position.row, self.config.position.col
+            "{}-{}-{}",
+            self.source.as_ref().map_or("github", |s| s.id_prefix),
+            self.config.position.row,
+            self.config.position.col
         )
     }
 
@@ -236,12 +508,13 @@ impl FeedWidget for GithubWidget {
         (self.config.position.row, self.config.position.col)
     }
 
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
     fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
-        let border_style = if selected {
-            Style::default().fg(Color::Yellow)
-        } else {
-            Style::default().fg(Color::White)
-        };
+        let border_style = self.palette.border_style(selected);
 
         // Build tab titles
         let mut tab_titles = Vec::new();
@@ -276,25 +549,50 @@ impl FeedWidget for GithubWidget {
             .position(|&t| t == self.current_tab)
             .unwrap_or(0);
 
-        let title = format!(" {} ", self.config.title);
-        let block = Block::default()
+        let mut modes = Vec::new();
+        if self.sort != GithubSort::Unread {
+            modes.push(self.sort.label());
+        }
+        if self.unread_only {
+            modes.push("unread only");
+        }
+        let title = if modes.is_empty() {
+            format!(" {} ", self.config.title)
+        } else {
+            format!(" {} [{}] ", self.config.title, modes.join(" · "))
+        };
+        let mut block = Block::default()
             .title(title)
             .borders(Borders::ALL)
             .border_style(border_style);
+        if let Some(filter) = &self.filter {
+            let cursor = if self.editing_filter { "_" } else { "" };
+            block = block.title_bottom(format!(" /{}{} ", filter, cursor));
+        }
 
         if self.loading
             && self.dashboard.notifications.is_empty()
             && self.dashboard.pull_requests.is_empty()
             && self.dashboard.commits.is_empty()
         {
-            let loading_text = List::new(vec![ListItem::new("Loading dashboard...")]).block(block);
+            let loading_text =
+                List::new(vec![ListItem::new(Spinner::text("Loading dashboard..."))]).block(block);
             frame.render_widget(loading_text, area);
             return;
         }
 
         if let Some(ref error) = self.error {
-            let error_text =
-                List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+            // The first line is the status; the response body behind it is
+            // shown in the details view
+            let summary = error.lines().next().unwrap_or_default();
+            let error_text = List::new(vec![
+                ListItem::new(format!("Error: {}", summary)),
+                ListItem::new(Span::styled(
+                    "Press Enter for details",
+                    Style::default().fg(self.palette.dim),
+                )),
+            ])
+            .block(block);
             frame.render_widget(error_text, area);
             return;
         }
@@ -305,45 +603,48 @@ impl FeedWidget for GithubWidget {
             .select(selected_tab_idx)
             .highlight_style(
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(self.palette.accent)
                     .add_modifier(Modifier::BOLD),
             );
 
         frame.render_widget(tabs, area);
 
+        // Create inner area for list (below tabs)
+        let inner_area = Rect {
+            x: area.x + 1,
+            y: area.y + 2,
+            width: area.width.saturating_sub(2),
+            height: area.height.saturating_sub(3),
+        };
+
         // Render content based on current tab
         let items = match self.current_tab {
             DashboardTab::Notifications => {
                 if self.dashboard.notifications.is_empty() {
                     vec![ListItem::new("No notifications")]
+                } else if self.current_tab_len() == 0 {
+                    vec![ListItem::new("No matching notifications")]
                 } else {
-                    self.render_notifications()
+                    self.render_notifications(inner_area.width as usize)
                 }
             }
             DashboardTab::PullRequests => {
                 if self.dashboard.pull_requests.is_empty() {
                     vec![ListItem::new("No pull requests")]
                 } else {
-                    self.render_pull_requests()
+                    self.render_pull_requests(inner_area.width as usize)
                 }
             }
             DashboardTab::Commits => {
                 if self.dashboard.commits.is_empty() {
                     vec![ListItem::new("No recent commits")]
                 } else {
-                    self.render_commits()
+                    self.render_commits(inner_area.width as usize)
                 }
             }
         };
 
-        // Create inner area for list (below tabs)
-        let inner_area = Rect {
-            x: area.x + 1,
-            y: area.y + 2,
-            width: area.width.saturating_sub(2),
-            height: area.height.saturating_sub(3),
-        };
-
+        self.view_height.record(inner_area.height, &items);
         let list = List::new(items).highlight_style(
             Style::default()
                 .bg(Color::DarkGray)
@@ -351,6 +652,16 @@ impl FeedWidget for GithubWidget {
         );
 
         let mut state = self.scroll_state.clone();
+        if self.current_tab == DashboardTab::Notifications && self.grouped {
+            // The selection counts notifications, the list counts rows
+            // including headers
+            let rows = notification_rows(&self.visible_notifications(), true);
+            let row = state.selected().and_then(|selected| {
+                rows.iter()
+                    .position(|row| *row == NotificationRow::Notification(selected))
+            });
+            state.select(row);
+        }
         frame.render_stateful_widget(list, inner_area, &mut state);
     }
 
@@ -358,6 +669,15 @@ impl FeedWidget for GithubWidget {
         self.loading = false;
         match data {
             FeedData::Github(dashboard) => {
+                // Only unread notifications count as news
+                let unread: Vec<_> = dashboard
+                    .notifications
+                    .iter()
+                    .filter(|n| n.unread)
+                    .cloned()
+                    .collect();
+                self.has_new_items |=
+                    list::has_new_items(&self.dashboard.notifications, &unread, |n| n.id.clone());
                 self.dashboard = dashboard;
                 self.error = None;
             }
@@ -371,16 +691,21 @@ impl FeedWidget for GithubWidget {
         }
     }
 
-    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
+    fn create_fetcher(&self, client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
+        if let Some(source) = &self.source {
+            return source.create_fetcher(client);
+        }
         Box::new(GithubFetcher::new(
             self.config.token.clone(),
             self.config.username.clone(),
             self.config.show_notifications,
             self.config.show_pull_requests,
             self.config.show_commits,
+            self.config.show_all,
             self.config.max_notifications,
             self.config.max_pull_requests,
             self.config.max_commits,
+            client,
         ))
     }
 
@@ -393,11 +718,7 @@ impl FeedWidget for GithubWidget {
     }
 
     fn scroll_down(&mut self) {
-        let max_items = match self.current_tab {
-            DashboardTab::Notifications => self.dashboard.notifications.len(),
-            DashboardTab::PullRequests => self.dashboard.pull_requests.len(),
-            DashboardTab::Commits => self.dashboard.commits.len(),
-        };
+        let max_items = self.current_tab_len();
 
         if let Some(selected) = self.scroll_state.selected() {
             if selected < max_items.saturating_sub(1) {
@@ -406,15 +727,277 @@ impl FeedWidget for GithubWidget {
         }
     }
 
+    fn scroll_to_bottom(&mut self) {
+        let len = self.current_tab_len();
+        list::select_last(&mut self.scroll_state, len);
+    }
+
+    fn page_size(&self) -> usize {
+        self.view_height.page_size()
+    }
+
     fn set_selected(&mut self, selected: bool) {
         self.selected = selected;
     }
 
+    fn set_palette(&mut self, palette: Palette) {
+        self.palette = palette;
+    }
+
+    fn error_detail(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        let mut hints = vec![
+            ("←/→", "Tabs"),
+            ("o", "Open"),
+            ("/", "Filter"),
+            ("s", "Sort"),
+            ("v", "Group"),
+            ("u", "Unread only"),
+        ];
+        if self.can_mark_read() {
+            hints.push(("x", "Mark read"));
+        }
+        hints
+    }
+
+    fn select_index(&mut self, index: usize) {
+        let len = self.current_tab_len();
+        list::select_index(&mut self.scroll_state, index, len);
+    }
+
+    fn take_new_items(&mut self) -> bool {
+        std::mem::take(&mut self.has_new_items)
+    }
+
     fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
         Some(self)
     }
 
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        if self.current_tab != DashboardTab::Notifications {
+            return None;
+        }
+        let notif = *self
+            .visible_notifications()
+            .get(self.scroll_state.selected()?)?;
+        Some(SelectedItem {
+            title: notif.title.clone(),
+            url: Some(notif.url.clone()),
+            description: None,
+            source: notif.repository.clone(),
+            metadata: Some(format!("{} · {}", notif.notification_type, notif.reason)),
+        })
+    }
+
     fn get_selected_discussion_url(&self) -> Option<String> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification(title: &str, repository: &str) -> GithubNotification {
+        GithubNotification {
+            id: title.to_string(),
+            title: title.to_string(),
+            notification_type: "Issue".to_string(),
+            repository: repository.to_string(),
+            url: String::new(),
+            unread: true,
+            updated_at: String::new(),
+            reason: "subscribed".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_commit_summary_keeps_first_line_within_width() {
+        let message = "Fix panic when the config is empty\n\nThe loader assumed...";
+        assert_eq!(
+            commit_summary(message, 80),
+            "Fix panic when the config is empty"
+        );
+        assert_eq!(commit_summary(message, 10), "Fix panic…");
+        assert_eq!(commit_summary("", 10), "");
+    }
+
+    #[test]
+    fn test_filter_matches_title_or_repository() {
+        let notif = notification("Fix Crash on startup", "rust-lang/cargo");
+        assert!(matches_filter(&notif, "crash"));
+        assert!(matches_filter(&notif, "CARGO"));
+        assert!(!matches_filter(&notif, "tokio"));
+    }
+
+    fn widget_with(notifications: Vec<GithubNotification>) -> GithubWidget {
+        let config: GithubConfig = toml::from_str(
+            r#"
+            token = "t"
+            username = "u"
+            position = { row = 0, col = 0 }
+            "#,
+        )
+        .unwrap();
+        let mut widget = GithubWidget::new(config, IconSet::default());
+        widget.update_data(FeedData::Github(GithubDashboard {
+            notifications,
+            ..Default::default()
+        }));
+        widget
+    }
+
+    #[test]
+    fn test_scrolling_covers_filtered_notifications() {
+        let mut widget = widget_with(vec![
+            notification("one", "a/x"),
+            notification("two", "b/y"),
+            notification("three", "a/z"),
+        ]);
+
+        widget.open_filter();
+        widget.filter_push('a');
+        widget.filter_push('/');
+        assert_eq!(widget.current_tab_len(), 2);
+        widget.scroll_down();
+        widget.scroll_down();
+        assert_eq!(widget.scroll_state.selected(), Some(1));
+
+        widget.clear_filter();
+        assert_eq!(widget.current_tab_len(), 3);
+    }
+
+    #[test]
+    fn test_sort_orders_notifications() {
+        let updated =
+            |title: &str, repository: &str, unread: bool, updated_at: &str| GithubNotification {
+                unread,
+                updated_at: updated_at.to_string(),
+                ..notification(title, repository)
+            };
+        let mut widget = widget_with(vec![
+            updated("old", "b/y", true, "2024-01-01T00:00:00Z"),
+            updated("read", "a/x", false, "2024-01-03T00:00:00Z"),
+            updated("new", "c/z", true, "2024-01-02T00:00:00Z"),
+        ]);
+        let titles = |widget: &GithubWidget| -> Vec<String> {
+            widget
+                .visible_notifications()
+                .iter()
+                .map(|n| n.title.clone())
+                .collect()
+        };
+
+        assert_eq!(titles(&widget), ["new", "old", "read"]);
+        widget.cycle_sort();
+        assert_eq!(titles(&widget), ["read", "new", "old"]);
+        widget.cycle_sort();
+        assert_eq!(titles(&widget), ["read", "old", "new"]);
+        widget.cycle_sort();
+        assert_eq!(widget.sort, GithubSort::Unread);
+    }
+
+    #[test]
+    fn test_grouped_rows_put_headers_before_each_repository() {
+        let mut widget = widget_with(vec![
+            notification("one", "a/x"),
+            notification("two", "b/y"),
+            notification("three", "a/x"),
+        ]);
+        widget.toggle_grouped();
+
+        let notifications = widget.visible_notifications();
+        let titles: Vec<&str> = notifications.iter().map(|n| n.title.as_str()).collect();
+        assert_eq!(titles, ["one", "three", "two"]);
+        assert_eq!(
+            notification_rows(&notifications, true),
+            [
+                NotificationRow::Header("a/x"),
+                NotificationRow::Notification(0),
+                NotificationRow::Notification(1),
+                NotificationRow::Header("b/y"),
+                NotificationRow::Notification(2),
+            ]
+        );
+
+        // Scrolling moves between notifications, never onto a header
+        widget.scroll_down();
+        widget.scroll_down();
+        widget.scroll_down();
+        assert_eq!(widget.scroll_state.selected(), Some(2));
+    }
+
+    #[test]
+    fn test_unread_only_hides_read_notifications() {
+        let read = GithubNotification {
+            unread: false,
+            ..notification("read", "a/x")
+        };
+        let mut widget = widget_with(vec![
+            read,
+            notification("one", "a/x"),
+            notification("two", "b/y"),
+        ]);
+        widget.toggle_unread_only();
+        assert_eq!(widget.current_tab_len(), 2);
+
+        // The selection indexes the filtered list
+        widget.scroll_down();
+        assert_eq!(
+            widget.selected_unread_notification().as_deref(),
+            Some("two")
+        );
+
+        // Marking the last one read drops it and moves the selection up
+        widget.mark_read("two");
+        assert_eq!(widget.current_tab_len(), 1);
+        assert_eq!(widget.scroll_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_mark_read_flips_selected_notification() {
+        let mut widget = widget_with(vec![notification("one", "a/x"), notification("two", "b/y")]);
+        widget.scroll_down();
+        assert_eq!(
+            widget.selected_unread_notification().as_deref(),
+            Some("two")
+        );
+
+        widget.mark_read("two");
+        assert!(!widget.dashboard.notifications[1].unread);
+        assert!(widget.dashboard.notifications[0].unread);
+        assert_eq!(widget.selected_unread_notification(), None);
+    }
+
+    #[tokio::test]
+    async fn test_refused_notifications_show_error_detail() {
+        const BODY: &str = r#"{"message":"Resource not accessible by personal access token"}"#;
+        let base = crate::feeds::http::serve(&[("/notifications", "403 Forbidden", BODY)]).await;
+        let fetcher = GithubFetcher::new(
+            "t".to_string(),
+            "u".to_string(),
+            true,
+            false,
+            false,
+            false,
+            10,
+            10,
+            10,
+            Arc::new(reqwest::Client::new()),
+        )
+        .with_api_base(&base);
+
+        let data =
+            crate::feeds::fetch_with_timeout(&fetcher, 0, std::time::Duration::from_secs(5)).await;
+        let mut widget = widget_with(Vec::new());
+        widget.update_data(data);
+
+        let detail = widget.error_detail().expect("the error reaches the widget");
+        assert!(detail.starts_with("GitHub API error"));
+        assert!(detail.contains("403 Forbidden"));
+        assert!(detail.contains(BODY));
+    }
+}
@@ -0,0 +1,64 @@
+use crate::config::{GithubConfig, GithubSort, GitlabConfig, IconSet};
+use crate::feeds::gitlab::GitlabFetcher;
+use crate::feeds::http;
+use crate::ui::widgets::{github::GithubWidget, list::ItemSource};
+
+/// GitLab to-do items. They arrive as GitHub notifications, so they are
+/// drawn by a `GithubWidget` showing only its notifications tab, with the
+/// GitLab fetcher.
+pub fn widget(config: GitlabConfig, icon_set: IconSet) -> GithubWidget {
+    let list = GithubWidget::new(
+        GithubConfig {
+            title: config.title.clone(),
+            token: String::new(),
+            username: String::new(),
+            show_notifications: true,
+            show_pull_requests: false,
+            show_commits: false,
+            show_all: false,
+            max_notifications: config.max_todos,
+            max_pull_requests: 0,
+            max_commits: 0,
+            sort: GithubSort::default(),
+            grouped: false,
+            overflow: config.overflow,
+            icons: config.icons.clone(),
+            refresh_secs: None,
+            position: config.position.clone(),
+        },
+        icon_set,
+    );
+
+    list.with_source(ItemSource::new("gitlab", move |client| {
+        http::with_client(&client, &config.http, |client| {
+            Box::new(GitlabFetcher::new(
+                config.base_url.clone(),
+                config.token.clone(),
+                config.max_todos,
+                client,
+            ))
+        })
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::widgets::FeedWidget;
+
+    #[test]
+    fn test_keeps_github_keys_but_not_mark_read() {
+        let config: GitlabConfig = toml::from_str(
+            r#"
+            token = "t"
+            position = { row = 0, col = 1 }
+            "#,
+        )
+        .unwrap();
+        let widget = widget(config, IconSet::default());
+        assert_eq!(widget.id(), "gitlab-0-1");
+        assert!(!widget.can_mark_read());
+        let keys: Vec<&str> = widget.key_hints().into_iter().map(|(k, _)| k).collect();
+        assert!(keys.contains(&"s") && !keys.contains(&"x"));
+    }
+}
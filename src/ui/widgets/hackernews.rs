@@ -1,7 +1,9 @@
 use crate::config::HackernewsConfig;
 use crate::feeds::hackernews::HnFetcher;
 use crate::feeds::{FeedData, FeedFetcher, HnStory};
-use crate::ui::widgets::{FeedWidget, SelectedItem};
+use crate::ui::spinner::Spinner;
+use crate::ui::text::fit_line;
+use crate::ui::widgets::{list, FeedWidget, SelectedItem};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -9,14 +11,58 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState},
     Frame,
 };
+use std::sync::Arc;
+
+/// Which kinds of story the widget lists
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HnFilter {
+    #[default]
+    All,
+    Ask,
+    Show,
+    Jobs,
+}
+
+impl HnFilter {
+    /// The next filter in the cycle All → Ask → Show → Jobs → All
+    pub fn next(self) -> Self {
+        match self {
+            HnFilter::All => HnFilter::Ask,
+            HnFilter::Ask => HnFilter::Show,
+            HnFilter::Show => HnFilter::Jobs,
+            HnFilter::Jobs => HnFilter::All,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            HnFilter::All => "All",
+            HnFilter::Ask => "Ask HN",
+            HnFilter::Show => "Show HN",
+            HnFilter::Jobs => "Jobs",
+        }
+    }
+
+    fn matches(self, story: &HnStory) -> bool {
+        match self {
+            HnFilter::All => true,
+            HnFilter::Ask => story.title.starts_with("Ask HN"),
+            HnFilter::Show => story.title.starts_with("Show HN"),
+            HnFilter::Jobs => story.kind == "job",
+        }
+    }
+}
 
 pub struct HackernewsWidget {
     config: HackernewsConfig,
     stories: Vec<HnStory>,
+    filter: HnFilter,
     loading: bool,
     error: Option<String>,
     scroll_state: ListState,
+    view_height: list::ViewHeight,
     selected: bool,
+    has_new_items: bool,
 }
 
 impl HackernewsWidget {
@@ -27,12 +73,35 @@ impl HackernewsWidget {
         Self {
             config,
             stories: Vec::new(),
+            filter: HnFilter::default(),
             loading: true,
             error: None,
             scroll_state,
+            view_height: list::ViewHeight::default(),
             selected: false,
+            has_new_items: false,
         }
     }
+
+    /// Stories that pass the current filter
+    fn visible_stories(&self) -> Vec<&HnStory> {
+        self.stories
+            .iter()
+            .filter(|s| self.filter.matches(s))
+            .collect()
+    }
+
+    pub fn selected_story(&self) -> Option<&HnStory> {
+        self.visible_stories()
+            .get(self.scroll_state.selected()?)
+            .copied()
+    }
+
+    /// Switch to the next story filter, starting again from the top
+    pub fn cycle_filter(&mut self) {
+        self.filter = self.filter.next();
+        self.scroll_state.select(Some(0));
+    }
 }
 
 impl FeedWidget for HackernewsWidget {
@@ -51,6 +120,11 @@ impl FeedWidget for HackernewsWidget {
         (self.config.position.row, self.config.position.col)
     }
 
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
     fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
             Style::default().fg(Color::Yellow)
@@ -58,13 +132,18 @@ impl FeedWidget for HackernewsWidget {
             Style::default().fg(Color::White)
         };
 
+        let title = match self.filter {
+            HnFilter::All => format!(" {} ", self.config.title),
+            filter => format!(" {} [{}] ", self.config.title, filter.label()),
+        };
         let block = Block::default()
-            .title(format!(" {} ", self.config.title))
+            .title(title)
             .borders(Borders::ALL)
             .border_style(border_style);
 
         if self.loading && self.stories.is_empty() {
-            let loading_text = List::new(vec![ListItem::new("Loading...")]).block(block);
+            let loading_text =
+                List::new(vec![ListItem::new(Spinner::text("Loading..."))]).block(block);
             frame.render_widget(loading_text, area);
             return;
         }
@@ -76,9 +155,20 @@ impl FeedWidget for HackernewsWidget {
             return;
         }
 
-        let items: Vec<ListItem> = self
-            .stories
-            .iter()
+        let stories = self.visible_stories();
+        if stories.is_empty() {
+            let empty = List::new(vec![ListItem::new(format!(
+                "No {} stories",
+                self.filter.label()
+            ))])
+            .block(block);
+            frame.render_widget(empty, area);
+            return;
+        }
+
+        let meta_width = area.width.saturating_sub(2) as usize;
+        let items: Vec<ListItem> = stories
+            .into_iter()
             .enumerate()
             .map(|(i, story)| {
                 let title_line = Line::from(vec![
@@ -101,10 +191,14 @@ impl FeedWidget for HackernewsWidget {
                     ),
                 ]);
 
-                ListItem::new(vec![title_line, meta_line])
+                let mut lines = vec![title_line];
+                lines.extend(fit_line(meta_line, meta_width, self.config.overflow));
+                ListItem::new(lines)
             })
             .collect();
 
+        self.view_height
+            .record(area.height.saturating_sub(2), &items);
         let list = List::new(items).block(block).highlight_style(
             Style::default()
                 .bg(Color::DarkGray)
@@ -119,6 +213,7 @@ impl FeedWidget for HackernewsWidget {
         self.loading = false;
         match data {
             FeedData::HackerNews(stories) => {
+                self.has_new_items |= list::has_new_items(&self.stories, &stories, |s| s.id);
                 self.stories = stories;
                 self.error = None;
             }
@@ -132,10 +227,11 @@ impl FeedWidget for HackernewsWidget {
         }
     }
 
-    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
+    fn create_fetcher(&self, client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
         Box::new(HnFetcher::new(
             self.config.story_type.clone(),
             self.config.story_count,
+            client,
         ))
     }
 
@@ -149,19 +245,45 @@ impl FeedWidget for HackernewsWidget {
 
     fn scroll_down(&mut self) {
         if let Some(selected) = self.scroll_state.selected() {
-            if selected < self.stories.len().saturating_sub(1) {
+            if selected < self.visible_stories().len().saturating_sub(1) {
                 self.scroll_state.select(Some(selected + 1));
             }
         }
     }
 
+    fn scroll_to_bottom(&mut self) {
+        let len = self.visible_stories().len();
+        list::select_last(&mut self.scroll_state, len);
+    }
+
+    fn page_size(&self) -> usize {
+        self.view_height.page_size()
+    }
+
     fn set_selected(&mut self, selected: bool) {
         self.selected = selected;
     }
 
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        vec![
+            ("Enter", "Comments"),
+            ("f", "Filter"),
+            ("o", "Open"),
+            ("y", "Copy URL"),
+        ]
+    }
+
+    fn select_index(&mut self, index: usize) {
+        let len = self.visible_stories().len();
+        list::select_index(&mut self.scroll_state, index, len);
+    }
+
+    fn take_new_items(&mut self) -> bool {
+        std::mem::take(&mut self.has_new_items)
+    }
+
     fn get_selected_item(&self) -> Option<SelectedItem> {
-        let idx = self.scroll_state.selected()?;
-        let story = self.stories.get(idx)?;
+        let story = self.selected_story()?;
 
         // For HN, if no direct URL, use the HN discussion page
         let url = story
@@ -181,10 +303,159 @@ impl FeedWidget for HackernewsWidget {
         })
     }
 
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
+
     /// Get the HN discussion URL for the selected story
     fn get_selected_discussion_url(&self) -> Option<String> {
-        let idx = self.scroll_state.selected()?;
-        let story = self.stories.get(idx)?;
+        let story = self.selected_story()?;
         Some(format!("https://news.ycombinator.com/item?id={}", story.id))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Overflow, Position};
+    use crate::feeds::fetch_with_timeout;
+    use crate::feeds::mock::MockFetcher;
+    use async_trait::async_trait;
+    use std::time::Duration;
+
+    struct FailingFetcher;
+
+    #[async_trait]
+    impl FeedFetcher for FailingFetcher {
+        fn source_name(&self) -> &'static str {
+            "failing"
+        }
+
+        async fn fetch(&self) -> anyhow::Result<FeedData> {
+            Err(anyhow::anyhow!("connection refused"))
+        }
+    }
+
+    struct HangingFetcher;
+
+    #[async_trait]
+    impl FeedFetcher for HangingFetcher {
+        fn source_name(&self) -> &'static str {
+            "hanging"
+        }
+
+        async fn fetch(&self) -> anyhow::Result<FeedData> {
+            std::future::pending().await
+        }
+    }
+
+    fn widget() -> HackernewsWidget {
+        HackernewsWidget::new(HackernewsConfig {
+            title: "Hacker News".to_string(),
+            story_count: 10,
+            story_type: "top".to_string(),
+            overflow: Overflow::default(),
+            refresh_secs: None,
+            position: Position { row: 0, col: 0 },
+        })
+    }
+
+    fn story(title: &str, kind: &str) -> HnStory {
+        HnStory {
+            id: 0,
+            title: title.to_string(),
+            url: None,
+            score: 1,
+            by: "pg".to_string(),
+            descendants: 0,
+            time: None,
+            kind: kind.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_filter_cycles_through_story_kinds() {
+        let mut widget = widget();
+        widget.update_data(FeedData::HackerNews(vec![
+            story("A new database", "story"),
+            story("Ask HN: Best editor?", "story"),
+            story("Show HN: feedtui", "story"),
+            story("Acme is hiring", "job"),
+        ]));
+
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            widget.cycle_filter();
+            seen.push(widget.selected_story().map(|s| s.title.clone()));
+        }
+        assert_eq!(
+            seen,
+            vec![
+                Some("Ask HN: Best editor?".to_string()),
+                Some("Show HN: feedtui".to_string()),
+                Some("Acme is hiring".to_string()),
+                Some("A new database".to_string()),
+            ]
+        );
+        assert_eq!(widget.filter, HnFilter::All);
+    }
+
+    #[test]
+    fn test_jump_to_top_and_bottom() {
+        let mut widget = widget();
+        widget.update_data(FeedData::HackerNews(vec![
+            story("First", "story"),
+            story("Ask HN: Second", "story"),
+            story("Third", "story"),
+        ]));
+
+        widget.scroll_to_bottom();
+        assert_eq!(widget.selected_story().unwrap().title, "Third");
+        widget.scroll_to_top();
+        assert_eq!(widget.selected_story().unwrap().title, "First");
+
+        // The bottom of a filtered list is its last visible story
+        widget.cycle_filter();
+        widget.scroll_to_bottom();
+        assert_eq!(widget.selected_story().unwrap().title, "Ask HN: Second");
+    }
+
+    #[tokio::test]
+    async fn test_mock_fetch_fills_widget() {
+        let mut widget = widget();
+        let fetcher = MockFetcher::new(FeedData::HackerNews(vec![story("Mocked", "story")]));
+
+        let data = fetch_with_timeout(&fetcher, 0, Duration::from_secs(1)).await;
+        widget.update_data(data);
+
+        assert!(!widget.loading);
+        assert_eq!(widget.selected_story().unwrap().title, "Mocked");
+    }
+
+    #[tokio::test]
+    async fn test_failed_fetch_leaves_loading_state() {
+        let mut widget = widget();
+        assert!(widget.loading);
+
+        let data = fetch_with_timeout(&FailingFetcher, 0, Duration::from_secs(1)).await;
+        widget.update_data(data);
+
+        assert!(!widget.loading);
+        assert_eq!(widget.error.as_deref(), Some("connection refused"));
+    }
+
+    #[tokio::test]
+    async fn test_hung_fetch_times_out_into_error() {
+        let mut widget = widget();
+
+        let data = fetch_with_timeout(&HangingFetcher, 0, Duration::from_millis(10)).await;
+        widget.update_data(data);
+
+        assert!(!widget.loading);
+        assert!(widget.error.is_some());
+    }
+}
@@ -0,0 +1,31 @@
+use crate::config::{JsonPollConfig, RssConfig};
+use crate::feeds::http;
+use crate::feeds::json_poll::JsonPollFetcher;
+use crate::ui::widgets::{list::ItemSource, rss::RssWidget};
+
+/// Items polled from a JSON endpoint. They are `RssItem`s, so they are
+/// drawn by an `RssWidget` with the JSON poll fetcher.
+pub fn widget(config: JsonPollConfig) -> RssWidget {
+    let list = RssWidget::new(RssConfig {
+        title: config.title.clone(),
+        feeds: Vec::new(),
+        opml_path: None,
+        max_items: config.max_items,
+        overflow: config.overflow,
+        http: config.http.clone(),
+        refresh_secs: None,
+        position: config.position.clone(),
+    });
+
+    list.with_source(ItemSource::new("jsonpoll", move |client| {
+        http::with_client(&client, &config.http, |client| {
+            Box::new(JsonPollFetcher::new(
+                config.url.clone(),
+                config.title.clone(),
+                config.paths.clone(),
+                config.max_items,
+                client,
+            ))
+        })
+    }))
+}
@@ -0,0 +1,213 @@
+//! Shared behavior for the numbered list widgets (Hacker News, RSS, GitHub, ...)
+
+use crate::feeds::FeedFetcher;
+use ratatui::widgets::{ListItem, ListState};
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How long to wait for the next digit before a numeric jump starts over
+const NUMBER_JUMP_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Select the item at `index` if it exists in a list of `len` items
+pub fn select_index(state: &mut ListState, index: usize, len: usize) {
+    if index < len {
+        state.select(Some(index));
+    }
+}
+
+/// Select the last item of a list of `len` items, if there is one
+pub fn select_last(state: &mut ListState, len: usize) {
+    if let Some(last) = len.checked_sub(1) {
+        state.select(Some(last));
+    }
+}
+
+/// Another kind of source whose items a list widget draws as its own, e.g.
+/// JSON poll items drawn by the RSS widget. Only the id and the fetcher
+/// change, so the widget keeps every key and feature it has.
+pub struct ItemSource {
+    /// Used in the widget id in place of its own type, e.g. `jsonpoll`
+    pub id_prefix: &'static str,
+    fetcher: Box<dyn Fn(Arc<reqwest::Client>) -> Box<dyn FeedFetcher> + Send + Sync>,
+}
+
+impl ItemSource {
+    pub fn new(
+        id_prefix: &'static str,
+        fetcher: impl Fn(Arc<reqwest::Client>) -> Box<dyn FeedFetcher> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            id_prefix,
+            fetcher: Box::new(fetcher),
+        }
+    }
+
+    pub fn create_fetcher(&self, client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
+        (self.fetcher)(client)
+    }
+}
+
+/// Number of list items shown by the last render, which sets how far
+/// `PageUp`/`PageDown` move. `render` only gets `&self`, hence the atomic.
+#[derive(Debug, Default)]
+pub struct ViewHeight(AtomicU16);
+
+impl ViewHeight {
+    /// Remember how many of `items` fit in the `rows` the list is drawn
+    /// with, counting each as tall as the tallest so a page never skips
+    /// past what was on screen
+    pub fn record(&self, rows: u16, items: &[ListItem]) {
+        let item_height = items.iter().map(ListItem::height).max().unwrap_or(1);
+        let item_height = u16::try_from(item_height).unwrap_or(u16::MAX).max(1);
+        self.0.store(rows / item_height, Ordering::Relaxed);
+    }
+
+    /// Items to move per page: those that fit in the view, at least one
+    pub fn page_size(&self) -> usize {
+        usize::from(self.0.load(Ordering::Relaxed)).max(1)
+    }
+}
+
+/// Whether `new` holds an item whose key isn't in `old`. An empty `old` is
+/// treated as the first load, which doesn't count as items arriving.
+pub fn has_new_items<T, K: PartialEq>(old: &[T], new: &[T], key: impl Fn(&T) -> K) -> bool {
+    if old.is_empty() {
+        return false;
+    }
+    let old_keys: Vec<K> = old.iter().map(&key).collect();
+    new.iter().any(|item| !old_keys.contains(&key(item)))
+}
+
+/// Accumulates digit key presses into a 1-based item number, so typing `1`
+/// then `2` in quick succession jumps to item 12 rather than item 2.
+#[derive(Debug, Default)]
+pub struct NumberJump {
+    number: usize,
+    last_input: Option<Instant>,
+}
+
+impl NumberJump {
+    /// Feed a digit and return the 0-based index it now points at, if any
+    pub fn push(&mut self, digit: u32, now: Instant) -> Option<usize> {
+        let expired = self
+            .last_input
+            .is_none_or(|last| now.duration_since(last) > NUMBER_JUMP_TIMEOUT);
+        if expired {
+            self.number = 0;
+        }
+
+        self.number = self
+            .number
+            .saturating_mul(10)
+            .saturating_add(digit as usize);
+        self.last_input = Some(now);
+
+        self.number.checked_sub(1)
+    }
+
+    /// Forget any partially typed number
+    pub fn reset(&mut self) {
+        self.number = 0;
+        self.last_input = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RssConfig;
+    use crate::feeds::FeedData;
+    use crate::ui::widgets::{rss::RssWidget, FeedWidget};
+    use async_trait::async_trait;
+
+    struct StubFetcher;
+
+    #[async_trait]
+    impl FeedFetcher for StubFetcher {
+        fn source_name(&self) -> &'static str {
+            "stub"
+        }
+
+        async fn fetch(&self) -> anyhow::Result<FeedData> {
+            Ok(FeedData::Loading)
+        }
+    }
+
+    #[test]
+    fn test_item_source_sets_id_and_fetcher() {
+        let config: RssConfig = toml::from_str("position = { row = 1, col = 2 }").unwrap();
+        let mut widget =
+            RssWidget::new(config).with_source(ItemSource::new("stub", |_| Box::new(StubFetcher)));
+        assert_eq!(widget.id(), "stub-1-2");
+        widget.set_position(0, 1);
+        assert_eq!(widget.id(), "stub-0-1");
+
+        let fetcher = widget.create_fetcher(Arc::new(reqwest::Client::new()));
+        assert_eq!(fetcher.source_name(), "stub");
+    }
+
+    #[test]
+    fn test_page_size_follows_last_render() {
+        let height = ViewHeight::default();
+        assert_eq!(height.page_size(), 1);
+
+        let one_line = [ListItem::new("a"), ListItem::new("b")];
+        height.record(12, &one_line);
+        assert_eq!(height.page_size(), 12);
+
+        // Two-line items: half as many fit
+        let two_lines = [ListItem::new("title\nmeta"), ListItem::new("c")];
+        height.record(12, &two_lines);
+        assert_eq!(height.page_size(), 6);
+
+        height.record(0, &two_lines);
+        assert_eq!(height.page_size(), 1);
+    }
+
+    #[test]
+    fn test_single_digit_selects_item() {
+        let mut jump = NumberJump::default();
+        assert_eq!(jump.push(3, Instant::now()), Some(2));
+    }
+
+    #[test]
+    fn test_digits_accumulate_within_timeout() {
+        let mut jump = NumberJump::default();
+        let start = Instant::now();
+        assert_eq!(jump.push(1, start), Some(0));
+        assert_eq!(jump.push(2, start + Duration::from_millis(300)), Some(11));
+    }
+
+    #[test]
+    fn test_digits_restart_after_timeout() {
+        let mut jump = NumberJump::default();
+        let start = Instant::now();
+        jump.push(1, start);
+        assert_eq!(jump.push(2, start + Duration::from_secs(2)), Some(1));
+    }
+
+    #[test]
+    fn test_zero_selects_nothing() {
+        let mut jump = NumberJump::default();
+        assert_eq!(jump.push(0, Instant::now()), None);
+    }
+
+    #[test]
+    fn test_has_new_items() {
+        assert!(!has_new_items(&[], &[1, 2], |i| *i));
+        assert!(!has_new_items(&[1, 2], &[2, 1], |i| *i));
+        assert!(!has_new_items(&[1, 2], &[1], |i| *i));
+        assert!(has_new_items(&[1, 2], &[3, 1], |i| *i));
+    }
+
+    #[test]
+    fn test_select_index_ignores_out_of_range() {
+        let mut state = ListState::default();
+        state.select(Some(0));
+        select_index(&mut state, 5, 3);
+        assert_eq!(state.selected(), Some(0));
+        select_index(&mut state, 2, 3);
+        assert_eq!(state.selected(), Some(2));
+    }
+}
@@ -0,0 +1,228 @@
+use crate::config::MastodonConfig;
+use crate::feeds::http;
+use crate::feeds::mastodon::MastodonFetcher;
+use crate::feeds::{FeedData, FeedFetcher, Toot};
+use crate::ui::spinner::Spinner;
+use crate::ui::text::fit_line;
+use crate::ui::widgets::{list, FeedWidget, SelectedItem};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+use std::sync::Arc;
+
+pub struct MastodonWidget {
+    config: MastodonConfig,
+    toots: Vec<Toot>,
+    loading: bool,
+    error: Option<String>,
+    scroll_state: ListState,
+    view_height: list::ViewHeight,
+    selected: bool,
+    has_new_items: bool,
+}
+
+impl MastodonWidget {
+    pub fn new(config: MastodonConfig) -> Self {
+        let mut scroll_state = ListState::default();
+        scroll_state.select(Some(0));
+
+        Self {
+            config,
+            toots: Vec::new(),
+            loading: true,
+            error: None,
+            scroll_state,
+            view_height: list::ViewHeight::default(),
+            selected: false,
+            has_new_items: false,
+        }
+    }
+}
+
+impl FeedWidget for MastodonWidget {
+    fn id(&self) -> String {
+        format!(
+            "mastodon-{}-{}",
+            self.config.position.row, self.config.position.col
+        )
+    }
+
+    fn title(&self) -> &str {
+        &self.config.title
+    }
+
+    fn position(&self) -> (usize, usize) {
+        (self.config.position.row, self.config.position.col)
+    }
+
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+        let border_style = if selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        let block = Block::default()
+            .title(format!(" {} ", self.config.title))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        if self.loading && self.toots.is_empty() {
+            let loading_text =
+                List::new(vec![ListItem::new(Spinner::text("Loading..."))]).block(block);
+            frame.render_widget(loading_text, area);
+            return;
+        }
+
+        if let Some(ref error) = self.error {
+            let error_text =
+                List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+            frame.render_widget(error_text, area);
+            return;
+        }
+
+        let width = area.width.saturating_sub(2) as usize;
+        let items: Vec<ListItem> = self
+            .toots
+            .iter()
+            .map(|toot| {
+                let mut header = vec![
+                    Span::styled(
+                        &toot.author,
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("  ★ {}  ⟲ {}", toot.favourites, toot.reblogs),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ];
+                if let Some(booster) = &toot.boosted_by {
+                    header.push(Span::styled(
+                        format!("  boosted by {}", booster),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+
+                let content_line = Line::from(Span::styled(
+                    &toot.content,
+                    Style::default().fg(Color::White),
+                ));
+
+                let mut lines = fit_line(Line::from(header), width, self.config.overflow);
+                lines.extend(fit_line(content_line, width, self.config.overflow));
+                ListItem::new(lines)
+            })
+            .collect();
+
+        self.view_height
+            .record(area.height.saturating_sub(2), &items);
+        let list = List::new(items).block(block).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let mut state = self.scroll_state.clone();
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn update_data(&mut self, data: FeedData) {
+        self.loading = false;
+        match data {
+            FeedData::Mastodon(toots) => {
+                self.has_new_items |= list::has_new_items(&self.toots, &toots, |t| t.id.clone());
+                self.toots = toots;
+                self.error = None;
+            }
+            FeedData::Error(e) => {
+                self.error = Some(e);
+            }
+            FeedData::Loading => {
+                self.loading = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn create_fetcher(&self, client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
+        http::with_client(&client, &self.config.http, |client| {
+            Box::new(MastodonFetcher::new(
+                self.config.instance.clone(),
+                self.config.access_token.clone(),
+                self.config.max_items,
+                client,
+            ))
+        })
+    }
+
+    fn scroll_up(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected > 0 {
+                self.scroll_state.select(Some(selected - 1));
+            }
+        }
+    }
+
+    fn scroll_down(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected < self.toots.len().saturating_sub(1) {
+                self.scroll_state.select(Some(selected + 1));
+            }
+        }
+    }
+
+    fn scroll_to_top(&mut self) {
+        self.scroll_state.select(Some(0));
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        list::select_last(&mut self.scroll_state, self.toots.len());
+    }
+
+    fn page_size(&self) -> usize {
+        self.view_height.page_size()
+    }
+
+    fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        vec![("Enter", "Read"), ("o", "Open"), ("y", "Copy URL")]
+    }
+
+    fn take_new_items(&mut self) -> bool {
+        std::mem::take(&mut self.has_new_items)
+    }
+
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        let idx = self.scroll_state.selected()?;
+        let toot = self.toots.get(idx)?;
+
+        Some(SelectedItem {
+            title: toot.author.clone(),
+            url: Some(toot.url.clone()),
+            description: Some(toot.content.clone()),
+            source: "Mastodon".to_string(),
+            metadata: Some(format!(
+                "{} favourites | {} boosts",
+                toot.favourites, toot.reblogs
+            )),
+        })
+    }
+
+    fn get_selected_discussion_url(&self) -> Option<String> {
+        None
+    }
+}
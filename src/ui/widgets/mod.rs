@@ -1,17 +1,34 @@
+pub mod arxiv;
+pub mod bluesky;
+pub mod calendar;
 pub mod clock;
+pub mod command;
 pub mod creature;
+pub mod crypto;
 pub mod github;
+pub mod gitlab;
 pub mod hackernews;
+pub mod json_poll;
+pub mod list;
+pub mod mastodon;
+pub mod news;
 pub mod pixelart;
+pub mod podcast;
+pub mod reddit;
+pub mod releases;
 pub mod rss;
 pub mod sports;
 pub mod stocks;
+pub mod timeline;
 pub mod twitter;
+pub mod weather;
 pub mod youtube;
 
 use crate::feeds::{FeedData, FeedFetcher};
+use crate::ui::theme::Palette;
 use ratatui::{layout::Rect, Frame};
 use std::any::Any;
+use std::sync::Arc;
 
 /// Information about a selected feed item for reading or opening
 #[derive(Debug, Clone)]
@@ -25,16 +42,76 @@ pub struct SelectedItem {
 
 pub trait FeedWidget: Send + Sync {
     fn id(&self) -> String;
-    #[allow(dead_code)]
     fn title(&self) -> &str;
     fn position(&self) -> (usize, usize);
+    /// Move the widget to another grid cell. Ids are built from positions,
+    /// so the id changes with it.
+    fn set_position(&mut self, row: usize, col: usize);
     fn render(&self, frame: &mut Frame, area: Rect, selected: bool);
     fn update_data(&mut self, data: FeedData);
-    fn create_fetcher(&self) -> Box<dyn FeedFetcher>;
+    fn create_fetcher(&self, _client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher>;
     fn scroll_up(&mut self);
     fn scroll_down(&mut self);
     fn set_selected(&mut self, selected: bool);
 
+    /// Colors to draw with. Widgets that don't take a palette yet keep
+    /// their built-in colors.
+    fn set_palette(&mut self, _palette: Palette) {}
+
+    /// Select the item at a 0-based index; numbered lists display it as `index + 1`
+    fn select_index(&mut self, _index: usize) {}
+
+    /// Jump to the first item (`g`)
+    fn scroll_to_top(&mut self) {
+        self.select_index(0);
+    }
+
+    /// Jump to the last item (`G`). Widgets without a list ignore it.
+    fn scroll_to_bottom(&mut self) {}
+
+    /// How many items `PageUp`/`PageDown` move by, normally the rows the
+    /// list showed when it was last rendered
+    fn page_size(&self) -> usize {
+        1
+    }
+
+    /// Move the selection up a page, stopping at the first item
+    fn page_up(&mut self) {
+        for _ in 0..self.page_size() {
+            self.scroll_up();
+        }
+    }
+
+    /// Move the selection down a page, stopping at the last item
+    fn page_down(&mut self) {
+        for _ in 0..self.page_size() {
+            self.scroll_down();
+        }
+    }
+
+    /// Whether the last update brought items that weren't shown before.
+    /// Reading the flag clears it.
+    fn take_new_items(&mut self) -> bool {
+        false
+    }
+
+    /// Alert messages raised by the last update. Reading them clears them.
+    fn take_alerts(&mut self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Keys specific to this widget and what they do, e.g. `("t", "Compose")`,
+    /// shown in the bar at the bottom of the screen while it is focused
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    /// Full text of the error the widget is showing, if any, for the
+    /// details view opened with Enter
+    fn error_detail(&self) -> Option<&str> {
+        None
+    }
+
     /// Get the currently selected item's information
     fn get_selected_item(&self) -> Option<SelectedItem> {
         None
@@ -49,6 +126,20 @@ pub trait FeedWidget: Send + Sync {
     fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
         None
     }
-    #[allow(dead_code)]
     fn get_selected_discussion_url(&self) -> Option<String>;
+
+    /// URL opened in the browser for the selected item: its own link,
+    /// falling back to the discussion page.
+    fn open_selected_url(&self) -> Option<String> {
+        self.get_selected_item()
+            .and_then(|item| item.url)
+            .or_else(|| self.get_selected_discussion_url())
+    }
+
+    /// URL copied to the clipboard for the selected item. Defaults to the
+    /// discussion page, falling back to the item's own link.
+    fn copy_selected_url(&self) -> Option<String> {
+        self.get_selected_discussion_url()
+            .or_else(|| self.get_selected_item().and_then(|item| item.url))
+    }
 }
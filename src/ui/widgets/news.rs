@@ -0,0 +1,48 @@
+use crate::config::{NewsConfig, RssConfig};
+use crate::feeds::composite::CompositeFetcher;
+use crate::feeds::hackernews::HnFetcher;
+use crate::feeds::http;
+use crate::feeds::rss::RssFetcher;
+use crate::feeds::FeedFetcher;
+use crate::ui::widgets::{list::ItemSource, rss::RssWidget};
+
+/// Hacker News and any number of feeds merged into one newest-first list.
+/// Merged items arrive as `RssItem`s tagged with their source, so they are
+/// drawn by an `RssWidget` with a composite fetcher.
+pub fn widget(config: NewsConfig) -> RssWidget {
+    let list = RssWidget::new(RssConfig {
+        title: config.title.clone(),
+        feeds: Vec::new(),
+        opml_path: None,
+        max_items: config.max_items,
+        overflow: config.overflow,
+        http: config.http.clone(),
+        refresh_secs: None,
+        position: config.position.clone(),
+    });
+
+    list.with_source(ItemSource::new("news", move |client| {
+        let mut fetchers: Vec<Box<dyn FeedFetcher>> = Vec::new();
+        if let Some(story_type) = &config.hackernews {
+            fetchers.push(Box::new(HnFetcher::new(
+                story_type.clone(),
+                config.story_count,
+                client.clone(),
+            )));
+        }
+        // The http options apply to the feeds; Hacker News keeps the
+        // shared client
+        http::with_client(&client, &config.http, |feed_client| {
+            // One fetcher per feed, so each is fetched alongside the others
+            for feed in &config.feeds {
+                fetchers.push(Box::new(RssFetcher::new(
+                    vec![feed.clone()],
+                    None,
+                    config.max_items,
+                    feed_client.clone(),
+                )));
+            }
+            Box::new(CompositeFetcher::new(fetchers, config.max_items))
+        })
+    }))
+}
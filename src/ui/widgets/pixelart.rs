@@ -1,7 +1,11 @@
 use crate::config::PixelArtConfig;
+use crate::feeds::pixelart::{
+    load_source, resize_frames, target_dimensions, PixelArtFetcher, PixelColor, PixelData,
+    PixelFrames, SourceFrames,
+};
 use crate::feeds::{FeedData, FeedFetcher};
+use crate::ui::spinner::Spinner;
 use crate::ui::widgets::FeedWidget;
-use async_trait::async_trait;
 use ratatui::{
     layout::{Alignment, Rect},
     style::{Color, Style},
@@ -10,191 +14,409 @@ use ratatui::{
     Frame,
 };
 use std::any::Any;
+use std::ops::Range;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// How image pixels are drawn into terminal cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelRenderMode {
+    /// One colored `█` per pixel; needs a truecolor terminal
+    Truecolor,
+    /// `▀` with the top pixel as foreground and the bottom pixel as
+    /// background, fitting two pixel rows into each terminal row
+    HalfBlock,
+    /// Colorless shading characters from each pixel's brightness
+    Ascii,
+}
+
+/// Pixel sizes `+`/`-` and auto-fit choose between
+const MIN_PIXEL_SIZE: u32 = 8;
+const MAX_PIXEL_SIZE: u32 = 128;
+
+/// Rows above the image: the metadata line and a blank line
+const HEADER_HEIGHT: u16 = 2;
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct PixelArtWidget {
-    id: String,
     title: String,
     position: (usize, usize),
     selected: bool,
     image_path: Option<PathBuf>,
-    pixel_data: Option<PixelData>,
+    /// The image at full resolution, decoded by the fetcher so pixel size
+    /// changes only resize it
+    source: Option<Arc<SourceFrames>>,
+    /// Every frame of the image; static images have exactly one
+    frames: PixelFrames,
+    current_frame: usize,
+    /// When the current frame was first shown
+    frame_started: Instant,
     pixel_size: u32,
+    /// `pixel_size` from the config, restored when auto-fit is turned off
+    configured_pixel_size: u32,
+    /// Size the image to the widget instead of using `pixel_size` as set
+    auto_fit: bool,
+    /// Columns and rows available to the image in the last render.
+    /// `render` only gets `&self`, hence the atomics.
+    view_width: AtomicU16,
+    view_height: AtomicU16,
+    /// View size `pixel_size` was last fitted to
+    fitted_to: Option<(u16, u16)>,
     error_message: Option<String>,
     scroll_offset: usize,
+    /// First pixel column shown, for images wider than the widget
+    horizontal_offset: usize,
+    render_mode: PixelRenderMode,
+    correct_aspect: bool,
+    /// Dither the ASCII render mode
+    dither: bool,
+    /// The initial decode is still running in the background
+    loading: bool,
+    /// Settings changed while the initial decode was running, so the image
+    /// it delivers must be resized again
+    stale_frames: bool,
 }
 
-#[derive(Debug, Clone)]
-struct PixelData {
-    pixels: Vec<Vec<PixelColor>>,
-    width: u32,
-    height: u32,
-    original_width: u32,
-    original_height: u32,
+fn pixel_color(pixel: PixelColor) -> Color {
+    Color::Rgb(pixel.r, pixel.g, pixel.b)
 }
 
-#[derive(Debug, Clone, Copy)]
-struct PixelColor {
-    r: u8,
-    g: u8,
-    b: u8,
+/// Shading characters from empty to full, for ASCII mode
+const SHADES: [&str; 5] = [" ", "░", "▒", "▓", "█"];
+
+fn block_char(pixel: PixelColor) -> &'static str {
+    let gray = pixel.grayscale();
+    match gray {
+        0..=31 => SHADES[0],
+        32..=63 => SHADES[1],
+        64..=95 => SHADES[2],
+        96..=127 => SHADES[3],
+        128..=255 => SHADES[4],
+    }
 }
 
-impl PixelColor {
-    fn to_ratatui_color(self) -> Color {
-        Color::Rgb(self.r, self.g, self.b)
-    }
-
-    #[allow(dead_code)] // Preserved for future ASCII art mode
-    fn grayscale(&self) -> u8 {
-        // Standard luminance calculation
-        ((0.299 * self.r as f64) + (0.587 * self.g as f64) + (0.114 * self.b as f64)) as u8
-    }
-
-    #[allow(dead_code)] // Preserved for future ASCII art mode
-    fn to_block_char(self) -> &'static str {
-        let gray = self.grayscale();
-        match gray {
-            0..=31 => " ",
-            32..=63 => "░",
-            64..=95 => "▒",
-            96..=127 => "▓",
-            128..=159 => "█",
-            160..=191 => "█",
-            192..=223 => "█",
-            224..=255 => "█",
+/// Floyd–Steinberg dither an image's brightness onto `SHADES`, giving the
+/// shade index of every pixel. Each pixel's rounding error is passed on to
+/// its right and lower neighbours, so areas between two shades mix them.
+fn dither_shades(data: &PixelData) -> Vec<Vec<usize>> {
+    let levels = (SHADES.len() - 1) as f32;
+    let mut gray: Vec<Vec<f32>> = data
+        .pixels
+        .iter()
+        .map(|row| row.iter().map(|p| p.grayscale() as f32 / 255.0).collect())
+        .collect();
+
+    let mut shades = Vec::with_capacity(gray.len());
+    for y in 0..gray.len() {
+        let mut row = Vec::with_capacity(gray[y].len());
+        for x in 0..gray[y].len() {
+            let value = gray[y][x];
+            let shade = (value.clamp(0.0, 1.0) * levels).round();
+            row.push(shade as usize);
+
+            let error = value - shade / levels;
+            let mut spread = |x: Option<usize>, y: usize, weight: f32| {
+                if let Some(cell) = x.and_then(|x| gray.get_mut(y)?.get_mut(x)) {
+                    *cell += error * weight;
+                }
+            };
+            spread(Some(x + 1), y, 7.0 / 16.0);
+            spread(x.checked_sub(1), y + 1, 3.0 / 16.0);
+            spread(Some(x), y + 1, 5.0 / 16.0);
+            spread(Some(x + 1), y + 1, 1.0 / 16.0);
         }
+        shades.push(row);
     }
+    shades
+}
+
+/// ASCII rows `rows` of an image dithered with `dither_shades`, keeping only
+/// the pixel columns `cols`
+fn dithered_lines(data: &PixelData, rows: Range<usize>, cols: Range<usize>) -> Vec<Line<'static>> {
+    let shades = dither_shades(data);
+    rows.map(|y| {
+        let row = &shades[y];
+        row[cols.start.min(row.len())..cols.end.min(row.len())]
+            .iter()
+            .map(|&shade| SHADES[shade])
+            .collect::<String>()
+            .into()
+    })
+    .collect()
+}
+
+/// Number of terminal rows an image takes when drawn in `mode`
+pub fn line_count(data: &PixelData, mode: PixelRenderMode) -> usize {
+    match mode {
+        PixelRenderMode::HalfBlock => data.pixels.len().div_ceil(2),
+        _ => data.pixels.len(),
+    }
+}
+
+/// Draw the terminal rows `rows` of an image (see `line_count`), keeping
+/// only the pixel columns `cols`
+pub fn pixel_lines(
+    data: &PixelData,
+    mode: PixelRenderMode,
+    rows: Range<usize>,
+    cols: Range<usize>,
+) -> Vec<Line<'static>> {
+    let slice = |row: &[PixelColor]| -> Vec<PixelColor> {
+        row.get(cols.start.min(row.len())..cols.end.min(row.len()))
+            .unwrap_or_default()
+            .to_vec()
+    };
+    rows.map(|line_idx| match mode {
+        // Use colored blocks for truecolor support
+        PixelRenderMode::Truecolor => Line::from(
+            slice(&data.pixels[line_idx])
+                .into_iter()
+                .map(|pixel| Span::styled("█", Style::default().fg(pixel_color(pixel))))
+                .collect::<Vec<_>>(),
+        ),
+        PixelRenderMode::HalfBlock => {
+            let top = slice(&data.pixels[line_idx * 2]);
+            let bottom = data.pixels.get(line_idx * 2 + 1).map(|row| slice(row));
+            Line::from(
+                top.iter()
+                    .enumerate()
+                    .map(|(x, pixel)| {
+                        let bg = bottom
+                            .as_ref()
+                            .and_then(|row| row.get(x))
+                            .map(|p| pixel_color(*p))
+                            .unwrap_or(Color::Reset);
+                        Span::styled("▀", Style::default().fg(pixel_color(*pixel)).bg(bg))
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        }
+        PixelRenderMode::Ascii => Line::from(
+            slice(&data.pixels[line_idx])
+                .into_iter()
+                .map(block_char)
+                .collect::<String>(),
+        ),
+    })
+    .collect()
 }
 
 impl PixelArtWidget {
     pub fn new(config: PixelArtConfig) -> Self {
-        let pixel_data = if let Some(ref path) = config.image_path {
-            Self::load_image_sync(path, config.pixel_size.unwrap_or(32)).ok()
-        } else {
-            None
-        };
+        let loading = config.image_path.is_some();
+        let pixel_size = config.pixel_size.unwrap_or(32);
 
         Self {
-            id: format!("pixelart-{}-{}", config.position.row, config.position.col),
             title: config.title,
             position: (config.position.row, config.position.col),
             selected: false,
             image_path: config.image_path,
-            pixel_data,
-            pixel_size: config.pixel_size.unwrap_or(32),
+            source: None,
+            frames: Vec::new(),
+            current_frame: 0,
+            frame_started: Instant::now(),
+            pixel_size,
+            configured_pixel_size: pixel_size,
+            auto_fit: config.auto_fit,
+            view_width: AtomicU16::new(0),
+            view_height: AtomicU16::new(0),
+            fitted_to: None,
             error_message: None,
             scroll_offset: 0,
+            horizontal_offset: 0,
+            render_mode: PixelRenderMode::Truecolor,
+            correct_aspect: config.correct_aspect,
+            dither: config.dither,
+            loading,
+            stale_frames: false,
         }
     }
 
     #[allow(dead_code)] // Preserved for dynamic image loading
     pub fn set_image_path(&mut self, path: PathBuf) {
-        self.image_path = Some(path.clone());
         self.error_message = None;
 
-        match Self::load_image_sync(&path, self.pixel_size) {
-            Ok(data) => {
-                self.pixel_data = Some(data);
+        match load_source(&path) {
+            Ok(source) => {
+                self.source = Some(Arc::new(source));
+                self.reload_image();
                 self.scroll_offset = 0;
+                self.horizontal_offset = 0;
             }
             Err(e) => {
                 self.error_message = Some(format!("Error loading image: {}", e));
-                self.pixel_data = None;
+                self.source = None;
+                self.set_frames(Vec::new());
             }
         }
+        self.image_path = Some(path);
     }
 
-    pub fn increase_pixel_size(&mut self) {
-        if self.pixel_size < 128 {
-            self.pixel_size *= 2;
+    /// Replace the decoded frames, staying on the same frame of an
+    /// animation when it still exists
+    fn set_frames(&mut self, frames: PixelFrames) {
+        if self.current_frame >= frames.len() {
+            self.current_frame = 0;
+        }
+        self.frames = frames;
+        self.frame_started = Instant::now();
+    }
+
+    fn current(&self) -> Option<&PixelData> {
+        self.frames.get(self.current_frame).map(|(data, _)| data)
+    }
+
+    /// Advance an animated image once the current frame has been shown for
+    /// its delay, and refit an auto-fitted image to a resized widget.
+    /// Called on every tick of the event loop.
+    pub fn tick(&mut self) {
+        if self.auto_fit {
+            self.fit_to_view();
+        }
+        if self.frames.len() < 2 {
+            return;
+        }
+        let delay = self.frames[self.current_frame].1;
+        if self.frame_started.elapsed() >= delay {
+            self.current_frame = (self.current_frame + 1) % self.frames.len();
+            self.frame_started = Instant::now();
+        }
+    }
+
+    /// Cycle between truecolor blocks, half blocks and grayscale ASCII
+    pub fn toggle_render_mode(&mut self) {
+        let had_correction = self.aspect_correction();
+        self.render_mode = match self.render_mode {
+            PixelRenderMode::Truecolor => PixelRenderMode::HalfBlock,
+            PixelRenderMode::HalfBlock => PixelRenderMode::Ascii,
+            PixelRenderMode::Ascii => PixelRenderMode::Truecolor,
+        };
+        self.scroll_offset = 0;
+        self.horizontal_offset = 0;
+        // Half blocks fit twice the rows, so the best size changes too
+        self.fitted_to = None;
+        if had_correction != self.aspect_correction() {
             self.reload_image();
         }
     }
 
-    pub fn decrease_pixel_size(&mut self) {
-        if self.pixel_size > 8 {
-            self.pixel_size /= 2;
+    /// Switch between sizing the image to the widget and the configured
+    /// pixel size
+    pub fn toggle_auto_fit(&mut self) {
+        self.auto_fit = !self.auto_fit;
+        self.fitted_to = None;
+        if !self.auto_fit && self.pixel_size != self.configured_pixel_size {
+            self.pixel_size = self.configured_pixel_size;
             self.reload_image();
         }
     }
 
-    fn reload_image(&mut self) {
-        if let Some(ref path) = self.image_path {
-            match Self::load_image_sync(path, self.pixel_size) {
-                Ok(data) => {
-                    self.pixel_data = Some(data);
-                    self.error_message = None;
-                }
-                Err(e) => {
-                    self.error_message = Some(format!("Error reloading image: {}", e));
-                }
-            }
+    /// Largest pixel size at which an image of the given size fits in
+    /// `width` columns and `height` rows, or the smallest size if none does
+    fn fitting_pixel_size(&self, original: (u32, u32), width: u16, height: u16) -> u32 {
+        (MIN_PIXEL_SIZE..=MAX_PIXEL_SIZE)
+            .rev()
+            .find(|&size| {
+                let (w, h) =
+                    target_dimensions(original.0, original.1, size, self.aspect_correction());
+                let rows = match self.render_mode {
+                    PixelRenderMode::HalfBlock => h.div_ceil(2),
+                    _ => h,
+                };
+                w <= u32::from(width) && rows <= u32::from(height)
+            })
+            .unwrap_or(MIN_PIXEL_SIZE)
+    }
+
+    /// Resize the image to the view recorded by the last render, if the view
+    /// changed since it was last fitted
+    fn fit_to_view(&mut self) {
+        let view = (
+            self.view_width.load(Ordering::Relaxed),
+            self.view_height.load(Ordering::Relaxed),
+        );
+        if view.0 == 0 || view.1 == 0 || self.fitted_to == Some(view) {
+            return;
+        }
+        let Some(data) = self.current() else {
+            return;
+        };
+        let original = (data.original_width, data.original_height);
+
+        self.fitted_to = Some(view);
+        let size = self.fitting_pixel_size(original, view.0, view.1);
+        if size != self.pixel_size {
+            self.pixel_size = size;
+            self.scroll_offset = 0;
+            self.horizontal_offset = 0;
+            self.reload_image();
         }
     }
 
-    fn load_image_sync(path: &PathBuf, target_size: u32) -> anyhow::Result<PixelData> {
-        // Read and decode image
-        let img_bytes = std::fs::read(path)?;
-        let img = image::load_from_memory(&img_bytes)?;
+    /// Half blocks already draw roughly square pixels, so the tall-cell
+    /// correction only applies to the one-pixel-per-cell modes
+    fn aspect_correction(&self) -> bool {
+        self.correct_aspect && self.render_mode != PixelRenderMode::HalfBlock
+    }
 
-        let original_width = img.width();
-        let original_height = img.height();
+    /// Number of terminal rows the image takes in the current render mode
+    fn line_count(&self, data: &PixelData) -> usize {
+        line_count(data, self.render_mode)
+    }
 
-        // Calculate aspect-preserving dimensions
-        let (new_width, new_height) = if original_width > original_height {
-            let ratio = original_height as f64 / original_width as f64;
-            (target_size, (target_size as f64 * ratio) as u32)
-        } else {
-            let ratio = original_width as f64 / original_height as f64;
-            ((target_size as f64 * ratio) as u32, target_size)
-        };
+    /// Pan a wide image one column to the left
+    pub fn scroll_left(&mut self) {
+        self.horizontal_offset = self.horizontal_offset.saturating_sub(1);
+    }
 
-        // Resize using nearest neighbor for pixel art effect
-        let resized = img.resize_exact(new_width, new_height, image::imageops::FilterType::Nearest);
-
-        // Convert to RGB
-        let rgb_img = resized.to_rgb8();
-
-        // Extract pixel data
-        let mut pixels = Vec::new();
-        for y in 0..new_height {
-            let mut row = Vec::new();
-            for x in 0..new_width {
-                let pixel = rgb_img.get_pixel(x, y);
-                row.push(PixelColor {
-                    r: pixel[0],
-                    g: pixel[1],
-                    b: pixel[2],
-                });
+    /// Pan a wide image one column to the right, stopping once its last
+    /// column is in view
+    pub fn scroll_right(&mut self) {
+        if let Some(data) = self.current() {
+            let view_width = self.view_width.load(Ordering::Relaxed) as usize;
+            let max_offset = (data.width as usize).saturating_sub(view_width);
+            if self.horizontal_offset < max_offset {
+                self.horizontal_offset += 1;
             }
-            pixels.push(row);
         }
+    }
 
-        Ok(PixelData {
-            pixels,
-            width: new_width,
-            height: new_height,
-            original_width,
-            original_height,
-        })
+    /// Setting the size by hand turns auto-fit off
+    pub fn increase_pixel_size(&mut self) {
+        self.auto_fit = false;
+        if self.pixel_size < MAX_PIXEL_SIZE {
+            self.pixel_size = (self.pixel_size * 2).min(MAX_PIXEL_SIZE);
+            self.reload_image();
+        }
     }
-}
 
-struct PixelArtFetcher;
+    pub fn decrease_pixel_size(&mut self) {
+        self.auto_fit = false;
+        if self.pixel_size > MIN_PIXEL_SIZE {
+            self.pixel_size = (self.pixel_size / 2).max(MIN_PIXEL_SIZE);
+            self.reload_image();
+        }
+    }
 
-#[async_trait]
-impl FeedFetcher for PixelArtFetcher {
-    async fn fetch(&self) -> anyhow::Result<FeedData> {
-        // Pixel art widget doesn't fetch data
-        Ok(FeedData::Loading)
+    /// Resize the decoded image to the current settings. Until the fetcher
+    /// has decoded it, the new settings are applied once it arrives.
+    fn reload_image(&mut self) {
+        match &self.source {
+            Some(source) => {
+                let frames = resize_frames(source, self.pixel_size, self.aspect_correction());
+                self.set_frames(frames);
+                self.error_message = None;
+            }
+            None => self.stale_frames = true,
+        }
     }
 }
 
 impl FeedWidget for PixelArtWidget {
     fn id(&self) -> String {
-        self.id.clone()
+        format!("pixelart-{}-{}", self.position.0, self.position.1)
     }
 
     fn title(&self) -> &str {
@@ -205,6 +427,10 @@ impl FeedWidget for PixelArtWidget {
         self.position
     }
 
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.position = (row, col);
+    }
+
     fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
             Style::default().fg(Color::Cyan)
@@ -219,6 +445,11 @@ impl FeedWidget for PixelArtWidget {
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
+        self.view_width.store(inner.width, Ordering::Relaxed);
+        self.view_height.store(
+            inner.height.saturating_sub(HEADER_HEIGHT),
+            Ordering::Relaxed,
+        );
 
         if let Some(error) = &self.error_message {
             let error_text = vec![
@@ -230,19 +461,46 @@ impl FeedWidget for PixelArtWidget {
             return;
         }
 
-        if let Some(data) = &self.pixel_data {
+        if let Some(data) = self.current() {
             self.render_pixel_art(frame, inner, data);
+        } else if self.loading {
+            let paragraph = Paragraph::new(vec![
+                Line::from(""),
+                Line::from(Spinner::text("Loading image...")),
+            ])
+            .alignment(Alignment::Center);
+            frame.render_widget(paragraph, inner);
         } else {
             self.render_help(frame, inner);
         }
     }
 
-    fn update_data(&mut self, _data: FeedData) {
-        // Pixel art widget doesn't use feed data
+    fn update_data(&mut self, data: FeedData) {
+        match data {
+            FeedData::PixelArt(image) if self.source.is_none() => {
+                self.source = Some(image.source);
+                self.set_frames(image.frames);
+                self.error_message = None;
+                self.loading = false;
+                // Keys pressed while it was decoding changed the size
+                if std::mem::take(&mut self.stale_frames) {
+                    self.reload_image();
+                }
+            }
+            FeedData::Error(e) => {
+                self.error_message = Some(format!("Error loading image: {}", e));
+                self.loading = false;
+            }
+            _ => {}
+        }
     }
 
-    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
-        Box::new(PixelArtFetcher)
+    fn create_fetcher(&self, _client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
+        Box::new(PixelArtFetcher::new(
+            self.image_path.clone(),
+            self.pixel_size,
+            self.aspect_correction(),
+        ))
     }
 
     fn scroll_up(&mut self) {
@@ -252,8 +510,8 @@ impl FeedWidget for PixelArtWidget {
     }
 
     fn scroll_down(&mut self) {
-        if let Some(data) = &self.pixel_data {
-            if self.scroll_offset < data.height as usize {
+        if let Some(data) = self.current() {
+            if self.scroll_offset < self.line_count(data) {
                 self.scroll_offset += 1;
             }
         }
@@ -263,6 +521,15 @@ impl FeedWidget for PixelArtWidget {
         self.selected = selected;
     }
 
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        vec![
+            ("+/-", "Pixel size"),
+            ("w", "Fit"),
+            ("←/→", "Pan"),
+            ("a", "Render mode"),
+        ]
+    }
+
     fn as_any(&self) -> Option<&dyn Any> {
         Some(self)
     }
@@ -299,7 +566,10 @@ impl PixelArtWidget {
             Line::from("Keybindings (when selected):"),
             Line::from("  + : Increase pixel size"),
             Line::from("  - : Decrease pixel size"),
+            Line::from("  w : Fit image to widget"),
+            Line::from("  a : Cycle color / half-block / ASCII"),
             Line::from("  ↑↓: Scroll image"),
+            Line::from("  ←→: Pan wide images"),
         ];
 
         let paragraph = Paragraph::new(help_lines).alignment(Alignment::Center);
@@ -322,40 +592,56 @@ impl PixelArtWidget {
             Span::raw("  "),
             Span::styled("Pixel size: ", Style::default().fg(Color::Gray)),
             Span::styled(
-                format!("{}", self.pixel_size),
+                if self.auto_fit {
+                    format!("{} (fit)", self.pixel_size)
+                } else {
+                    format!("{}", self.pixel_size)
+                },
                 Style::default().fg(Color::White),
             ),
         ]));
         lines.push(Line::from(""));
 
         // Calculate visible rows based on available space
-        let header_height = 2; // metadata + blank line
-        let max_visible_rows =
-            (area.height.saturating_sub(header_height) as usize).min(data.height as usize);
+        let total_rows = self.line_count(data);
+        let max_visible_rows = (area.height.saturating_sub(HEADER_HEIGHT) as usize).min(total_rows);
 
         let start_row = self
             .scroll_offset
-            .min(data.height.saturating_sub(max_visible_rows as u32) as usize);
-        let end_row = (start_row + max_visible_rows).min(data.height as usize);
-
-        // Render pixel rows
-        for row in &data.pixels[start_row..end_row] {
-            let mut spans = Vec::new();
-            for pixel in row {
-                // Use colored blocks for truecolor support
-                spans.push(Span::styled(
-                    "█",
-                    Style::default().fg(pixel.to_ratatui_color()),
-                ));
-            }
-            lines.push(Line::from(spans));
+            .min(total_rows.saturating_sub(max_visible_rows));
+        let end_row = (start_row + max_visible_rows).min(total_rows);
+
+        // Every pixel takes one column whatever the render mode
+        let total_cols = data.width as usize;
+        let max_visible_cols = (area.width as usize).min(total_cols);
+        let start_col = self
+            .horizontal_offset
+            .min(total_cols.saturating_sub(max_visible_cols));
+
+        let visible_cols = start_col..start_col + max_visible_cols;
+        if self.dither && self.render_mode == PixelRenderMode::Ascii {
+            lines.extend(dithered_lines(data, start_row..end_row, visible_cols));
+        } else {
+            lines.extend(pixel_lines(
+                data,
+                self.render_mode,
+                start_row..end_row,
+                visible_cols,
+            ));
         }
 
         // Add scroll indicator if needed
-        if data.height as usize > max_visible_rows {
+        if total_rows > max_visible_rows || total_cols > max_visible_cols {
+            let mut position = Vec::new();
+            if total_rows > max_visible_rows {
+                position.push(format!("Row {}/{}", start_row + 1, total_rows));
+            }
+            if total_cols > max_visible_cols {
+                position.push(format!("Col {}/{}", start_col + 1, total_cols));
+            }
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
-                format!("Row {}/{} (use ↑↓ to scroll)", start_row + 1, data.height),
+                format!("{} (use arrows to scroll)", position.join("  ")),
                 Style::default().fg(Color::DarkGray),
             )));
         }
@@ -364,3 +650,153 @@ impl PixelArtWidget {
         frame.render_widget(paragraph, area);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn gradient(width: u8, height: usize) -> PixelData {
+        let row: Vec<PixelColor> = (0..width)
+            .map(|x| PixelColor {
+                r: x * 64 + 16,
+                g: x * 64 + 16,
+                b: x * 64 + 16,
+            })
+            .collect();
+        PixelData {
+            pixels: vec![row; height],
+            width: width as u32,
+            height: height as u32,
+            original_width: width as u32,
+            original_height: height as u32,
+        }
+    }
+
+    #[test]
+    fn test_pixel_lines_keeps_visible_columns() {
+        let data = gradient(4, 2);
+        let lines = pixel_lines(&data, PixelRenderMode::Ascii, 0..1, 1..3);
+        assert_eq!(lines[0].to_string(), "▒█");
+
+        let lines = pixel_lines(&data, PixelRenderMode::HalfBlock, 0..1, 2..10);
+        assert_eq!(lines[0].spans.len(), 2);
+    }
+
+    #[test]
+    fn test_dither_gradient() {
+        // Black to white, left to right
+        let row: Vec<PixelColor> = (0..16u8)
+            .map(|x| PixelColor {
+                r: x * 17,
+                g: x * 17,
+                b: x * 17,
+            })
+            .collect();
+        let data = PixelData {
+            pixels: vec![row; 8],
+            width: 16,
+            height: 8,
+            original_width: 16,
+            original_height: 8,
+        };
+
+        let shades = dither_shades(&data);
+        assert!(shades.iter().all(|row| row[0] == 0 && row[15] == 4));
+        // Brightness is kept overall: the gradient averages half way
+        let total: usize = shades.iter().flatten().sum();
+        let mean = total as f32 / (16.0 * 8.0) / 4.0;
+        assert!((mean - 0.5).abs() < 0.05, "mean brightness {}", mean);
+        // Columns between two shades mix them rather than banding
+        let column: Vec<usize> = shades.iter().map(|row| row[2]).collect();
+        assert!(column.contains(&0) && column.contains(&1));
+
+        let lines = dithered_lines(&data, 0..1, 14..16);
+        assert_eq!(lines[0].to_string().chars().count(), 2);
+    }
+
+    fn config(image_path: Option<PathBuf>, pixel_size: Option<u32>) -> PixelArtConfig {
+        PixelArtConfig {
+            title: "Pixel Art".to_string(),
+            image_path,
+            pixel_size,
+            correct_aspect: false,
+            auto_fit: false,
+            dither: false,
+            position: crate::config::Position { row: 0, col: 0 },
+        }
+    }
+
+    #[test]
+    fn test_fitting_pixel_size() {
+        let mut widget = PixelArtWidget::new(config(None, None));
+
+        // A 2:1 image in 40 columns by 30 rows is limited by the width
+        assert_eq!(widget.fitting_pixel_size((200, 100), 40, 30), 40);
+        // A square one by the height; half blocks fit twice the rows
+        assert_eq!(widget.fitting_pixel_size((100, 100), 80, 20), 20);
+        widget.toggle_render_mode();
+        assert_eq!(widget.fitting_pixel_size((100, 100), 80, 20), 40);
+        // Never smaller than the smallest size
+        assert_eq!(widget.fitting_pixel_size((100, 100), 2, 2), MIN_PIXEL_SIZE);
+    }
+
+    #[test]
+    fn test_scroll_right_stops_at_last_column() {
+        let mut widget = PixelArtWidget::new(config(None, None));
+        widget.set_frames(vec![(gradient(4, 2), std::time::Duration::ZERO)]);
+        widget.view_width.store(2, Ordering::Relaxed);
+
+        for _ in 0..5 {
+            widget.scroll_right();
+        }
+        assert_eq!(widget.horizontal_offset, 2);
+        // Panning back moves straight away
+        widget.scroll_left();
+        assert_eq!(widget.horizontal_offset, 1);
+    }
+
+    /// The data `PixelArtFetcher` delivers for `path` at `pixel_size`
+    async fn fetched(path: &Path, pixel_size: u32) -> FeedData {
+        let fetcher = PixelArtFetcher::new(Some(path.to_path_buf()), pixel_size, false);
+        fetcher.fetch().await.unwrap()
+    }
+
+    fn red_png(dir: &Path) -> PathBuf {
+        let path = dir.join("red.png");
+        image::RgbImage::from_pixel(64, 32, image::Rgb([255, 0, 0]))
+            .save(&path)
+            .unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_pixel_size_changes_resize_the_decoded_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = red_png(dir.path());
+
+        let mut widget = PixelArtWidget::new(config(Some(path.clone()), Some(16)));
+        widget.update_data(fetched(&path, 16).await);
+        // Resizes work from memory, not the file
+        std::fs::remove_file(&path).unwrap();
+        widget.increase_pixel_size();
+        assert_eq!(widget.current().unwrap().width, 32);
+        widget.decrease_pixel_size();
+        assert!(widget.error_message.is_none());
+        assert_eq!(widget.current().unwrap().width, 16);
+    }
+
+    #[tokio::test]
+    async fn test_pixel_size_change_before_decode_applies_on_arrival() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = red_png(dir.path());
+
+        let mut widget = PixelArtWidget::new(config(Some(path.clone()), Some(16)));
+        // Nothing is decoded on the UI thread while the fetcher runs
+        widget.increase_pixel_size();
+        assert!(widget.current().is_none());
+
+        widget.update_data(fetched(&path, 16).await);
+        assert_eq!(widget.current().unwrap().width, 32);
+    }
+}
@@ -0,0 +1,280 @@
+use crate::config::PodcastConfig;
+use crate::feeds::http;
+use crate::feeds::podcast::PodcastFetcher;
+use crate::feeds::{FeedData, FeedFetcher, PodcastEpisode};
+use crate::ui::spinner::Spinner;
+use crate::ui::text::fit_line;
+use crate::ui::widgets::{list, FeedWidget, SelectedItem};
+use chrono::{Local, TimeZone};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+use std::sync::Arc;
+
+pub struct PodcastWidget {
+    config: PodcastConfig,
+    episodes: Vec<PodcastEpisode>,
+    loading: bool,
+    error: Option<String>,
+    scroll_state: ListState,
+    view_height: list::ViewHeight,
+    selected: bool,
+    has_new_items: bool,
+}
+
+fn format_date(timestamp: i64) -> Option<String> {
+    Local
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .map(|t| t.format("%Y-%m-%d").to_string())
+}
+
+/// `H:MM:SS` for episodes an hour or longer, `M:SS` otherwise
+fn format_duration(secs: u64) -> String {
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+impl PodcastWidget {
+    pub fn new(config: PodcastConfig) -> Self {
+        let mut scroll_state = ListState::default();
+        scroll_state.select(Some(0));
+
+        Self {
+            config,
+            episodes: Vec::new(),
+            loading: true,
+            error: None,
+            scroll_state,
+            view_height: list::ViewHeight::default(),
+            selected: false,
+            has_new_items: false,
+        }
+    }
+}
+
+impl FeedWidget for PodcastWidget {
+    fn id(&self) -> String {
+        format!(
+            "podcast-{}-{}",
+            self.config.position.row, self.config.position.col
+        )
+    }
+
+    fn title(&self) -> &str {
+        &self.config.title
+    }
+
+    fn position(&self) -> (usize, usize) {
+        (self.config.position.row, self.config.position.col)
+    }
+
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+        let border_style = if selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        let block = Block::default()
+            .title(format!(" {} ", self.config.title))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        if self.loading && self.episodes.is_empty() {
+            let loading_text =
+                List::new(vec![ListItem::new(Spinner::text("Loading..."))]).block(block);
+            frame.render_widget(loading_text, area);
+            return;
+        }
+
+        if let Some(ref error) = self.error {
+            let error_text =
+                List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+            frame.render_widget(error_text, area);
+            return;
+        }
+
+        let width = area.width.saturating_sub(2) as usize;
+        let items: Vec<ListItem> = self
+            .episodes
+            .iter()
+            .map(|episode| {
+                let title_line = Line::from(Span::styled(
+                    &episode.title,
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ));
+
+                let mut details = vec![Span::styled(
+                    episode.show.clone(),
+                    Style::default().fg(Color::Cyan),
+                )];
+                if let Some(date) = episode.published.and_then(format_date) {
+                    details.push(Span::styled(
+                        format!("  {}", date),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                if let Some(secs) = episode.duration_secs {
+                    details.push(Span::styled(
+                        format!("  {}", format_duration(secs)),
+                        Style::default().fg(Color::Green),
+                    ));
+                }
+                if episode.audio_url.is_none() {
+                    details.push(Span::styled(
+                        "  no audio",
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+
+                let mut lines = fit_line(title_line, width, self.config.overflow);
+                lines.extend(fit_line(Line::from(details), width, self.config.overflow));
+                ListItem::new(lines)
+            })
+            .collect();
+
+        self.view_height
+            .record(area.height.saturating_sub(2), &items);
+        let list = List::new(items).block(block).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let mut state = self.scroll_state.clone();
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn update_data(&mut self, data: FeedData) {
+        self.loading = false;
+        match data {
+            FeedData::Podcast(episodes) => {
+                self.has_new_items |= list::has_new_items(&self.episodes, &episodes, |e| {
+                    e.audio_url.clone().unwrap_or_else(|| e.title.clone())
+                });
+                self.episodes = episodes;
+                self.error = None;
+            }
+            FeedData::Error(e) => {
+                self.error = Some(e);
+            }
+            FeedData::Loading => {
+                self.loading = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn create_fetcher(&self, client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
+        http::with_client(&client, &self.config.http, |client| {
+            Box::new(PodcastFetcher::new(
+                self.config.feeds.clone(),
+                self.config.max_items,
+                client,
+            ))
+        })
+    }
+
+    fn scroll_up(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected > 0 {
+                self.scroll_state.select(Some(selected - 1));
+            }
+        }
+    }
+
+    fn scroll_down(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected < self.episodes.len().saturating_sub(1) {
+                self.scroll_state.select(Some(selected + 1));
+            }
+        }
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        list::select_last(&mut self.scroll_state, self.episodes.len());
+    }
+
+    fn page_size(&self) -> usize {
+        self.view_height.page_size()
+    }
+
+    fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        vec![("Enter", "Play"), ("y", "Copy URL")]
+    }
+
+    fn select_index(&mut self, index: usize) {
+        list::select_index(&mut self.scroll_state, index, self.episodes.len());
+    }
+
+    fn take_new_items(&mut self) -> bool {
+        std::mem::take(&mut self.has_new_items)
+    }
+
+    /// The URL is the audio file, so opening or copying the selection hands
+    /// the episode itself to the browser or a player
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        let idx = self.scroll_state.selected()?;
+        let episode = self.episodes.get(idx)?;
+
+        let mut metadata = Vec::new();
+        if let Some(date) = episode.published.and_then(format_date) {
+            metadata.push(date);
+        }
+        if let Some(secs) = episode.duration_secs {
+            metadata.push(format_duration(secs));
+        }
+
+        Some(SelectedItem {
+            title: episode.title.clone(),
+            url: episode.audio_url.clone().or_else(|| episode.link.clone()),
+            description: None,
+            source: episode.show.clone(),
+            metadata: (!metadata.is_empty()).then(|| metadata.join(" | ")),
+        })
+    }
+
+    fn get_selected_discussion_url(&self) -> Option<String> {
+        None
+    }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(42), "0:42");
+        assert_eq!(format_duration(25 * 60 + 7), "25:07");
+        assert_eq!(format_duration(3723), "1:02:03");
+    }
+}
@@ -0,0 +1,224 @@
+use crate::config::RedditConfig;
+use crate::feeds::reddit::RedditFetcher;
+use crate::feeds::{FeedData, FeedFetcher, RedditPost};
+use crate::ui::spinner::Spinner;
+use crate::ui::text::fit_line;
+use crate::ui::widgets::{list, FeedWidget, SelectedItem};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+use std::sync::Arc;
+
+pub struct RedditWidget {
+    config: RedditConfig,
+    posts: Vec<RedditPost>,
+    loading: bool,
+    error: Option<String>,
+    scroll_state: ListState,
+    view_height: list::ViewHeight,
+    selected: bool,
+    has_new_items: bool,
+}
+
+impl RedditWidget {
+    pub fn new(config: RedditConfig) -> Self {
+        let mut scroll_state = ListState::default();
+        scroll_state.select(Some(0));
+
+        Self {
+            config,
+            posts: Vec::new(),
+            loading: true,
+            error: None,
+            scroll_state,
+            view_height: list::ViewHeight::default(),
+            selected: false,
+            has_new_items: false,
+        }
+    }
+}
+
+impl FeedWidget for RedditWidget {
+    fn id(&self) -> String {
+        format!(
+            "reddit-{}-{}",
+            self.config.position.row, self.config.position.col
+        )
+    }
+
+    fn title(&self) -> &str {
+        &self.config.title
+    }
+
+    fn position(&self) -> (usize, usize) {
+        (self.config.position.row, self.config.position.col)
+    }
+
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+        let border_style = if selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        let block = Block::default()
+            .title(format!(" {} ", self.config.title))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        if self.loading && self.posts.is_empty() {
+            let loading_text =
+                List::new(vec![ListItem::new(Spinner::text("Loading..."))]).block(block);
+            frame.render_widget(loading_text, area);
+            return;
+        }
+
+        if let Some(ref error) = self.error {
+            let error_text =
+                List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+            frame.render_widget(error_text, area);
+            return;
+        }
+
+        let meta_width = area.width.saturating_sub(2) as usize;
+        let items: Vec<ListItem> = self
+            .posts
+            .iter()
+            .enumerate()
+            .map(|(i, post)| {
+                let title_line = Line::from(vec![
+                    Span::styled(format!("{}. ", i + 1), Style::default().fg(Color::DarkGray)),
+                    Span::styled(&post.title, Style::default().fg(Color::White)),
+                ]);
+
+                let meta_line = Line::from(vec![
+                    Span::styled(
+                        format!("   {} pts | ", post.score),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                    Span::styled(
+                        format!("{} comments | ", post.num_comments),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(
+                        format!("by u/{}", post.author),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]);
+
+                let mut lines = vec![title_line];
+                lines.extend(fit_line(meta_line, meta_width, self.config.overflow));
+                ListItem::new(lines)
+            })
+            .collect();
+
+        self.view_height
+            .record(area.height.saturating_sub(2), &items);
+        let list = List::new(items).block(block).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let mut state = self.scroll_state.clone();
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn update_data(&mut self, data: FeedData) {
+        self.loading = false;
+        match data {
+            FeedData::Reddit(posts) => {
+                self.has_new_items |=
+                    list::has_new_items(&self.posts, &posts, |p| p.permalink.clone());
+                self.posts = posts;
+                self.error = None;
+            }
+            FeedData::Error(e) => {
+                self.error = Some(e);
+            }
+            FeedData::Loading => {
+                self.loading = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn create_fetcher(&self, client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
+        Box::new(RedditFetcher::new(
+            self.config.subreddit.clone(),
+            self.config.max_items,
+            client,
+        ))
+    }
+
+    fn scroll_up(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected > 0 {
+                self.scroll_state.select(Some(selected - 1));
+            }
+        }
+    }
+
+    fn scroll_down(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected < self.posts.len().saturating_sub(1) {
+                self.scroll_state.select(Some(selected + 1));
+            }
+        }
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        list::select_last(&mut self.scroll_state, self.posts.len());
+    }
+
+    fn page_size(&self) -> usize {
+        self.view_height.page_size()
+    }
+
+    fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        vec![("Enter", "Read"), ("o", "Open"), ("y", "Copy URL")]
+    }
+
+    fn select_index(&mut self, index: usize) {
+        list::select_index(&mut self.scroll_state, index, self.posts.len());
+    }
+
+    fn take_new_items(&mut self) -> bool {
+        std::mem::take(&mut self.has_new_items)
+    }
+
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        let idx = self.scroll_state.selected()?;
+        let post = self.posts.get(idx)?;
+
+        Some(SelectedItem {
+            title: post.title.clone(),
+            url: Some(post.permalink.clone()),
+            description: None,
+            source: format!("r/{}", post.subreddit),
+            metadata: Some(format!(
+                "{} points | {} comments | by u/{}",
+                post.score, post.num_comments, post.author
+            )),
+        })
+    }
+
+    /// Get the comments page for the selected post
+    fn get_selected_discussion_url(&self) -> Option<String> {
+        let idx = self.scroll_state.selected()?;
+        self.posts.get(idx).map(|post| post.permalink.clone())
+    }
+}
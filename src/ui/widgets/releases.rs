@@ -0,0 +1,293 @@
+use crate::config::ReleasesConfig;
+use crate::feeds::releases::ReleasesFetcher;
+use crate::feeds::{FeedData, FeedFetcher, ReleaseInfo};
+use crate::ui::spinner::Spinner;
+use crate::ui::widgets::{list, FeedWidget, SelectedItem};
+use chrono::{Local, TimeZone};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+pub struct ReleasesWidget {
+    config: ReleasesConfig,
+    releases: Vec<ReleaseInfo>,
+    /// Version of each package in the previous update
+    last_seen: HashMap<String, String>,
+    /// Packages whose version changed in the latest update
+    changed: HashSet<String>,
+    loading: bool,
+    error: Option<String>,
+    scroll_state: ListState,
+    view_height: list::ViewHeight,
+    selected: bool,
+    has_new_items: bool,
+}
+
+fn format_date(timestamp: i64) -> Option<String> {
+    Local
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .map(|t| t.format("%Y-%m-%d").to_string())
+}
+
+impl ReleasesWidget {
+    pub fn new(config: ReleasesConfig) -> Self {
+        let mut scroll_state = ListState::default();
+        scroll_state.select(Some(0));
+
+        Self {
+            config,
+            releases: Vec::new(),
+            last_seen: HashMap::new(),
+            changed: HashSet::new(),
+            loading: true,
+            error: None,
+            scroll_state,
+            view_height: list::ViewHeight::default(),
+            selected: false,
+            has_new_items: false,
+        }
+    }
+
+    /// Note which packages have a different version than last time. A
+    /// package seen for the first time isn't counted as changed.
+    fn track_versions(&mut self, releases: &[ReleaseInfo]) {
+        self.changed = releases
+            .iter()
+            .filter(|r| {
+                self.last_seen
+                    .get(&r.package)
+                    .is_some_and(|version| *version != r.version)
+            })
+            .map(|r| r.package.clone())
+            .collect();
+        for release in releases {
+            self.last_seen
+                .insert(release.package.clone(), release.version.clone());
+        }
+        self.has_new_items |= !self.changed.is_empty();
+    }
+}
+
+impl FeedWidget for ReleasesWidget {
+    fn id(&self) -> String {
+        format!(
+            "releases-{}-{}",
+            self.config.position.row, self.config.position.col
+        )
+    }
+
+    fn title(&self) -> &str {
+        &self.config.title
+    }
+
+    fn position(&self) -> (usize, usize) {
+        (self.config.position.row, self.config.position.col)
+    }
+
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+        let border_style = if selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        let block = Block::default()
+            .title(format!(" {} ", self.config.title))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        if self.loading && self.releases.is_empty() {
+            let loading_text =
+                List::new(vec![ListItem::new(Spinner::text("Loading..."))]).block(block);
+            frame.render_widget(loading_text, area);
+            return;
+        }
+
+        if let Some(ref error) = self.error {
+            let error_text =
+                List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+            frame.render_widget(error_text, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .releases
+            .iter()
+            .map(|release| {
+                let changed = self.changed.contains(&release.package);
+                let version_style = if changed {
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Green)
+                };
+
+                let mut spans = vec![
+                    Span::styled(
+                        format!("{:<24} ", release.package),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::styled(format!("{:<12} ", release.version), version_style),
+                ];
+                if let Some(date) = release.published.and_then(format_date) {
+                    spans.push(Span::styled(date, Style::default().fg(Color::DarkGray)));
+                }
+                if changed {
+                    spans.push(Span::styled(
+                        "  NEW",
+                        Style::default()
+                            .fg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        self.view_height
+            .record(area.height.saturating_sub(2), &items);
+        let list = List::new(items).block(block).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let mut state = self.scroll_state.clone();
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn update_data(&mut self, data: FeedData) {
+        self.loading = false;
+        match data {
+            FeedData::Releases(releases) => {
+                self.track_versions(&releases);
+                self.releases = releases;
+                self.error = None;
+            }
+            FeedData::Error(e) => {
+                self.error = Some(e);
+            }
+            FeedData::Loading => {
+                self.loading = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn create_fetcher(&self, client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
+        Box::new(ReleasesFetcher::new(self.config.packages.clone(), client))
+    }
+
+    fn scroll_up(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected > 0 {
+                self.scroll_state.select(Some(selected - 1));
+            }
+        }
+    }
+
+    fn scroll_down(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected < self.releases.len().saturating_sub(1) {
+                self.scroll_state.select(Some(selected + 1));
+            }
+        }
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        list::select_last(&mut self.scroll_state, self.releases.len());
+    }
+
+    fn page_size(&self) -> usize {
+        self.view_height.page_size()
+    }
+
+    fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        vec![("Enter", "Read"), ("o", "Open"), ("y", "Copy URL")]
+    }
+
+    fn select_index(&mut self, index: usize) {
+        list::select_index(&mut self.scroll_state, index, self.releases.len());
+    }
+
+    fn take_new_items(&mut self) -> bool {
+        std::mem::take(&mut self.has_new_items)
+    }
+
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        let idx = self.scroll_state.selected()?;
+        let release = self.releases.get(idx)?;
+
+        Some(SelectedItem {
+            title: format!("{} {}", release.package, release.version),
+            url: Some(release.url.clone()),
+            description: None,
+            source: self.config.title.clone(),
+            metadata: release
+                .published
+                .and_then(format_date)
+                .map(|date| format!("Published {}", date)),
+        })
+    }
+
+    fn get_selected_discussion_url(&self) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Position;
+
+    fn release(package: &str, version: &str) -> ReleaseInfo {
+        ReleaseInfo {
+            package: package.to_string(),
+            version: version.to_string(),
+            published: None,
+            url: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_changed_versions_are_highlighted() {
+        let mut widget = ReleasesWidget::new(ReleasesConfig {
+            title: "Releases".to_string(),
+            packages: Vec::new(),
+            refresh_secs: None,
+            position: Position { row: 0, col: 0 },
+        });
+
+        widget.update_data(FeedData::Releases(vec![release("crate:serde", "1.0.0")]));
+        assert!(widget.changed.is_empty());
+        assert!(!widget.take_new_items());
+
+        widget.update_data(FeedData::Releases(vec![
+            release("crate:serde", "1.0.1"),
+            release("npm:react", "18.3.1"),
+        ]));
+        assert_eq!(widget.changed, HashSet::from(["crate:serde".to_string()]));
+        assert!(widget.take_new_items());
+
+        // The highlight lasts until the next poll
+        widget.update_data(FeedData::Releases(vec![release("crate:serde", "1.0.1")]));
+        assert!(widget.changed.is_empty());
+    }
+}
@@ -1,7 +1,10 @@
 use crate::config::RssConfig;
+use crate::feeds::http;
 use crate::feeds::rss::RssFetcher;
 use crate::feeds::{FeedData, FeedFetcher, RssItem};
-use crate::ui::widgets::{FeedWidget, SelectedItem};
+use crate::ui::spinner::Spinner;
+use crate::ui::text::fit_line;
+use crate::ui::widgets::{list, FeedWidget, SelectedItem};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -9,6 +12,7 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState},
     Frame,
 };
+use std::sync::Arc;
 
 pub struct RssWidget {
     config: RssConfig,
@@ -16,7 +20,11 @@ pub struct RssWidget {
     loading: bool,
     error: Option<String>,
     scroll_state: ListState,
+    view_height: list::ViewHeight,
     selected: bool,
+    has_new_items: bool,
+    /// Set when the items come from elsewhere (JSON poll, arXiv, ...)
+    source: Option<list::ItemSource>,
 }
 
 impl RssWidget {
@@ -30,16 +38,27 @@ impl RssWidget {
             loading: true,
             error: None,
             scroll_state,
+            view_height: list::ViewHeight::default(),
             selected: false,
+            has_new_items: false,
+            source: None,
         }
     }
+
+    /// Draw another source's items instead of the configured feeds
+    pub fn with_source(mut self, source: list::ItemSource) -> Self {
+        self.source = Some(source);
+        self
+    }
 }
 
 impl FeedWidget for RssWidget {
     fn id(&self) -> String {
         format!(
-            "rss-{}-{}",
-            self.config.position.row, self.config.position.col
+            "{}-{}-{}",
+            self.source.as_ref().map_or("rss", |s| s.id_prefix),
+            self.config.position.row,
+            self.config.position.col
         )
     }
 
@@ -51,6 +70,11 @@ impl FeedWidget for RssWidget {
         (self.config.position.row, self.config.position.col)
     }
 
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
     fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
             Style::default().fg(Color::Yellow)
@@ -64,7 +88,8 @@ impl FeedWidget for RssWidget {
             .border_style(border_style);
 
         if self.loading && self.items.is_empty() {
-            let loading_text = List::new(vec![ListItem::new("Loading...")]).block(block);
+            let loading_text =
+                List::new(vec![ListItem::new(Spinner::text("Loading..."))]).block(block);
             frame.render_widget(loading_text, area);
             return;
         }
@@ -76,6 +101,7 @@ impl FeedWidget for RssWidget {
             return;
         }
 
+        let meta_width = area.width.saturating_sub(2) as usize;
         let items: Vec<ListItem> = self
             .items
             .iter()
@@ -100,10 +126,14 @@ impl FeedWidget for RssWidget {
 
                 let meta_line = Line::from(meta_parts);
 
-                ListItem::new(vec![title_line, meta_line])
+                let mut lines = vec![title_line];
+                lines.extend(fit_line(meta_line, meta_width, self.config.overflow));
+                ListItem::new(lines)
             })
             .collect();
 
+        self.view_height
+            .record(area.height.saturating_sub(2), &items);
         let list = List::new(items).block(block).highlight_style(
             Style::default()
                 .bg(Color::DarkGray)
@@ -118,6 +148,9 @@ impl FeedWidget for RssWidget {
         self.loading = false;
         match data {
             FeedData::Rss(items) => {
+                self.has_new_items |= list::has_new_items(&self.items, &items, |i| {
+                    i.link.clone().unwrap_or_else(|| i.title.clone())
+                });
                 self.items = items;
                 self.error = None;
             }
@@ -131,11 +164,18 @@ impl FeedWidget for RssWidget {
         }
     }
 
-    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
-        Box::new(RssFetcher::new(
-            self.config.feeds.clone(),
-            self.config.max_items,
-        ))
+    fn create_fetcher(&self, client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
+        if let Some(source) = &self.source {
+            return source.create_fetcher(client);
+        }
+        http::with_client(&client, &self.config.http, |client| {
+            Box::new(RssFetcher::new(
+                self.config.feeds.clone(),
+                self.config.opml_path.clone(),
+                self.config.max_items,
+                client,
+            ))
+        })
     }
 
     fn scroll_up(&mut self) {
@@ -154,10 +194,34 @@ impl FeedWidget for RssWidget {
         }
     }
 
+    fn scroll_to_bottom(&mut self) {
+        list::select_last(&mut self.scroll_state, self.items.len());
+    }
+
+    fn page_size(&self) -> usize {
+        self.view_height.page_size()
+    }
+
     fn set_selected(&mut self, selected: bool) {
         self.selected = selected;
     }
 
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        vec![("Enter", "Read"), ("o", "Open"), ("y", "Copy URL")]
+    }
+
+    fn error_detail(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn select_index(&mut self, index: usize) {
+        list::select_index(&mut self.scroll_state, index, self.items.len());
+    }
+
+    fn take_new_items(&mut self) -> bool {
+        std::mem::take(&mut self.has_new_items)
+    }
+
     fn get_selected_item(&self) -> Option<SelectedItem> {
         let idx = self.scroll_state.selected()?;
         let item = self.items.get(idx)?;
@@ -1,7 +1,9 @@
 use crate::config::SportsConfig;
 use crate::feeds::sports::SportsFetcher;
 use crate::feeds::{FeedData, FeedFetcher, SportsEvent};
-use crate::ui::widgets::FeedWidget;
+use crate::ui::spinner::Spinner;
+use crate::ui::widgets::{list, FeedWidget};
+use chrono::{DateTime, NaiveDateTime, Utc};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -9,13 +11,69 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState},
     Frame,
 };
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How long a game's score stays highlighted after it changes
+const SCORE_FLASH: Duration = Duration::from_secs(5);
+
+fn is_final(status: &str) -> bool {
+    status.to_lowercase().contains("final")
+}
+
+/// Parse a game's start time. ESPN sends `2024-01-15T00:30Z`, TheSportsDB
+/// `2024-01-15T00:30:00` without a zone; both are UTC.
+fn parse_start_time(start: &str) -> Option<DateTime<Utc>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(start) {
+        return Some(time.with_timezone(&Utc));
+    }
+    ["%Y-%m-%dT%H:%MZ", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(start, format).ok())
+        .map(|time| time.and_utc())
+}
+
+/// `starts in 2h 15m`, or `None` once the start time has passed
+fn starts_in(start: DateTime<Utc>, now: DateTime<Utc>) -> Option<String> {
+    if start <= now {
+        return None;
+    }
+    let minutes = (start - now).num_minutes();
+    let label = match minutes {
+        m if m < 60 => format!("{}m", m.max(1)),
+        m if m < 24 * 60 => format!("{}h {}m", m / 60, m % 60),
+        m => format!("{}d {}h", m / (24 * 60), m % (24 * 60) / 60),
+    };
+    Some(format!("starts in {}", label))
+}
+
+/// Identifies a game across refreshes
+fn game_key(event: &SportsEvent) -> String {
+    format!("{}:{}@{}", event.league, event.away_team, event.home_team)
+}
+
+/// Whether a game's status says it is being played right now
+fn is_live(status: &str) -> bool {
+    let status = status.to_lowercase();
+    status.contains("progress") || status.contains("half") || status.contains("quarter")
+}
 
 pub struct SportsWidget {
     config: SportsConfig,
     events: Vec<SportsEvent>,
+    /// Only this league's games are listed; `None` shows every league
+    active_league: Option<String>,
+    live_only: bool,
+    /// Last known (home, away) score of each game, by `game_key`
+    previous_scores: HashMap<String, (u32, u32)>,
+    /// When each game's score last went up
+    score_changed: HashMap<String, Instant>,
     loading: bool,
     error: Option<String>,
     scroll_state: ListState,
+    view_height: list::ViewHeight,
     selected: bool,
 }
 
@@ -27,12 +85,78 @@ impl SportsWidget {
         Self {
             config,
             events: Vec::new(),
+            active_league: None,
+            live_only: false,
+            previous_scores: HashMap::new(),
+            score_changed: HashMap::new(),
             loading: true,
             error: None,
             scroll_state,
+            view_height: list::ViewHeight::default(),
             selected: false,
         }
     }
+
+    /// Games that pass the league and live filters
+    fn visible_events(&self) -> Vec<&SportsEvent> {
+        self.events
+            .iter()
+            .filter(|e| self.active_league.as_ref().is_none_or(|l| &e.league == l))
+            .filter(|e| !self.live_only || is_live(&e.status))
+            .collect()
+    }
+
+    /// Compare fresh scores with the previous ones, marking games whose
+    /// score went up
+    fn record_scores(&mut self, events: &[SportsEvent], now: Instant) {
+        let mut scores = HashMap::new();
+        for event in events {
+            let (Some(home), Some(away)) = (event.home_score, event.away_score) else {
+                continue;
+            };
+            let key = game_key(event);
+            if let Some(&(prev_home, prev_away)) = self.previous_scores.get(&key) {
+                if home > prev_home || away > prev_away {
+                    self.score_changed.insert(key.clone(), now);
+                }
+            }
+            scores.insert(key, (home, away));
+        }
+        self.previous_scores = scores;
+        self.score_changed
+            .retain(|_, changed| now.duration_since(*changed) < SCORE_FLASH);
+    }
+
+    fn score_flashing(&self, event: &SportsEvent, now: Instant) -> bool {
+        self.score_changed
+            .get(&game_key(event))
+            .is_some_and(|changed| now.duration_since(*changed) < SCORE_FLASH)
+    }
+
+    /// Show the next configured league, then all of them again
+    pub fn cycle_league(&mut self) {
+        // Events carry the league name upper-cased
+        let leagues: Vec<String> = self
+            .config
+            .leagues
+            .iter()
+            .map(|l| l.to_uppercase())
+            .collect();
+        let next = match &self.active_league {
+            None => 0,
+            Some(active) => leagues
+                .iter()
+                .position(|l| l == active)
+                .map_or(leagues.len(), |i| i + 1),
+        };
+        self.active_league = leagues.get(next).cloned();
+        self.scroll_state.select(Some(0));
+    }
+
+    pub fn toggle_live_only(&mut self) {
+        self.live_only = !self.live_only;
+        self.scroll_state.select(Some(0));
+    }
 }
 
 impl FeedWidget for SportsWidget {
@@ -51,6 +175,11 @@ impl FeedWidget for SportsWidget {
         (self.config.position.row, self.config.position.col)
     }
 
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
     fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
             Style::default().fg(Color::Yellow)
@@ -58,13 +187,26 @@ impl FeedWidget for SportsWidget {
             Style::default().fg(Color::White)
         };
 
+        let mut filters: Vec<&str> = Vec::new();
+        if let Some(ref league) = self.active_league {
+            filters.push(league);
+        }
+        if self.live_only {
+            filters.push("live");
+        }
+        let title = if filters.is_empty() {
+            format!(" {} ", self.config.title)
+        } else {
+            format!(" {} [{}] ", self.config.title, filters.join(" · "))
+        };
         let block = Block::default()
-            .title(format!(" {} ", self.config.title))
+            .title(title)
             .borders(Borders::ALL)
             .border_style(border_style);
 
         if self.loading && self.events.is_empty() {
-            let loading_text = List::new(vec![ListItem::new("Loading...")]).block(block);
+            let loading_text =
+                List::new(vec![ListItem::new(Spinner::text("Loading..."))]).block(block);
             frame.render_widget(loading_text, area);
             return;
         }
@@ -76,27 +218,41 @@ impl FeedWidget for SportsWidget {
             return;
         }
 
-        if self.events.is_empty() {
-            let no_games = List::new(vec![ListItem::new("No games scheduled")]).block(block);
+        let events = self.visible_events();
+        if events.is_empty() {
+            let message = if self.live_only {
+                "No games in progress"
+            } else {
+                "No games scheduled"
+            };
+            let no_games = List::new(vec![ListItem::new(message)]).block(block);
             frame.render_widget(no_games, area);
             return;
         }
 
-        let items: Vec<ListItem> = self
-            .events
-            .iter()
+        let now = Instant::now();
+        let wall_now = Utc::now();
+        let items: Vec<ListItem> = events
+            .into_iter()
             .map(|event| {
+                let score_style = if self.score_flashing(event, now) {
+                    Style::default().fg(Color::Black).bg(Color::Green)
+                } else {
+                    Style::default().fg(Color::Yellow)
+                };
+
                 let score_text = match (event.home_score, event.away_score) {
-                    (Some(h), Some(a)) => format!("{} - {}", h, a),
+                    // Away team is listed first, so its score comes first too
+                    (Some(h), Some(a)) => format!("{} - {}", a, h),
                     _ => "vs".to_string(),
                 };
 
-                let status_color = match event.status.to_lowercase().as_str() {
-                    s if s.contains("final") => Color::Gray,
-                    s if s.contains("progress") || s.contains("half") || s.contains("quarter") => {
-                        Color::Green
-                    }
-                    _ => Color::Yellow,
+                let status_color = if is_final(&event.status) {
+                    Color::Gray
+                } else if is_live(&event.status) {
+                    Color::Green
+                } else {
+                    Color::Yellow
                 };
 
                 let game_line = Line::from(vec![
@@ -107,22 +263,29 @@ impl FeedWidget for SportsWidget {
                     Span::styled(&event.away_team, Style::default().fg(Color::White)),
                     Span::styled(
                         format!(" {} ", score_text),
-                        Style::default()
-                            .fg(Color::Yellow)
-                            .add_modifier(Modifier::BOLD),
+                        score_style.add_modifier(Modifier::BOLD),
                     ),
                     Span::styled(&event.home_team, Style::default().fg(Color::White)),
                 ]);
 
+                // Games that haven't started count down to kick-off instead
+                let countdown = (!is_live(&event.status) && !is_final(&event.status))
+                    .then(|| event.start_time.as_deref().and_then(parse_start_time))
+                    .flatten()
+                    .and_then(|start| starts_in(start, wall_now));
+                let status_text = countdown.unwrap_or_else(|| event.status.clone());
+
                 let status_line = Line::from(vec![
                     Span::styled("      ", Style::default()),
-                    Span::styled(&event.status, Style::default().fg(status_color)),
+                    Span::styled(status_text, Style::default().fg(status_color)),
                 ]);
 
                 ListItem::new(vec![game_line, status_line])
             })
             .collect();
 
+        self.view_height
+            .record(area.height.saturating_sub(2), &items);
         let list = List::new(items).block(block).highlight_style(
             Style::default()
                 .bg(Color::DarkGray)
@@ -137,6 +300,7 @@ impl FeedWidget for SportsWidget {
         self.loading = false;
         match data {
             FeedData::Sports(events) => {
+                self.record_scores(&events, Instant::now());
                 self.events = events;
                 self.error = None;
             }
@@ -150,8 +314,12 @@ impl FeedWidget for SportsWidget {
         }
     }
 
-    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
-        Box::new(SportsFetcher::new(self.config.leagues.clone()))
+    fn create_fetcher(&self, client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
+        Box::new(SportsFetcher::new(
+            self.config.provider,
+            self.config.leagues.clone(),
+            client,
+        ))
     }
 
     fn scroll_up(&mut self) {
@@ -164,17 +332,156 @@ impl FeedWidget for SportsWidget {
 
     fn scroll_down(&mut self) {
         if let Some(selected) = self.scroll_state.selected() {
-            if selected < self.events.len().saturating_sub(1) {
+            if selected < self.visible_events().len().saturating_sub(1) {
                 self.scroll_state.select(Some(selected + 1));
             }
         }
     }
 
+    fn scroll_to_top(&mut self) {
+        self.scroll_state.select(Some(0));
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        let len = self.visible_events().len();
+        list::select_last(&mut self.scroll_state, len);
+    }
+
+    fn page_size(&self) -> usize {
+        self.view_height.page_size()
+    }
+
     fn set_selected(&mut self, selected: bool) {
         self.selected = selected;
     }
 
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        vec![("f", "League"), ("L", "Live only")]
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        Some(self)
+    }
+
     fn get_selected_discussion_url(&self) -> Option<String> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Position, SportsProvider};
+
+    fn event(league: &str, status: &str) -> SportsEvent {
+        SportsEvent {
+            league: league.to_string(),
+            home_team: "Home".to_string(),
+            away_team: "Away".to_string(),
+            home_score: None,
+            away_score: None,
+            status: status.to_string(),
+            start_time: None,
+        }
+    }
+
+    fn leagues(widget: &SportsWidget) -> Vec<&str> {
+        widget
+            .visible_events()
+            .iter()
+            .map(|e| e.league.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_league_and_live_filters() {
+        let mut widget = SportsWidget::new(SportsConfig {
+            title: "Sports".to_string(),
+            provider: SportsProvider::default(),
+            leagues: vec!["nba".to_string(), "nfl".to_string()],
+            refresh_secs: None,
+            position: Position { row: 0, col: 0 },
+        });
+        widget.update_data(FeedData::Sports(vec![
+            event("NBA", "In Progress"),
+            event("NFL", "Final"),
+            event("NBA", "Scheduled"),
+        ]));
+        assert_eq!(leagues(&widget), ["NBA", "NFL", "NBA"]);
+
+        widget.cycle_league();
+        assert_eq!(widget.active_league.as_deref(), Some("NBA"));
+        assert_eq!(leagues(&widget), ["NBA", "NBA"]);
+
+        widget.toggle_live_only();
+        assert_eq!(widget.visible_events().len(), 1);
+        assert_eq!(widget.visible_events()[0].status, "In Progress");
+
+        widget.cycle_league();
+        assert!(widget.visible_events().is_empty());
+
+        // Back round to every league
+        widget.cycle_league();
+        assert_eq!(widget.active_league, None);
+        widget.toggle_live_only();
+        assert_eq!(widget.visible_events().len(), 3);
+    }
+
+    #[test]
+    fn test_countdown_to_start() {
+        let now = parse_start_time("2024-01-15T00:30Z").unwrap();
+        assert_eq!(
+            parse_start_time("2024-01-15T00:30:00"),
+            Some(now),
+            "TheSportsDB times are UTC too"
+        );
+
+        let at = |s| parse_start_time(s).unwrap();
+        assert_eq!(
+            starts_in(at("2024-01-15T02:45Z"), now).as_deref(),
+            Some("starts in 2h 15m")
+        );
+        assert_eq!(
+            starts_in(at("2024-01-15T00:30:20Z"), now).as_deref(),
+            Some("starts in 1m")
+        );
+        assert_eq!(
+            starts_in(at("2024-01-17T03:30Z"), now).as_deref(),
+            Some("starts in 2d 3h")
+        );
+        assert_eq!(starts_in(at("2024-01-15T00:00Z"), now), None);
+    }
+
+    #[test]
+    fn test_score_increase_flashes_briefly() {
+        let mut widget = SportsWidget::new(SportsConfig {
+            title: "Sports".to_string(),
+            provider: SportsProvider::default(),
+            leagues: vec!["nba".to_string()],
+            refresh_secs: None,
+            position: Position { row: 0, col: 0 },
+        });
+        let scored = |home, away| SportsEvent {
+            home_score: Some(home),
+            away_score: Some(away),
+            ..event("NBA", "In Progress")
+        };
+
+        let start = Instant::now();
+        widget.record_scores(&[scored(10, 8)], start);
+        // The first score seen isn't a change
+        assert!(!widget.score_flashing(&scored(10, 8), start));
+
+        let later = start + Duration::from_secs(30);
+        widget.record_scores(&[scored(10, 11)], later);
+        assert!(widget.score_flashing(&scored(10, 11), later));
+        assert!(!widget.score_flashing(&scored(10, 11), later + SCORE_FLASH));
+
+        widget.record_scores(&[scored(10, 11)], later + SCORE_FLASH);
+        assert!(widget.score_changed.is_empty());
+    }
+}
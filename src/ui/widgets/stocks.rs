@@ -1,14 +1,71 @@
-use crate::config::StocksConfig;
+use crate::config::{AlertDirection, StockAlert, StocksConfig};
 use crate::feeds::stocks::StocksFetcher;
 use crate::feeds::{FeedData, FeedFetcher, StockQuote};
-use crate::ui::widgets::FeedWidget;
+use crate::ui::spinner::Spinner;
+use crate::ui::widgets::{list, FeedWidget};
 use ratatui::{
-    layout::Rect,
+    layout::{Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState},
+    widgets::{Block, Borders, List, ListItem, ListState, Sparkline},
     Frame,
 };
+use std::sync::Arc;
+
+/// Lines per quote: symbol, change and the sparkline
+const QUOTE_HEIGHT: u16 = 3;
+/// Sparklines are indented to line up with the price
+const SPARKLINE_INDENT: u16 = 6;
+
+/// Scale prices onto 0..=100 so the sparkline spans its full height between
+/// the day's low and high
+fn sparkline_points(history: &[f64]) -> Vec<u64> {
+    let min = history.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = history.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+
+    history
+        .iter()
+        .map(|price| {
+            if range > 0.0 {
+                ((price - min) / range * 100.0).round() as u64
+            } else {
+                50
+            }
+        })
+        .collect()
+}
+
+/// Whether `price` is past the alert's threshold
+fn alert_triggered(alert: &StockAlert, price: f64) -> bool {
+    match alert.direction {
+        AlertDirection::Above => price > alert.price,
+        AlertDirection::Below => price < alert.price,
+    }
+}
+
+fn alert_label(alert: &StockAlert) -> String {
+    let direction = match alert.direction {
+        AlertDirection::Above => "above",
+        AlertDirection::Below => "below",
+    };
+    format!("{} {} {:.2}", alert.symbol, direction, alert.price)
+}
+
+/// What to print before a price quoted in `currency` (`usd`, `eur`, ...).
+/// Codes without a common symbol are shown as `CHF 1.00`.
+fn currency_prefix(currency: &str) -> String {
+    match currency.to_lowercase().as_str() {
+        "usd" => "$".to_string(),
+        "eur" => "€".to_string(),
+        "gbp" => "£".to_string(),
+        "jpy" | "cny" => "¥".to_string(),
+        "inr" => "₹".to_string(),
+        "krw" => "₩".to_string(),
+        "btc" => "₿".to_string(),
+        code => format!("{} ", code.to_uppercase()),
+    }
+}
 
 pub struct StocksWidget {
     config: StocksConfig,
@@ -16,7 +73,16 @@ pub struct StocksWidget {
     loading: bool,
     error: Option<String>,
     scroll_state: ListState,
+    view_height: list::ViewHeight,
     selected: bool,
+    /// Per alert, whether the threshold was passed at the last quote; None
+    /// until the symbol has been seen
+    alert_state: Vec<Option<bool>>,
+    pending_alerts: Vec<String>,
+    /// Set when the quotes come from elsewhere (crypto)
+    source: Option<list::ItemSource>,
+    /// Printed before prices, `$` unless set with `with_currency`
+    price_prefix: String,
 }
 
 impl StocksWidget {
@@ -25,21 +91,75 @@ impl StocksWidget {
         scroll_state.select(Some(0));
 
         Self {
+            alert_state: vec![None; config.alerts.len()],
             config,
             quotes: Vec::new(),
             loading: true,
             error: None,
             scroll_state,
+            view_height: list::ViewHeight::default(),
             selected: false,
+            pending_alerts: Vec::new(),
+            source: None,
+            price_prefix: currency_prefix("usd"),
+        }
+    }
+
+    /// Show prices in `currency` (`eur`, `gbp`, ...) instead of dollars
+    pub fn with_currency(mut self, currency: &str) -> Self {
+        self.price_prefix = currency_prefix(currency);
+        self
+    }
+
+    /// Draw another source's quotes instead of the configured symbols
+    pub fn with_source(mut self, source: list::ItemSource) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Compare new quotes against each alert. A message is raised only when
+    /// the price crosses into the alert, and again only after it has crossed
+    /// back out.
+    fn check_alerts(&mut self) {
+        for (alert, state) in self.config.alerts.iter().zip(self.alert_state.iter_mut()) {
+            let Some(quote) = self
+                .quotes
+                .iter()
+                .find(|q| q.symbol.eq_ignore_ascii_case(&alert.symbol))
+            else {
+                continue;
+            };
+
+            let triggered = alert_triggered(alert, quote.price);
+            if triggered && *state == Some(false) {
+                self.pending_alerts.push(format!(
+                    "Alert: {} (now {}{:.2})",
+                    alert_label(alert),
+                    self.price_prefix,
+                    quote.price
+                ));
+            }
+            *state = Some(triggered);
         }
     }
+
+    /// Alerts whose threshold the quote is currently past
+    fn active_alerts<'a>(&'a self, quote: &'a StockQuote) -> impl Iterator<Item = &'a StockAlert> {
+        self.config
+            .alerts
+            .iter()
+            .filter(|a| a.symbol.eq_ignore_ascii_case(&quote.symbol))
+            .filter(|a| alert_triggered(a, quote.price))
+    }
 }
 
 impl FeedWidget for StocksWidget {
     fn id(&self) -> String {
         format!(
-            "stocks-{}-{}",
-            self.config.position.row, self.config.position.col
+            "{}-{}-{}",
+            self.source.as_ref().map_or("stocks", |s| s.id_prefix),
+            self.config.position.row,
+            self.config.position.col
         )
     }
 
@@ -51,6 +171,11 @@ impl FeedWidget for StocksWidget {
         (self.config.position.row, self.config.position.col)
     }
 
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
     fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
             Style::default().fg(Color::Yellow)
@@ -64,7 +189,8 @@ impl FeedWidget for StocksWidget {
             .border_style(border_style);
 
         if self.loading && self.quotes.is_empty() {
-            let loading_text = List::new(vec![ListItem::new("Loading...")]).block(block);
+            let loading_text =
+                List::new(vec![ListItem::new(Spinner::text("Loading..."))]).block(block);
             frame.render_widget(loading_text, area);
             return;
         }
@@ -88,18 +214,30 @@ impl FeedWidget for StocksWidget {
 
                 let change_symbol = if quote.change >= 0.0 { "+" } else { "" };
 
-                let symbol_line = Line::from(vec![
+                let alerts: Vec<String> = self.active_alerts(quote).map(alert_label).collect();
+                let text_color = if alerts.is_empty() {
+                    Color::White
+                } else {
+                    Color::Yellow
+                };
+
+                let mut symbol_spans = vec![
                     Span::styled(
                         format!("{:<6}", quote.symbol),
-                        Style::default()
-                            .fg(Color::White)
-                            .add_modifier(Modifier::BOLD),
+                        Style::default().fg(text_color).add_modifier(Modifier::BOLD),
                     ),
                     Span::styled(
-                        format!(" ${:.2}", quote.price),
-                        Style::default().fg(Color::White),
+                        format!(" {}{:.2}", self.price_prefix, quote.price),
+                        Style::default().fg(text_color),
                     ),
-                ]);
+                ];
+                if !alerts.is_empty() {
+                    symbol_spans.push(Span::styled(
+                        format!("  ! {}", alerts.join(", ")),
+                        Style::default().fg(Color::Yellow),
+                    ));
+                }
+                let symbol_line = Line::from(symbol_spans);
 
                 let change_line = Line::from(vec![Span::styled(
                     format!(
@@ -109,10 +247,13 @@ impl FeedWidget for StocksWidget {
                     Style::default().fg(change_color),
                 )]);
 
-                ListItem::new(vec![symbol_line, change_line])
+                // The last line is left blank for the sparkline drawn below
+                ListItem::new(vec![symbol_line, change_line, Line::default()])
             })
             .collect();
 
+        self.view_height
+            .record(area.height.saturating_sub(2), &items);
         let list = List::new(items).block(block).highlight_style(
             Style::default()
                 .bg(Color::DarkGray)
@@ -121,6 +262,50 @@ impl FeedWidget for StocksWidget {
 
         let mut state = self.scroll_state.clone();
         frame.render_stateful_widget(list, area, &mut state);
+
+        // Lists can't hold widgets, so sparklines are drawn over the blank
+        // line of each visible quote
+        let inner = area.inner(Margin::new(1, 1));
+        let visible = (inner.height / QUOTE_HEIGHT) as usize;
+        for (row, (idx, quote)) in self
+            .quotes
+            .iter()
+            .enumerate()
+            .skip(state.offset())
+            .take(visible)
+            .enumerate()
+        {
+            if quote.history.len() < 2 {
+                continue;
+            }
+
+            let color = if quote.change >= 0.0 {
+                Color::Green
+            } else {
+                Color::Red
+            };
+            let mut style = Style::default().fg(color);
+            if state.selected() == Some(idx) {
+                style = style.bg(Color::DarkGray);
+            }
+
+            let line_area = Rect {
+                x: inner.x + SPARKLINE_INDENT,
+                y: inner.y + row as u16 * QUOTE_HEIGHT + 2,
+                width: inner.width.saturating_sub(SPARKLINE_INDENT),
+                height: 1,
+            };
+            let data = sparkline_points(&quote.history);
+            // Show the most recent prices when the history is wider than the line
+            let start = data.len().saturating_sub(line_area.width as usize);
+            frame.render_widget(
+                Sparkline::default()
+                    .data(&data[start..])
+                    .max(100)
+                    .style(style),
+                line_area,
+            );
+        }
     }
 
     fn update_data(&mut self, data: FeedData) {
@@ -129,6 +314,7 @@ impl FeedWidget for StocksWidget {
             FeedData::Stocks(quotes) => {
                 self.quotes = quotes;
                 self.error = None;
+                self.check_alerts();
             }
             FeedData::Error(e) => {
                 self.error = Some(e);
@@ -140,8 +326,11 @@ impl FeedWidget for StocksWidget {
         }
     }
 
-    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
-        Box::new(StocksFetcher::new(self.config.symbols.clone()))
+    fn create_fetcher(&self, client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
+        if let Some(source) = &self.source {
+            return source.create_fetcher(client);
+        }
+        Box::new(StocksFetcher::new(self.config.symbols.clone(), client))
     }
 
     fn scroll_up(&mut self) {
@@ -160,11 +349,84 @@ impl FeedWidget for StocksWidget {
         }
     }
 
+    fn scroll_to_top(&mut self) {
+        self.scroll_state.select(Some(0));
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        list::select_last(&mut self.scroll_state, self.quotes.len());
+    }
+
+    fn page_size(&self) -> usize {
+        self.view_height.page_size()
+    }
+
+    fn select_index(&mut self, index: usize) {
+        list::select_index(&mut self.scroll_state, index, self.quotes.len());
+    }
+
     fn set_selected(&mut self, selected: bool) {
         self.selected = selected;
     }
 
+    fn take_alerts(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.pending_alerts)
+    }
+
     fn get_selected_discussion_url(&self) -> Option<String> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparkline_points_span_low_to_high() {
+        assert_eq!(sparkline_points(&[10.0, 15.0, 20.0]), vec![0, 50, 100]);
+        assert_eq!(sparkline_points(&[7.0, 7.0]), vec![50, 50]);
+    }
+
+    fn quote(price: f64) -> FeedData {
+        FeedData::Stocks(vec![StockQuote {
+            symbol: "AAPL".to_string(),
+            price,
+            change: 0.0,
+            change_percent: 0.0,
+            name: "Apple Inc.".to_string(),
+            history: Vec::new(),
+        }])
+    }
+
+    #[test]
+    fn test_alert_fires_once_per_crossing() {
+        let config: StocksConfig = toml::from_str(
+            r#"
+            symbols = ["AAPL"]
+            alerts = [{ symbol = "aapl", direction = "above", price = 200.0 }]
+            position = { row = 0, col = 0 }
+            "#,
+        )
+        .unwrap();
+        let mut widget = StocksWidget::new(config);
+
+        // Already past the threshold when first seen: no crossing yet
+        widget.update_data(quote(205.0));
+        assert!(widget.take_alerts().is_empty());
+
+        widget.update_data(quote(190.0));
+        widget.update_data(quote(201.0));
+        assert_eq!(
+            widget.take_alerts(),
+            vec!["Alert: aapl above 200.00 (now $201.00)"]
+        );
+
+        widget.update_data(quote(210.0));
+        assert!(widget.take_alerts().is_empty());
+
+        widget.update_data(quote(199.0));
+        widget.update_data(quote(202.0));
+        assert_eq!(widget.take_alerts().len(), 1);
+    }
+}
@@ -0,0 +1,284 @@
+use crate::config::TimelineConfig;
+use crate::feeds::timeline::{merge_sources, TimelineFetcher, TimelineSource};
+use crate::feeds::{FeedData, FeedFetcher, TimelineItem};
+use crate::ui::spinner::Spinner;
+use crate::ui::text::fit_line;
+use crate::ui::widgets::{list, FeedWidget, SelectedItem};
+use chrono::{Local, TimeZone};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct TimelineWidget {
+    config: TimelineConfig,
+    sources: Vec<TimelineSource>,
+    unknown_sources: Vec<String>,
+    items: Vec<TimelineItem>,
+    loading: bool,
+    error: Option<String>,
+    scroll_state: ListState,
+    view_height: list::ViewHeight,
+    selected: bool,
+    has_new_items: bool,
+}
+
+impl TimelineWidget {
+    pub fn new(config: TimelineConfig) -> Self {
+        let mut scroll_state = ListState::default();
+        scroll_state.select(Some(0));
+
+        Self {
+            config,
+            sources: Vec::new(),
+            unknown_sources: Vec::new(),
+            items: Vec::new(),
+            loading: true,
+            error: None,
+            scroll_state,
+            view_height: list::ViewHeight::default(),
+            selected: false,
+            has_new_items: false,
+        }
+    }
+
+    /// Ids of the widgets this timeline merges, as configured
+    pub fn source_ids(&self) -> &[String] {
+        &self.config.sources
+    }
+
+    /// Attach the resolved sources. Must be called before `create_fetcher`,
+    /// once every other widget exists.
+    pub fn set_sources(&mut self, sources: Vec<TimelineSource>, unknown_sources: Vec<String>) {
+        self.sources = sources;
+        self.unknown_sources = unknown_sources;
+    }
+
+    /// Whether the widget `id` is one of the merged sources
+    pub fn merges(&self, id: &str) -> bool {
+        self.sources.iter().any(|s| s.id == id)
+    }
+
+    /// Follow source widgets that moved to another cell, keyed old id to new
+    /// id. Data already received stays with the widget that sent it.
+    pub fn rename_sources(&mut self, renamed: &HashMap<String, String>) {
+        let ids = self
+            .config
+            .sources
+            .iter_mut()
+            .chain(self.sources.iter_mut().map(|s| &mut s.id));
+        for id in ids {
+            if let Some(new_id) = renamed.get(id) {
+                *id = new_id.clone();
+            }
+        }
+    }
+
+    /// Record data received by the widget `source_id`. Returns the merged
+    /// timeline data when the source is one of this timeline's and the
+    /// merge has something to show.
+    pub fn merge_source(&mut self, source_id: &str, data: &FeedData) -> Option<FeedData> {
+        if matches!(data, FeedData::Loading) {
+            return None;
+        }
+        let source = self.sources.iter_mut().find(|s| s.id == source_id)?;
+        source.data = Some(data.clone());
+        merge_sources(&self.sources, self.config.max_items)
+    }
+}
+
+fn format_timestamp(timestamp: i64) -> Option<String> {
+    Local
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+}
+
+impl FeedWidget for TimelineWidget {
+    fn id(&self) -> String {
+        format!(
+            "timeline-{}-{}",
+            self.config.position.row, self.config.position.col
+        )
+    }
+
+    fn title(&self) -> &str {
+        &self.config.title
+    }
+
+    fn position(&self) -> (usize, usize) {
+        (self.config.position.row, self.config.position.col)
+    }
+
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+        let border_style = if selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        let block = Block::default()
+            .title(format!(" {} ", self.config.title))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        if self.loading && self.items.is_empty() {
+            let loading_text =
+                List::new(vec![ListItem::new(Spinner::text("Loading..."))]).block(block);
+            frame.render_widget(loading_text, area);
+            return;
+        }
+
+        if let Some(ref error) = self.error {
+            let error_text =
+                List::new(vec![ListItem::new(format!("Error: {}", error))]).block(block);
+            frame.render_widget(error_text, area);
+            return;
+        }
+
+        let meta_width = area.width.saturating_sub(2) as usize;
+        let items: Vec<ListItem> = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let title_line = Line::from(vec![
+                    Span::styled(format!("{}. ", i + 1), Style::default().fg(Color::DarkGray)),
+                    Span::styled(&item.title, Style::default().fg(Color::White)),
+                ]);
+
+                let meta_line = Line::from(vec![
+                    Span::styled("   ", Style::default()),
+                    Span::styled(
+                        format!("[{}]", item.source),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(
+                        item.timestamp
+                            .and_then(format_timestamp)
+                            .map(|d| format!(" | {}", d))
+                            .unwrap_or_default(),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]);
+
+                let mut lines = vec![title_line];
+                lines.extend(fit_line(meta_line, meta_width, self.config.overflow));
+                ListItem::new(lines)
+            })
+            .collect();
+
+        self.view_height
+            .record(area.height.saturating_sub(2), &items);
+        let list = List::new(items).block(block).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let mut state = self.scroll_state.clone();
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn update_data(&mut self, data: FeedData) {
+        match data {
+            FeedData::Timeline(items) => {
+                self.loading = false;
+                self.has_new_items |= list::has_new_items(&self.items, &items, |i| {
+                    i.url.clone().unwrap_or_else(|| i.title.clone())
+                });
+                self.items = items;
+                self.error = None;
+            }
+            FeedData::Error(e) => {
+                self.loading = false;
+                self.error = Some(e);
+            }
+            // The fetcher only checks the configuration; items arrive
+            // through `merge_source`, so keep loading until one merges
+            _ => {}
+        }
+    }
+
+    fn create_fetcher(&self, _client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
+        Box::new(TimelineFetcher::new(
+            !self.sources.is_empty(),
+            self.unknown_sources.clone(),
+        ))
+    }
+
+    fn scroll_up(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected > 0 {
+                self.scroll_state.select(Some(selected - 1));
+            }
+        }
+    }
+
+    fn scroll_down(&mut self) {
+        if let Some(selected) = self.scroll_state.selected() {
+            if selected < self.items.len().saturating_sub(1) {
+                self.scroll_state.select(Some(selected + 1));
+            }
+        }
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        list::select_last(&mut self.scroll_state, self.items.len());
+    }
+
+    fn page_size(&self) -> usize {
+        self.view_height.page_size()
+    }
+
+    fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        vec![("Enter", "Read"), ("o", "Open"), ("y", "Copy URL")]
+    }
+
+    fn select_index(&mut self, index: usize) {
+        list::select_index(&mut self.scroll_state, index, self.items.len());
+    }
+
+    fn take_new_items(&mut self) -> bool {
+        std::mem::take(&mut self.has_new_items)
+    }
+
+    fn get_selected_item(&self) -> Option<SelectedItem> {
+        let idx = self.scroll_state.selected()?;
+        let item = self.items.get(idx)?;
+
+        Some(SelectedItem {
+            title: item.title.clone(),
+            url: item.url.clone(),
+            description: item.description.clone(),
+            source: item.source.clone(),
+            metadata: item.timestamp.and_then(format_timestamp),
+        })
+    }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn get_selected_discussion_url(&self) -> Option<String> {
+        None
+    }
+}
@@ -1,5 +1,6 @@
 use crate::config::TwitterConfig;
 use crate::feeds::{FeedData, FeedFetcher};
+use crate::ui::theme::Palette;
 use crate::ui::widgets::FeedWidget;
 use async_trait::async_trait;
 use ratatui::{
@@ -11,23 +12,31 @@ use ratatui::{
 };
 use std::any::Any;
 use std::process::Stdio;
-use std::time::Instant;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::process::Command;
 
+/// Longest tweet bird will post, in characters
+pub const TWEET_MAX_CHARS: usize = 280;
+
 #[derive(Debug, Clone)]
 pub struct TwitterWidget {
-    id: String,
     title: String,
     position: (usize, usize),
     selected: bool,
+    palette: Palette,
     tweets: Vec<Tweet>,
     selected_index: usize,
     list_state: ListState,
     mode: TwitterMode,
     compose_text: String,
+    /// Byte index into `compose_text` where typing inserts; always on a
+    /// char boundary
+    cursor: usize,
     search_query: String,
     detail_view: Option<TweetDetail>,
     status_message: Option<(String, Instant)>,
+    command_timeout: Duration,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -52,30 +61,39 @@ impl TwitterWidget {
         list_state.select(Some(0));
 
         Self {
-            id: format!("twitter-{}-{}", config.position.row, config.position.col),
             title: config.title,
             position: (config.position.row, config.position.col),
             selected: false,
+            palette: Palette::default(),
             tweets: Vec::new(),
             selected_index: 0,
             list_state,
             mode: TwitterMode::Normal,
             compose_text: String::new(),
+            cursor: 0,
             search_query: String::new(),
             detail_view: None,
             status_message: None,
+            command_timeout: Duration::from_secs(config.command_timeout_secs),
         }
     }
 
+    /// How long `bird` commands for this widget may run
+    pub fn command_timeout(&self) -> Duration {
+        self.command_timeout
+    }
+
     pub fn open_compose(&mut self) {
         self.mode = TwitterMode::Compose;
         self.compose_text.clear();
+        self.cursor = 0;
     }
 
     pub fn open_reply(&mut self) {
         if !self.tweets.is_empty() {
             self.mode = TwitterMode::Reply;
             self.compose_text.clear();
+            self.cursor = 0;
         }
     }
 
@@ -87,13 +105,15 @@ impl TwitterWidget {
     pub fn close_modal(&mut self) {
         self.mode = TwitterMode::Normal;
         self.compose_text.clear();
+        self.cursor = 0;
         self.search_query.clear();
     }
 
     pub fn add_char(&mut self, c: char) {
         match self.mode {
             TwitterMode::Compose | TwitterMode::Reply => {
-                self.compose_text.push(c);
+                self.compose_text.insert(self.cursor, c);
+                self.cursor += c.len_utf8();
             }
             TwitterMode::Search => {
                 self.search_query.push(c);
@@ -105,7 +125,10 @@ impl TwitterWidget {
     pub fn delete_char(&mut self) {
         match self.mode {
             TwitterMode::Compose | TwitterMode::Reply => {
-                self.compose_text.pop();
+                if let Some(c) = self.compose_text[..self.cursor].chars().next_back() {
+                    self.cursor -= c.len_utf8();
+                    self.compose_text.remove(self.cursor);
+                }
             }
             TwitterMode::Search => {
                 self.search_query.pop();
@@ -114,11 +137,57 @@ impl TwitterWidget {
         }
     }
 
+    /// Move the compose cursor one character left
+    pub fn cursor_left(&mut self) {
+        if let Some(c) = self.compose_text[..self.cursor].chars().next_back() {
+            self.cursor -= c.len_utf8();
+        }
+    }
+
+    /// Move the compose cursor one character right
+    pub fn cursor_right(&mut self) {
+        if let Some(c) = self.compose_text[self.cursor..].chars().next() {
+            self.cursor += c.len_utf8();
+        }
+    }
+
+    pub fn cursor_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn cursor_end(&mut self) {
+        self.cursor = self.compose_text.len();
+    }
+
+    /// Length of the draft in Unicode scalar values, so an emoji counts once
+    fn compose_chars(&self) -> usize {
+        self.compose_text.chars().count()
+    }
+
+    /// Whether the draft can be posted. Over-long drafts are refused with a
+    /// status message instead of failing inside bird.
+    pub fn check_compose_length(&mut self) -> bool {
+        let count = self.compose_chars();
+        if count > TWEET_MAX_CHARS {
+            self.set_status(format!(
+                "Tweet is {} characters; the limit is {}",
+                count, TWEET_MAX_CHARS
+            ));
+            return false;
+        }
+        true
+    }
+
     pub fn close_detail_view(&mut self) {
         self.detail_view = None;
     }
 
-    pub async fn execute_bird_command_static(args: &[&str]) -> anyhow::Result<String> {
+    /// Run `bird` with the auth flags from the environment, failing if it
+    /// hasn't finished within `timeout`. A timed-out process is killed.
+    pub async fn execute_bird_command_static(
+        args: &[&str],
+        timeout: Duration,
+    ) -> anyhow::Result<String> {
         // Check for environment variables
         let ct0 = std::env::var("CT0").map_err(|_| {
             anyhow::anyhow!("Missing CT0 environment variable. Export it before running feedtui.")
@@ -138,8 +207,11 @@ impl TwitterWidget {
             .args(args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
-            .output()
+            .kill_on_drop(true)
+            .output();
+        let output = tokio::time::timeout(timeout, output)
             .await
+            .map_err(|_| anyhow::anyhow!("bird command timed out after {}s", timeout.as_secs()))?
             .map_err(|e| {
                 if e.kind() == std::io::ErrorKind::NotFound {
                     anyhow::anyhow!(
@@ -180,6 +252,20 @@ impl TwitterWidget {
         &self.search_query
     }
 
+    /// URL of the selected tweet for a like or retweet, or None with a status
+    /// message explaining why there is nothing to act on
+    pub fn action_target(&mut self) -> Option<String> {
+        if self.tweets.is_empty() {
+            self.set_status("No tweet selected".into());
+            return None;
+        }
+        let url = self.get_selected_tweet_url();
+        if url.is_none() {
+            self.set_status("Selected tweet has no URL".into());
+        }
+        url
+    }
+
     pub fn get_selected_tweet_url(&self) -> Option<String> {
         self.tweets
             .get(self.selected_index)
@@ -211,6 +297,12 @@ impl TwitterWidget {
                 self.set_status(format!("Reply posted: {}", msg.trim()));
                 self.close_modal();
             }
+            TwitterData::Liked(msg) => {
+                self.set_status(format!("Liked: {}", msg.trim()));
+            }
+            TwitterData::Retweeted(msg) => {
+                self.set_status(format!("Retweeted: {}", msg.trim()));
+            }
             TwitterData::SearchResults(tweets) => {
                 if tweets.is_empty() {
                     self.set_status("No results found".into());
@@ -251,6 +343,10 @@ struct TwitterFetcher;
 
 #[async_trait]
 impl FeedFetcher for TwitterFetcher {
+    fn source_name(&self) -> &'static str {
+        "twitter"
+    }
+
     async fn fetch(&self) -> anyhow::Result<FeedData> {
         // Twitter widget doesn't auto-fetch
         Ok(FeedData::Loading)
@@ -259,7 +355,7 @@ impl FeedFetcher for TwitterFetcher {
 
 impl FeedWidget for TwitterWidget {
     fn id(&self) -> String {
-        self.id.clone()
+        format!("twitter-{}-{}", self.position.0, self.position.1)
     }
 
     fn title(&self) -> &str {
@@ -270,9 +366,13 @@ impl FeedWidget for TwitterWidget {
         self.position
     }
 
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.position = (row, col);
+    }
+
     fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
-            Style::default().fg(Color::Cyan)
+            Style::default().fg(self.palette.accent)
         } else {
             Style::default().fg(Color::Gray)
         };
@@ -292,7 +392,7 @@ impl FeedWidget for TwitterWidget {
                 Line::from(Span::styled(
                     "Twitter/X Feed",
                     Style::default()
-                        .fg(Color::Cyan)
+                        .fg(self.palette.accent)
                         .add_modifier(Modifier::BOLD),
                 )),
                 Line::from(""),
@@ -359,7 +459,7 @@ impl FeedWidget for TwitterWidget {
         // Twitter widget doesn't use standard feed data
     }
 
-    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
+    fn create_fetcher(&self, _client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
         Box::new(TwitterFetcher)
     }
 
@@ -377,10 +477,38 @@ impl FeedWidget for TwitterWidget {
         }
     }
 
+    fn scroll_to_top(&mut self) {
+        self.selected_index = 0;
+        self.list_state.select(Some(0));
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        if let Some(last) = self.tweets.len().checked_sub(1) {
+            self.selected_index = last;
+            self.list_state.select(Some(last));
+        }
+    }
+
     fn set_selected(&mut self, selected: bool) {
         self.selected = selected;
     }
 
+    fn set_palette(&mut self, palette: Palette) {
+        self.palette = palette;
+    }
+
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        vec![
+            ("t", "Compose"),
+            ("r", "Reply"),
+            ("l", "Like"),
+            ("R", "Retweet"),
+            ("/", "Search"),
+            ("m", "Mentions"),
+            ("Enter", "Read"),
+        ]
+    }
+
     fn as_any(&self) -> Option<&dyn Any> {
         Some(self)
     }
@@ -397,13 +525,50 @@ impl FeedWidget for TwitterWidget {
 }
 
 impl TwitterWidget {
+    /// The draft with the character under the cursor shown reversed (a
+    /// reversed space when the cursor is at the end)
+    fn compose_line(&self) -> Line<'_> {
+        let (before, after) = self.compose_text.split_at(self.cursor);
+        let mut rest = after.chars();
+        let under_cursor = rest.next().map_or(" ".to_string(), String::from);
+
+        Line::from(vec![
+            Span::raw(before),
+            Span::styled(
+                under_cursor,
+                Style::default().add_modifier(Modifier::REVERSED),
+            ),
+            Span::raw(rest.as_str()),
+        ])
+    }
+
+    /// Key hints and the character counter, red once the draft is too long
+    fn compose_footer(&self) -> Line<'static> {
+        let count = self.compose_chars();
+        let counter_color = if count > TWEET_MAX_CHARS {
+            self.palette.error
+        } else {
+            self.palette.dim
+        };
+        Line::from(vec![
+            Span::styled(
+                "Enter to post | Esc to cancel | ",
+                Style::default().fg(self.palette.dim),
+            ),
+            Span::styled(
+                format!("{}/{}", count, TWEET_MAX_CHARS),
+                Style::default().fg(counter_color),
+            ),
+        ])
+    }
+
     fn render_compose_modal(&self, frame: &mut Frame, area: Rect) {
         let modal_area = self.center_rect(60, 30, area);
         frame.render_widget(Clear, modal_area);
 
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan))
+            .border_style(Style::default().fg(self.palette.accent))
             .title("Compose Tweet");
 
         let inner = block.inner(modal_area);
@@ -411,12 +576,9 @@ impl TwitterWidget {
 
         let text = vec![
             Line::from(""),
-            Line::from(self.compose_text.as_str()),
+            self.compose_line(),
             Line::from(""),
-            Line::from(Span::styled(
-                "Enter to post | Esc to cancel",
-                Style::default().fg(Color::DarkGray),
-            )),
+            self.compose_footer(),
         ];
 
         let paragraph = Paragraph::new(text).wrap(Wrap { trim: false });
@@ -429,7 +591,7 @@ impl TwitterWidget {
 
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan))
+            .border_style(Style::default().fg(self.palette.accent))
             .title("Reply to Tweet");
 
         let inner = block.inner(modal_area);
@@ -437,12 +599,9 @@ impl TwitterWidget {
 
         let text = vec![
             Line::from(""),
-            Line::from(self.compose_text.as_str()),
+            self.compose_line(),
             Line::from(""),
-            Line::from(Span::styled(
-                "Enter to post | Esc to cancel",
-                Style::default().fg(Color::DarkGray),
-            )),
+            self.compose_footer(),
         ];
 
         let paragraph = Paragraph::new(text).wrap(Wrap { trim: false });
@@ -455,7 +614,7 @@ impl TwitterWidget {
 
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan))
+            .border_style(Style::default().fg(self.palette.accent))
             .title("Search Twitter");
 
         let inner = block.inner(modal_area);
@@ -467,7 +626,7 @@ impl TwitterWidget {
             Line::from(""),
             Line::from(Span::styled(
                 "Enter to search | Esc to cancel",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(self.palette.dim),
             )),
         ];
 
@@ -481,7 +640,7 @@ impl TwitterWidget {
 
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan))
+            .border_style(Style::default().fg(self.palette.accent))
             .title("Tweet Detail");
 
         let inner = block.inner(modal_area);
@@ -503,7 +662,7 @@ impl TwitterWidget {
 
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Yellow));
+            .border_style(Style::default().fg(self.palette.border_selected));
 
         let paragraph = Paragraph::new(message).block(block);
         frame.render_widget(Clear, status_area);
@@ -530,3 +689,85 @@ impl TwitterWidget {
             .split(popup_layout[1])[1]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Position;
+
+    fn widget() -> TwitterWidget {
+        TwitterWidget::new(TwitterConfig {
+            title: "Twitter/X".to_string(),
+            command_timeout_secs: 15,
+            refresh_secs: None,
+            position: Position { row: 0, col: 0 },
+        })
+    }
+
+    #[test]
+    fn test_compose_length_counts_characters() {
+        let mut widget = widget();
+        widget.open_compose();
+
+        // 280 multi-byte emoji are within the limit even though they are
+        // far more than 280 bytes
+        for _ in 0..TWEET_MAX_CHARS {
+            widget.add_char('🦀');
+        }
+        assert!(widget.check_compose_length());
+        assert!(widget.status_message.is_none());
+
+        widget.add_char('!');
+        assert!(!widget.check_compose_length());
+        assert!(widget.status_message.is_some());
+    }
+
+    #[test]
+    fn test_compose_edits_at_cursor() {
+        let mut widget = widget();
+        widget.open_compose();
+        for c in "hllo wörld".chars() {
+            widget.add_char(c);
+        }
+
+        // Fix the typo at the start
+        widget.cursor_home();
+        widget.cursor_right();
+        widget.add_char('e');
+        assert_eq!(widget.get_compose_text(), "hello wörld");
+
+        // Delete the multi-byte char from the middle
+        widget.cursor_end();
+        for _ in 0..3 {
+            widget.cursor_left();
+        }
+        widget.delete_char();
+        widget.add_char('o');
+        assert_eq!(widget.get_compose_text(), "hello world");
+
+        widget.cursor_home();
+        widget.cursor_left();
+        widget.delete_char();
+        assert_eq!(widget.get_compose_text(), "hello world");
+    }
+
+    #[test]
+    fn test_action_target_needs_a_tweet_with_url() {
+        let mut widget = widget();
+        assert_eq!(widget.action_target(), None);
+
+        widget.tweets = vec![Tweet {
+            id: "1".to_string(),
+            author: "someone".to_string(),
+            text: "hello".to_string(),
+            url: None,
+        }];
+        assert_eq!(widget.action_target(), None);
+
+        widget.tweets[0].url = Some("https://x.com/someone/status/1".to_string());
+        assert_eq!(
+            widget.action_target().as_deref(),
+            Some("https://x.com/someone/status/1")
+        );
+    }
+}
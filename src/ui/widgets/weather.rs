@@ -0,0 +1,196 @@
+use crate::config::WeatherConfig;
+use crate::feeds::weather::{weather_condition, WeatherFetcher};
+use crate::feeds::{FeedData, FeedFetcher, WeatherReport};
+use crate::ui::spinner::Spinner;
+use crate::ui::widgets::FeedWidget;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::sync::Arc;
+
+/// Rows taken by the current conditions above the hourly forecast
+const CURRENT_HEIGHT: u16 = 3;
+
+/// Narrowest column an hour of forecast is drawn in
+const HOUR_WIDTH: u16 = 7;
+
+pub struct WeatherWidget {
+    config: WeatherConfig,
+    report: Option<WeatherReport>,
+    loading: bool,
+    error: Option<String>,
+    selected: bool,
+}
+
+impl WeatherWidget {
+    pub fn new(config: WeatherConfig) -> Self {
+        Self {
+            config,
+            report: None,
+            loading: true,
+            error: None,
+            selected: false,
+        }
+    }
+
+    fn render_current(&self, frame: &mut Frame, area: Rect, report: &WeatherReport) {
+        let (icon, description) = weather_condition(report.weather_code);
+        let lines = vec![
+            Line::from(vec![
+                Span::raw(format!("{}  ", icon)),
+                Span::styled(
+                    format!("{:.1}{}", report.temperature, report.temperature_unit),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("  {}", description),
+                    Style::default().fg(Color::White),
+                ),
+            ]),
+            Line::from(Span::styled(
+                format!("Wind {:.0} {}", report.wind_speed, report.wind_speed_unit),
+                Style::default().fg(Color::DarkGray),
+            )),
+        ];
+        frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), area);
+    }
+
+    /// One column per hour, as many as fit the width
+    fn render_hourly(&self, frame: &mut Frame, area: Rect, report: &WeatherReport) {
+        let count = report.hourly.len().min((area.width / HOUR_WIDTH) as usize);
+        if count == 0 {
+            return;
+        }
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, count as u32); count])
+            .split(area);
+
+        for (hour, column) in report.hourly.iter().zip(columns.iter()) {
+            let (icon, _) = weather_condition(hour.weather_code);
+            let lines = vec![
+                Line::from(Span::styled(
+                    &hour.time,
+                    Style::default().fg(Color::DarkGray),
+                )),
+                Line::from(icon),
+                Line::from(Span::styled(
+                    format!("{:.0}°", hour.temperature),
+                    Style::default().fg(Color::White),
+                )),
+            ];
+            frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), *column);
+        }
+    }
+}
+
+impl FeedWidget for WeatherWidget {
+    fn id(&self) -> String {
+        format!(
+            "weather-{}-{}",
+            self.config.position.row, self.config.position.col
+        )
+    }
+
+    fn title(&self) -> &str {
+        &self.config.title
+    }
+
+    fn position(&self) -> (usize, usize) {
+        (self.config.position.row, self.config.position.col)
+    }
+
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
+        let border_style = if selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        let block = Block::default()
+            .title(format!(" {} ", self.config.title))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        let report = match (&self.report, &self.error) {
+            (_, Some(error)) => {
+                let text = Paragraph::new(format!("Error: {}", error)).block(block);
+                frame.render_widget(text, area);
+                return;
+            }
+            (Some(report), None) => report,
+            (None, None) => {
+                frame.render_widget(
+                    Paragraph::new(Spinner::text("Loading...")).block(block),
+                    area,
+                );
+                return;
+            }
+        };
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(CURRENT_HEIGHT), Constraint::Min(0)])
+            .split(inner);
+        self.render_current(frame, chunks[0], report);
+        self.render_hourly(frame, chunks[1], report);
+    }
+
+    fn update_data(&mut self, data: FeedData) {
+        self.loading = false;
+        match data {
+            FeedData::Weather(report) => {
+                self.report = Some(report);
+                self.error = None;
+            }
+            FeedData::Error(e) => {
+                self.error = Some(e);
+            }
+            FeedData::Loading => {
+                self.loading = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn create_fetcher(&self, client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
+        Box::new(WeatherFetcher::new(
+            self.config.latitude,
+            self.config.longitude,
+            self.config.units,
+            self.config.hours,
+            client,
+        ))
+    }
+
+    fn scroll_up(&mut self) {
+        // Not applicable for weather widget
+    }
+
+    fn scroll_down(&mut self) {
+        // Not applicable for weather widget
+    }
+
+    fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    fn get_selected_discussion_url(&self) -> Option<String> {
+        None
+    }
+}
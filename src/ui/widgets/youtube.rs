@@ -1,14 +1,33 @@
 use crate::config::YoutubeConfig;
+use crate::feeds::pixelart::PixelData;
 use crate::feeds::youtube::YoutubeFetcher;
 use crate::feeds::{FeedData, FeedFetcher, YoutubeVideo};
-use crate::ui::widgets::{FeedWidget, SelectedItem};
+use crate::ui::spinner::Spinner;
+use crate::ui::text::fit_line;
+use crate::ui::widgets::pixelart::{line_count, pixel_lines, PixelRenderMode};
+use crate::ui::widgets::{list, FeedWidget, SelectedItem};
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Thumbnail width in pixels (and terminal columns)
+pub const THUMBNAIL_SIZE: u32 = 32;
+
+/// `12:34 · 1.2M views`, or whichever half is known
+fn video_stats(video: &YoutubeVideo) -> Option<String> {
+    let parts: Vec<&str> = [&video.duration, &video.view_count]
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(" · "))
+}
 
 pub struct YoutubeWidget {
     config: YoutubeConfig,
@@ -16,7 +35,15 @@ pub struct YoutubeWidget {
     loading: bool,
     error: Option<String>,
     scroll_state: ListState,
+    view_height: list::ViewHeight,
     selected: bool,
+    has_new_items: bool,
+    /// Draw the selected video's thumbnail under the list
+    show_thumbnail: bool,
+    /// Downloaded thumbnails, or why they failed, by video id
+    thumbnails: HashMap<String, Result<PixelData, String>>,
+    /// Video ids whose thumbnail is being downloaded
+    requested: HashSet<String>,
 }
 
 impl YoutubeWidget {
@@ -30,8 +57,73 @@ impl YoutubeWidget {
             loading: true,
             error: None,
             scroll_state,
+            view_height: list::ViewHeight::default(),
             selected: false,
+            has_new_items: false,
+            show_thumbnail: false,
+            thumbnails: HashMap::new(),
+            requested: HashSet::new(),
+        }
+    }
+
+    fn selected_video(&self) -> Option<&YoutubeVideo> {
+        self.videos.get(self.scroll_state.selected()?)
+    }
+
+    pub fn toggle_thumbnail(&mut self) {
+        self.show_thumbnail = !self.show_thumbnail;
+    }
+
+    /// The video id and URL of a thumbnail to download: the selected video's,
+    /// while thumbnails are shown and it isn't cached or already on its way
+    pub fn take_thumbnail_request(&mut self) -> Option<(String, String)> {
+        if !self.show_thumbnail {
+            return None;
         }
+        let video = self.selected_video()?;
+        let url = video.thumbnail_url.clone()?;
+        if self.thumbnails.contains_key(&video.id) || self.requested.contains(&video.id) {
+            return None;
+        }
+        let id = video.id.clone();
+        self.requested.insert(id.clone());
+        Some((id, url))
+    }
+
+    pub fn set_thumbnail(&mut self, video_id: String, thumbnail: Result<PixelData, String>) {
+        self.requested.remove(&video_id);
+        self.thumbnails.insert(video_id, thumbnail);
+    }
+
+    fn render_thumbnail(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title(" Thumbnail ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::DarkGray));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let Some(video) = self.selected_video() else {
+            return;
+        };
+        let lines = match self.thumbnails.get(&video.id) {
+            Some(Ok(data)) => {
+                let rows = line_count(data, PixelRenderMode::HalfBlock).min(inner.height as usize);
+                pixel_lines(
+                    data,
+                    PixelRenderMode::HalfBlock,
+                    0..rows,
+                    0..data.width as usize,
+                )
+            }
+            Some(Err(e)) => vec![Line::styled(
+                format!("Error: {}", e),
+                Style::default().fg(Color::Red),
+            )],
+            None if video.thumbnail_url.is_none() => vec![Line::from("No thumbnail")],
+            None => vec![Line::from(Spinner::text("Loading thumbnail..."))],
+        };
+        frame.render_widget(Paragraph::new(lines), inner);
     }
 }
 
@@ -51,6 +143,11 @@ impl FeedWidget for YoutubeWidget {
         (self.config.position.row, self.config.position.col)
     }
 
+    fn set_position(&mut self, row: usize, col: usize) {
+        self.config.position.row = row;
+        self.config.position.col = col;
+    }
+
     fn render(&self, frame: &mut Frame, area: Rect, selected: bool) {
         let border_style = if selected {
             Style::default().fg(Color::Yellow)
@@ -64,8 +161,10 @@ impl FeedWidget for YoutubeWidget {
             .border_style(border_style);
 
         if self.loading && self.videos.is_empty() {
-            let loading_text =
-                List::new(vec![ListItem::new("Loading YouTube videos...")]).block(block);
+            let loading_text = List::new(vec![ListItem::new(Spinner::text(
+                "Loading YouTube videos...",
+            ))])
+            .block(block);
             frame.render_widget(loading_text, area);
             return;
         }
@@ -83,37 +182,46 @@ impl FeedWidget for YoutubeWidget {
             return;
         }
 
+        let (area, thumbnail_area) = if self.show_thumbnail {
+            // A 16:9 thumbnail in half blocks is 9/32 as tall as it is wide
+            let height = (THUMBNAIL_SIZE * 9 / 32) as u16 + 2;
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(height)])
+                .split(area);
+            (chunks[0], Some(chunks[1]))
+        } else {
+            (area, None)
+        };
+        if let Some(thumbnail_area) = thumbnail_area {
+            self.render_thumbnail(frame, thumbnail_area);
+        }
+
+        let meta_width = area.width.saturating_sub(2) as usize;
         let items: Vec<ListItem> = self
             .videos
             .iter()
             .enumerate()
             .map(|(i, video)| {
-                // Title line with numbering
-                let title_line = Line::from(vec![
+                // Title line with numbering, then duration and views when known
+                let mut title_parts = vec![
                     Span::styled(format!("{}. ", i + 1), Style::default().fg(Color::DarkGray)),
                     Span::styled(&video.title, Style::default().fg(Color::White)),
-                ]);
+                ];
+                if let Some(stats) = video_stats(video) {
+                    title_parts.push(Span::styled(
+                        format!("  {}", stats),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                let title_line = Line::from(title_parts);
 
-                // Metadata line: channel, date, views, duration
+                // Metadata line: channel and date
                 let mut meta_parts: Vec<Span> = vec![
                     Span::styled("   ", Style::default()),
                     Span::styled(&video.channel, Style::default().fg(Color::Cyan)),
                 ];
 
-                if let Some(ref views) = video.view_count {
-                    meta_parts.push(Span::styled(
-                        format!(" | {}", views),
-                        Style::default().fg(Color::Green),
-                    ));
-                }
-
-                if let Some(ref duration) = video.duration {
-                    meta_parts.push(Span::styled(
-                        format!(" | {}", duration),
-                        Style::default().fg(Color::Magenta),
-                    ));
-                }
-
                 meta_parts.push(Span::styled(
                     format!(" | {}", video.published),
                     Style::default().fg(Color::DarkGray),
@@ -121,10 +229,14 @@ impl FeedWidget for YoutubeWidget {
 
                 let meta_line = Line::from(meta_parts);
 
-                ListItem::new(vec![title_line, meta_line])
+                let mut lines = vec![title_line];
+                lines.extend(fit_line(meta_line, meta_width, self.config.overflow));
+                ListItem::new(lines)
             })
             .collect();
 
+        self.view_height
+            .record(area.height.saturating_sub(2), &items);
         let list = List::new(items).block(block).highlight_style(
             Style::default()
                 .bg(Color::DarkGray)
@@ -139,6 +251,7 @@ impl FeedWidget for YoutubeWidget {
         self.loading = false;
         match data {
             FeedData::Youtube(videos) => {
+                self.has_new_items |= list::has_new_items(&self.videos, &videos, |v| v.id.clone());
                 self.videos = videos;
                 self.error = None;
             }
@@ -152,12 +265,13 @@ impl FeedWidget for YoutubeWidget {
         }
     }
 
-    fn create_fetcher(&self) -> Box<dyn FeedFetcher> {
+    fn create_fetcher(&self, client: Arc<reqwest::Client>) -> Box<dyn FeedFetcher> {
         Box::new(YoutubeFetcher::new(
             self.config.api_key.clone(),
             self.config.channels.clone(),
             self.config.search_query.clone(),
             self.config.max_videos,
+            client,
         ))
     }
 
@@ -177,10 +291,35 @@ impl FeedWidget for YoutubeWidget {
         }
     }
 
+    fn scroll_to_bottom(&mut self) {
+        list::select_last(&mut self.scroll_state, self.videos.len());
+    }
+
+    fn page_size(&self) -> usize {
+        self.view_height.page_size()
+    }
+
     fn set_selected(&mut self, selected: bool) {
         self.selected = selected;
     }
 
+    fn key_hints(&self) -> Vec<(&str, &str)> {
+        vec![
+            ("Enter", "Read"),
+            ("v", "Thumbnail"),
+            ("o", "Open"),
+            ("y", "Copy URL"),
+        ]
+    }
+
+    fn select_index(&mut self, index: usize) {
+        list::select_index(&mut self.scroll_state, index, self.videos.len());
+    }
+
+    fn take_new_items(&mut self) -> bool {
+        std::mem::take(&mut self.has_new_items)
+    }
+
     fn get_selected_item(&self) -> Option<SelectedItem> {
         let idx = self.scroll_state.selected()?;
         let video = self.videos.get(idx)?;
@@ -208,4 +347,55 @@ impl FeedWidget for YoutubeWidget {
     fn get_selected_discussion_url(&self) -> Option<String> {
         None
     }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn video(id: &str) -> YoutubeVideo {
+        YoutubeVideo {
+            id: id.to_string(),
+            title: String::new(),
+            channel: String::new(),
+            published: String::new(),
+            description: String::new(),
+            thumbnail_url: Some(format!("https://i.ytimg.com/vi/{}/mqdefault.jpg", id)),
+            view_count: None,
+            duration: None,
+            timestamp: None,
+        }
+    }
+
+    #[test]
+    fn test_thumbnails_are_requested_once() {
+        let mut widget = YoutubeWidget::new(
+            toml::from_str::<YoutubeConfig>(
+                r#"api_key = "key"
+position = { row = 0, col = 0 }"#,
+            )
+            .unwrap(),
+        );
+        widget.update_data(FeedData::Youtube(vec![video("a"), video("b")]));
+        assert_eq!(widget.take_thumbnail_request(), None);
+
+        widget.toggle_thumbnail();
+        let (id, _) = widget.take_thumbnail_request().unwrap();
+        assert_eq!(id, "a");
+        assert_eq!(widget.take_thumbnail_request(), None);
+
+        widget.set_thumbnail(id, Err("offline".to_string()));
+        assert_eq!(widget.take_thumbnail_request(), None);
+
+        widget.scroll_down();
+        assert_eq!(widget.take_thumbnail_request().unwrap().0, "b");
+    }
 }